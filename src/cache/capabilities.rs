@@ -0,0 +1,209 @@
+//! Redmine server version and the features it gates.
+//!
+//! Redmine does not report its version through the REST API, so the version
+//! is declared once with `--api-version` and remembered per server URL.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AppError, Result};
+
+/// File under the cache directory holding the known server versions.
+pub const CAPABILITIES_FILE: &str = "server_capabilities.json";
+
+/// A Redmine `major.minor` version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ServerVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parse `5.1`, `5.1.2` or `5.1.2.stable`; anything after the minor part is ignored.
+    pub fn parse(value: &str) -> Result<Self> {
+        let mut parts = value.trim().split('.');
+        let major = parts.next().and_then(|p| p.parse().ok());
+        let minor = parts.next().and_then(|p| p.parse().ok());
+        match (major, minor) {
+            (Some(major), Some(minor)) => Ok(Self { major, minor }),
+            _ => Err(AppError::validation_with_hint(
+                format!("Invalid Redmine version: '{}'", value),
+                "Use major.minor as shown in Administration > Information, e.g. 5.1",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl TryFrom<String> for ServerVersion {
+    type Error = AppError;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&value)
+    }
+}
+
+impl From<ServerVersion> for String {
+    fn from(version: ServerVersion) -> Self {
+        version.to_string()
+    }
+}
+
+/// API features that need a minimum Redmine version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// The `closed_on` issue filter.
+    ClosedOnFilter,
+    /// Project archive/unarchive/close/reopen endpoints.
+    ProjectState,
+}
+
+impl Feature {
+    /// First Redmine version that supports the feature.
+    pub fn min_version(self) -> ServerVersion {
+        match self {
+            Self::ClosedOnFilter => ServerVersion::new(2, 3),
+            Self::ProjectState => ServerVersion::new(5, 1),
+        }
+    }
+
+    /// Human-readable feature name used in errors.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::ClosedOnFilter => "The closed_on issue filter",
+            Self::ProjectState => "Archiving, closing and reopening projects",
+        }
+    }
+}
+
+/// What the CLI knows about a server.
+#[derive(Debug, Clone, Default)]
+pub struct ServerCapabilities {
+    /// Declared server version; `None` when unknown.
+    pub version: Option<ServerVersion>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CapabilitiesFile {
+    #[serde(default)]
+    servers: BTreeMap<String, ServerVersion>,
+}
+
+impl ServerCapabilities {
+    fn path(cache_dir: &Path) -> PathBuf {
+        cache_dir.join(CAPABILITIES_FILE)
+    }
+
+    fn server_key(url: &str) -> String {
+        url.trim_end_matches('/').to_string()
+    }
+
+    fn read(cache_dir: &Path) -> CapabilitiesFile {
+        std::fs::read_to_string(Self::path(cache_dir))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Load the remembered version of `url`; unreadable files mean unknown.
+    pub fn load(cache_dir: &Path, url: &str) -> Self {
+        Self {
+            version: Self::read(cache_dir)
+                .servers
+                .get(&Self::server_key(url))
+                .copied(),
+        }
+    }
+
+    /// Record the version of `url` for later runs.
+    pub fn declare(cache_dir: &Path, url: &str, version: &str) -> Result<Self> {
+        let version = ServerVersion::parse(version)?;
+        let mut file = Self::read(cache_dir);
+        if file.servers.get(&Self::server_key(url)) != Some(&version) {
+            file.servers.insert(Self::server_key(url), version);
+            std::fs::create_dir_all(cache_dir)?;
+            std::fs::write(Self::path(cache_dir), serde_json::to_string_pretty(&file)?)?;
+        }
+        Ok(Self {
+            version: Some(version),
+        })
+    }
+
+    /// Check if the server supports `feature`; unknown versions are assumed to.
+    pub fn supports(&self, feature: Feature) -> bool {
+        match self.version {
+            Some(version) => version >= feature.min_version(),
+            None => true,
+        }
+    }
+
+    /// Fail with a clear error when the server is too old for `feature`.
+    pub fn require(&self, feature: Feature) -> Result<()> {
+        match self.version {
+            Some(version) if !self.supports(feature) => Err(AppError::api_with_hint(
+                format!(
+                    "{} requires Redmine >= {} (server is {})",
+                    feature.description(),
+                    feature.min_version(),
+                    version
+                ),
+                None,
+                "Upgrade Redmine, or pass --api-version if the recorded version is outdated",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            ServerVersion::parse("5.1.2.stable").unwrap(),
+            ServerVersion::new(5, 1)
+        );
+        assert!(ServerVersion::parse("5").is_err());
+        assert!(ServerVersion::parse("latest").is_err());
+    }
+
+    #[test]
+    fn test_require_gates_old_servers() {
+        let old = ServerCapabilities {
+            version: Some(ServerVersion::new(4, 2)),
+        };
+        assert!(old.require(Feature::ClosedOnFilter).is_ok());
+        let err = old.require(Feature::ProjectState).unwrap_err();
+        assert!(err.to_string().contains("requires Redmine >= 5.1"));
+
+        assert!(ServerCapabilities::default()
+            .require(Feature::ProjectState)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_declare_is_remembered_per_url() {
+        let dir = tempdir().unwrap();
+        ServerCapabilities::declare(dir.path(), "https://old.example.com/", "4.2").unwrap();
+
+        let loaded = ServerCapabilities::load(dir.path(), "https://old.example.com");
+        assert_eq!(loaded.version, Some(ServerVersion::new(4, 2)));
+        let other = ServerCapabilities::load(dir.path(), "https://new.example.com");
+        assert_eq!(other.version, None);
+    }
+}
@@ -0,0 +1,106 @@
+//! Cache for small lookup lists that rarely change (activities, trackers, priorities).
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::Path;
+
+use super::{now_secs, CACHE_TTL};
+use crate::error::Result;
+
+/// A cached list with the time it was fetched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListCache<T> {
+    /// When the cache was last updated.
+    pub updated_at: u64,
+    /// Server the items were fetched from; empty when not tied to one.
+    #[serde(default)]
+    pub server: String,
+    /// Cached items.
+    pub items: Vec<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> ListCache<T> {
+    /// Create a new cache with the given items.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            updated_at: now_secs(),
+            server: String::new(),
+            items,
+        }
+    }
+
+    /// Tie the cache to the server the items were fetched from.
+    pub fn with_server(mut self, url: &str) -> Self {
+        self.server = url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Check if the items were fetched from `url`.
+    pub fn is_for(&self, url: &str) -> bool {
+        self.server == url.trim_end_matches('/')
+    }
+
+    /// Check if the cache is still valid.
+    pub fn is_valid(&self) -> bool {
+        now_secs().saturating_sub(self.updated_at) < CACHE_TTL.as_secs()
+    }
+
+    /// Read a cache whether or not it has expired; missing or unreadable files yield `None`.
+    pub fn read(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Load a valid cache; missing, unreadable or expired files yield `None`.
+    pub fn load(path: &Path) -> Option<Self> {
+        Self::read(path).filter(Self::is_valid)
+    }
+
+    /// Get cache age as human-readable string.
+    pub fn age_string(&self) -> String {
+        super::age_string(self.updated_at)
+    }
+
+    /// Save cache to file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_save_load() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("trackers.json");
+
+        ListCache::new(vec!["Bug".to_string()]).save(&path).unwrap();
+        let loaded: ListCache<String> = ListCache::load(&path).unwrap();
+        assert_eq!(loaded.items, vec!["Bug".to_string()]);
+    }
+
+    #[test]
+    fn test_server_match() {
+        let cache = ListCache::new(vec!["Bug".to_string()]).with_server("https://a.example.com/");
+        assert!(cache.is_for("https://a.example.com"));
+        assert!(!cache.is_for("https://b.example.com"));
+    }
+
+    #[test]
+    fn test_expired_cache_is_ignored() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("trackers.json");
+        let mut cache = ListCache::new(vec!["Bug".to_string()]);
+        cache.updated_at -= CACHE_TTL.as_secs() + 1;
+
+        cache.save(&path).unwrap();
+        assert!(ListCache::<String>::load(&path).is_none());
+        assert!(ListCache::<String>::read(&path).is_some());
+    }
+}
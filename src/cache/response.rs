@@ -0,0 +1,176 @@
+//! HTTP response cache for conditional GET requests.
+//!
+//! Entries keep the body together with the `ETag`/`Last-Modified` validators
+//! so a later request can be revalidated and answered from disk on 304.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use super::now_secs;
+use crate::error::Result;
+
+/// Entries older than this are not revalidated.
+const RESPONSE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Maximum number of cached responses kept on disk.
+const MAX_ENTRIES: usize = 200;
+
+/// A cached GET response body with its validators.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResponse {
+    /// When the entry was stored (seconds since the Unix epoch).
+    pub stored_at: u64,
+    #[serde(default)]
+    pub etag: Option<String>,
+    #[serde(default)]
+    pub last_modified: Option<String>,
+    #[serde(default)]
+    pub content_type: Option<String>,
+    pub body: String,
+}
+
+impl CachedResponse {
+    /// Create an entry stamped with the current time.
+    pub fn new(
+        etag: Option<String>,
+        last_modified: Option<String>,
+        content_type: Option<String>,
+        body: String,
+    ) -> Self {
+        Self {
+            stored_at: now_secs(),
+            etag,
+            last_modified,
+            content_type,
+            body,
+        }
+    }
+
+    /// Check if the entry is still within its TTL and can be served back
+    /// as a response (a hand-edited content type counts as a miss).
+    pub fn is_valid(&self) -> bool {
+        let content_type_ok = self
+            .content_type
+            .as_deref()
+            .is_none_or(|v| reqwest::header::HeaderValue::from_str(v).is_ok());
+        content_type_ok && now_secs().saturating_sub(self.stored_at) < RESPONSE_TTL.as_secs()
+    }
+}
+
+/// On-disk response cache, one JSON file per key.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    dir: PathBuf,
+}
+
+impl ResponseCache {
+    /// Create a cache stored in `dir`.
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Build a cache key from the parts that make a response unique
+    /// (URL, credentials, language).
+    pub fn key(parts: &[&str]) -> String {
+        let mut hasher = DefaultHasher::new();
+        parts.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+
+    /// Load a valid entry; missing, unreadable or expired entries yield `None`.
+    pub fn load(&self, key: &str) -> Option<CachedResponse> {
+        let content = std::fs::read_to_string(self.path(key)).ok()?;
+        let entry: CachedResponse = serde_json::from_str(&content).ok()?;
+        entry.is_valid().then_some(entry)
+    }
+
+    /// Store an entry, then evict the oldest files beyond the size cap.
+    pub fn store(&self, key: &str, entry: &CachedResponse) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.path(key), serde_json::to_string(entry)?)?;
+        self.prune();
+        Ok(())
+    }
+
+    /// Remove the oldest entries so at most `MAX_ENTRIES` remain.
+    fn prune(&self) {
+        let Ok(dir) = std::fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut files: Vec<(SystemTime, PathBuf)> = dir
+            .filter_map(|e| e.ok())
+            .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+            .collect();
+        if files.len() <= MAX_ENTRIES {
+            return;
+        }
+        files.sort();
+        for (_, path) in &files[..files.len() - MAX_ENTRIES] {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn entry() -> CachedResponse {
+        CachedResponse::new(
+            Some("\"v1\"".to_string()),
+            None,
+            Some("application/json".to_string()),
+            "{}".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_store_load() {
+        let dir = tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().to_path_buf());
+        let key = ResponseCache::key(&["https://example.com/issues/1.json", "key"]);
+
+        cache.store(&key, &entry()).unwrap();
+        let loaded = cache.load(&key).unwrap();
+        assert_eq!(loaded.etag.as_deref(), Some("\"v1\""));
+    }
+
+    #[test]
+    fn test_expired_entry_is_ignored() {
+        let dir = tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().to_path_buf());
+        let mut stale = entry();
+        stale.stored_at -= RESPONSE_TTL.as_secs() + 1;
+
+        cache.store("stale", &stale).unwrap();
+        assert!(cache.load("stale").is_none());
+    }
+
+    #[test]
+    fn test_unusable_content_type_is_ignored() {
+        let dir = tempdir().unwrap();
+        let cache = ResponseCache::new(dir.path().to_path_buf());
+        let mut broken = entry();
+        broken.content_type = Some("application/json\n".to_string());
+
+        cache.store("broken", &broken).unwrap();
+        assert!(cache.load("broken").is_none());
+    }
+
+    #[test]
+    fn test_key_depends_on_all_parts() {
+        let url = "https://example.com/issues/1.json";
+        assert_ne!(
+            ResponseCache::key(&[url, "key-a"]),
+            ResponseCache::key(&[url, "key-b"])
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Cache commands.
+
+use clap::Subcommand;
+use serde::Serialize;
+
+use crate::cache::{ListCache, CAPABILITIES_FILE};
+use crate::config::ConfigPaths;
+use crate::error::Result;
+use crate::output::{markdown::markdown_table, MarkdownOutput, Meta};
+
+/// Lookup list files kept in the cache directory.
+const LIST_FILES: &[&str] = &[
+    "activities.json",
+    "statuses.json",
+    "trackers.json",
+    "priorities.json",
+    "projects.json",
+    "project_ids.json",
+];
+
+/// Directory of cached API responses.
+const RESPONSES_DIR: &str = "responses";
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Show the cached lookup lists with their age and validity.
+    Status,
+    /// Delete the cached lookup lists, responses and declared server versions.
+    Clear,
+}
+
+/// A cached lookup list.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheEntry {
+    pub file: String,
+    pub path: String,
+    pub items: usize,
+    pub updated_at: u64,
+    pub age: String,
+    /// Still within the 24-hour TTL.
+    pub valid: bool,
+}
+
+/// Result of cache status command.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStatus {
+    pub path: String,
+    pub entries: Vec<CacheEntry>,
+    /// Number of cached API responses.
+    pub responses: usize,
+}
+
+impl MarkdownOutput for CacheStatus {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = format!("## Cache Status\n\nDirectory: `{}`\n\n", self.path);
+
+        if self.entries.is_empty() {
+            output.push_str("*No cached lookup lists*\n");
+        } else {
+            let rows = self
+                .entries
+                .iter()
+                .map(|e| {
+                    vec![
+                        e.file.clone(),
+                        e.items.to_string(),
+                        e.age.clone(),
+                        if e.valid { "Yes" } else { "Expired" }.to_string(),
+                    ]
+                })
+                .collect();
+            output.push_str(&markdown_table(&["File", "Items", "Age", "Valid"], rows));
+        }
+
+        output.push_str(&format!("\n**Cached responses**: {}\n", self.responses));
+        output.push_str("\n*Use `rdm cache clear` to delete the cache*\n");
+        output
+    }
+}
+
+/// Result of cache clear command.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheCleared {
+    pub path: String,
+    /// Cache files and directories removed.
+    pub removed: u32,
+}
+
+impl MarkdownOutput for CacheCleared {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        format!(
+            "## Cache Cleared\n\nRemoved {} item{} from {}.\n",
+            self.removed,
+            if self.removed == 1 { "" } else { "s" },
+            self.path
+        )
+    }
+}
+
+/// Execute cache status command.
+pub fn status(paths: &ConfigPaths) -> Result<CacheStatus> {
+    let dir = &paths.cache_dir;
+    let entry = |file: &str, items: usize, updated_at: u64, age: String, valid: bool| CacheEntry {
+        file: file.to_string(),
+        path: dir.join(file).display().to_string(),
+        items,
+        updated_at,
+        age,
+        valid,
+    };
+
+    let mut entries = Vec::new();
+    // An unreadable list is refetched on use, so it counts as absent
+    for file in LIST_FILES {
+        if let Some(cache) = ListCache::<serde_json::Value>::read(&dir.join(file)) {
+            entries.push(entry(
+                file,
+                cache.items.len(),
+                cache.updated_at,
+                cache.age_string(),
+                cache.is_valid(),
+            ));
+        }
+    }
+
+    let responses = std::fs::read_dir(dir.join(RESPONSES_DIR))
+        .map(|entries| entries.filter_map(|e| e.ok()).count())
+        .unwrap_or(0);
+
+    Ok(CacheStatus {
+        path: dir.display().to_string(),
+        entries,
+        responses,
+    })
+}
+
+/// Execute cache clear command; only files written by rdm are removed, and a
+/// missing cache directory counts as empty.
+pub fn clear(paths: &ConfigPaths) -> Result<CacheCleared> {
+    let mut removed = 0;
+    let files = LIST_FILES.iter().chain([&CAPABILITIES_FILE]);
+    for file in files {
+        let path = paths.cache_dir.join(file);
+        if path.is_file() {
+            std::fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    let responses = paths.cache_dir.join(RESPONSES_DIR);
+    if responses.is_dir() {
+        std::fs::remove_dir_all(&responses)?;
+        removed += 1;
+    }
+    Ok(CacheCleared {
+        path: paths.cache_dir.display().to_string(),
+        removed,
+    })
+}
@@ -0,0 +1,291 @@
+//! Doctor command: diagnose configuration, connectivity and environment.
+//!
+//! Every check is recorded instead of aborting the run, so one problem does
+//! not hide the others.
+
+use serde::Serialize;
+use std::path::Path;
+
+use crate::cache::ServerCapabilities;
+use crate::client::RedmineClient;
+use crate::config::{load_config, ConfigPaths};
+use crate::error::{AppError, AppExitCode};
+use crate::output::{markdown::markdown_table, MarkdownOutput, Meta};
+
+/// Clock differences above this many seconds are reported.
+const MAX_CLOCK_SKEW_SECS: i64 = 60;
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    /// Not run because an earlier check failed.
+    Skip,
+}
+
+impl CheckStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Pass => "PASS",
+            Self::Warn => "WARN",
+            Self::Fail => "FAIL",
+            Self::Skip => "SKIP",
+        }
+    }
+}
+
+/// A single doctor check.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// Exit code of the error behind a failed check.
+    #[serde(skip)]
+    pub exit_code: Option<AppExitCode>,
+}
+
+impl DoctorCheck {
+    fn new(name: &str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+            hint: None,
+            exit_code: None,
+        }
+    }
+
+    fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    fn from_error(name: &str, e: &AppError) -> Self {
+        let mut check = Self::new(name, CheckStatus::Fail, e.to_string());
+        check.exit_code = Some(e.exit_code());
+        match e.hint() {
+            Some(hint) => check.with_hint(hint),
+            None => check,
+        }
+    }
+}
+
+/// Result of doctor command.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    /// No check failed (warnings are allowed).
+    pub healthy: bool,
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    fn new(checks: Vec<DoctorCheck>) -> Self {
+        Self {
+            healthy: checks.iter().all(|c| c.status != CheckStatus::Fail),
+            checks,
+        }
+    }
+
+    /// Exit code of the first failed check, or success when none failed.
+    ///
+    /// A failure without an error behind it (the cache directory) counts as
+    /// an I/O error.
+    pub fn exit_code(&self) -> AppExitCode {
+        self.checks
+            .iter()
+            .filter(|c| c.status == CheckStatus::Fail)
+            .map(|c| c.exit_code.unwrap_or(AppExitCode::ApiError))
+            .next()
+            .unwrap_or(AppExitCode::Success)
+    }
+}
+
+impl MarkdownOutput for DoctorReport {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Doctor\n\n");
+
+        let rows = self
+            .checks
+            .iter()
+            .map(|c| {
+                vec![
+                    c.status.label().to_string(),
+                    c.name.clone(),
+                    c.detail.clone(),
+                ]
+            })
+            .collect();
+        output.push_str(&markdown_table(&["Result", "Check", "Details"], rows));
+
+        let hints: Vec<&DoctorCheck> = self.checks.iter().filter(|c| c.hint.is_some()).collect();
+        if !hints.is_empty() {
+            output.push_str("\n### Hints\n\n");
+            for check in hints {
+                output.push_str(&format!(
+                    "- **{}**: {}\n",
+                    check.name,
+                    check.hint.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+        output
+    }
+}
+
+/// Execute doctor command.
+///
+/// `timeout` and `language` apply to the server checks as they would to any
+/// other command.
+pub async fn execute(
+    cli_url: Option<&str>,
+    cli_api_key: Option<&str>,
+    timeout: Option<u64>,
+    language: Option<String>,
+    paths: &ConfigPaths,
+) -> DoctorReport {
+    let mut checks = Vec::new();
+    let server_checks = ["Connection", "TLS", "Server version", "API key", "Clock"];
+    let skipped = |names: &[&str], reason: &str| -> Vec<DoctorCheck> {
+        names
+            .iter()
+            .map(|name| DoctorCheck::new(name, CheckStatus::Skip, reason))
+            .collect()
+    };
+
+    let config = load_config(cli_url, cli_api_key, paths);
+    checks.push(match &config {
+        Ok(config) => DoctorCheck::new(
+            "Config",
+            CheckStatus::Pass,
+            match &config.profile_name {
+                Some(name) => format!("Profile '{}' ({})", name, config.url),
+                None => config.url.clone(),
+            },
+        ),
+        Err(e) => DoctorCheck::from_error("Config", e),
+    });
+    checks.push(check_cache_dir(&paths.cache_dir));
+
+    let Ok(mut config) = config else {
+        checks.extend(skipped(&server_checks, "No usable configuration"));
+        return DoctorReport::new(checks);
+    };
+    config.timeout_secs = timeout.or(config.timeout_secs);
+
+    let ping = match RedmineClient::new(&config, false) {
+        Ok(client) => {
+            let client = client.with_language(language);
+            client.ping().await.map(|ping| (client, ping))
+        }
+        Err(e) => Err(e),
+    };
+    let (client, ping) = match ping {
+        Ok(result) => result,
+        Err(e) => {
+            checks.push(DoctorCheck::from_error("Connection", &e));
+            checks.extend(skipped(&server_checks[1..], "Server not reachable"));
+            return DoctorReport::new(checks);
+        }
+    };
+    checks.push(DoctorCheck::new(
+        "Connection",
+        CheckStatus::Pass,
+        format!("Reached server in {} ms", ping.latency_ms),
+    ));
+
+    // A successful ping over https means rustls verified the certificate
+    checks.push(if config.url.starts_with("https://") {
+        DoctorCheck::new("TLS", CheckStatus::Pass, "HTTPS, certificate verified")
+    } else {
+        DoctorCheck::new(
+            "TLS",
+            CheckStatus::Warn,
+            "Plain HTTP, the API key is sent unencrypted",
+        )
+        .with_hint("Use an https:// URL if the server supports it")
+    });
+
+    // Redmine does not report its version through the API
+    checks.push(
+        match ServerCapabilities::load(&paths.cache_dir, &config.url).version {
+            Some(version) => DoctorCheck::new(
+                "Server version",
+                CheckStatus::Pass,
+                format!("Redmine {}", version),
+            ),
+            None => DoctorCheck::new("Server version", CheckStatus::Warn, "Unknown")
+                .with_hint("Declare it once with `--api-version X.Y` to enable version checks"),
+        },
+    );
+
+    checks.push(match client.me().await {
+        Ok(user) if user.admin == Some(true) => DoctorCheck::new(
+            "API key",
+            CheckStatus::Pass,
+            format!("Authenticated as {} (administrator)", user.login),
+        ),
+        Ok(user) => DoctorCheck::new(
+            "API key",
+            CheckStatus::Pass,
+            format!("Authenticated as {} (regular user)", user.login),
+        ),
+        Err(e) => DoctorCheck::from_error("API key", &e),
+    });
+
+    checks.push(check_clock(ping.server_date));
+    DoctorReport::new(checks)
+}
+
+/// Check that the cache directory can be created and written to.
+fn check_cache_dir(cache_dir: &Path) -> DoctorCheck {
+    let probe = cache_dir.join(".doctor");
+    let result = std::fs::create_dir_all(cache_dir)
+        .and_then(|_| std::fs::write(&probe, b"ok"))
+        .and_then(|_| std::fs::remove_file(&probe));
+    match result {
+        Ok(()) => DoctorCheck::new(
+            "Cache directory",
+            CheckStatus::Pass,
+            format!("{} is writable", cache_dir.display()),
+        ),
+        Err(e) => DoctorCheck::new(
+            "Cache directory",
+            CheckStatus::Fail,
+            format!("{} is not writable: {}", cache_dir.display(), e),
+        )
+        .with_hint("Fix the directory permissions or point REDMINE_CACHE_DIR elsewhere"),
+    }
+}
+
+/// Compare the local clock with the server `Date` header.
+fn check_clock(server_date: Option<chrono::DateTime<chrono::Utc>>) -> DoctorCheck {
+    let Some(server_date) = server_date else {
+        return DoctorCheck::new("Clock", CheckStatus::Warn, "Server sent no Date header");
+    };
+    let skew = (chrono::Utc::now() - server_date).num_seconds();
+    if skew.abs() > MAX_CLOCK_SKEW_SECS {
+        DoctorCheck::new(
+            "Clock",
+            CheckStatus::Warn,
+            format!(
+                "Local clock is {} s {} the server",
+                skew.abs(),
+                if skew > 0 { "ahead of" } else { "behind" }
+            ),
+        )
+        .with_hint("Sync the local clock; dates such as `today` are computed locally")
+    } else {
+        DoctorCheck::new(
+            "Clock",
+            CheckStatus::Pass,
+            format!("Within {} s of the server", MAX_CLOCK_SKEW_SECS),
+        )
+    }
+}
@@ -0,0 +1,88 @@
+//! Git helpers for inferring context from the working copy.
+
+use regex::Regex;
+use std::process::Command;
+
+use crate::error::{AppError, Result};
+
+/// Default pattern: a number delimited by `/`, `-`, `_`, `#` or the string bounds,
+/// e.g. `feature/1234-foo`, `1234_bar`, `fix-#42`.
+pub const DEFAULT_BRANCH_ISSUE_PATTERN: &str = r"(?:^|[/_#-])(\d+)(?:[/_-]|$)";
+
+/// Get the current git branch name.
+pub fn current_branch() -> Result<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .map_err(|e| {
+            AppError::validation_with_hint(
+                format!("Failed to run git: {}", e),
+                "Make sure git is installed and on PATH, or pass --issue explicitly",
+            )
+        })?;
+
+    if !output.status.success() {
+        return Err(AppError::validation_with_hint(
+            "Not inside a git repository",
+            "Run from a git working copy, or pass --issue explicitly",
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Extract an issue ID from a branch name using `pattern`.
+///
+/// The issue ID is taken from a capture group named `issue` if present,
+/// otherwise from the first capture group.
+pub fn branch_issue_id(branch: &str, pattern: &str) -> Result<Option<u32>> {
+    let re = Regex::new(pattern).map_err(|e| {
+        AppError::config_with_hint(
+            format!("Invalid branch_issue_pattern: {}", e),
+            "Fix it with `rdm config set branch_issue_pattern <regex>`",
+        )
+    })?;
+
+    let id = re.captures(branch).and_then(|caps| {
+        caps.name("issue")
+            .or_else(|| caps.get(1))
+            .and_then(|m| m.as_str().trim_start_matches('#').parse().ok())
+    });
+
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_id(branch: &str) -> Option<u32> {
+        branch_issue_id(branch, DEFAULT_BRANCH_ISSUE_PATTERN).unwrap()
+    }
+
+    #[test]
+    fn test_default_pattern() {
+        assert_eq!(default_id("feature/1234-foo"), Some(1234));
+        assert_eq!(default_id("1234-foo"), Some(1234));
+        assert_eq!(default_id("bugfix/42"), Some(42));
+        assert_eq!(default_id("fix-#77-login"), Some(77));
+        assert_eq!(default_id("hotfix_9_typo"), Some(9));
+    }
+
+    #[test]
+    fn test_default_pattern_no_match() {
+        assert_eq!(default_id("main"), None);
+        assert_eq!(default_id("feature/oauth2-login"), None);
+    }
+
+    #[test]
+    fn test_named_group_pattern() {
+        let id = branch_issue_id("RM-555/cleanup", r"^RM-(?<issue>\d+)").unwrap();
+        assert_eq!(id, Some(555));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(branch_issue_id("main", "(").is_err());
+    }
+}
@@ -1,18 +1,39 @@
 //! Issue commands.
 
-use clap::{Args, Subcommand};
-use serde::Serialize;
-use std::path::PathBuf;
+use chrono::{DateTime, Days, Local, Months, NaiveDate};
+use clap::{Args, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::future::Future;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 
-use super::parse_custom_fields;
-use crate::client::{endpoints::IssueFilters, RedmineClient};
+use super::{
+    cached_list, limit_parser, page_size_parser, parse_custom_fields, parse_date_arg, parse_hours,
+    parse_sort, read_arg_value, require_results, resolve_me, resolve_project_write_id,
+};
+use crate::cache::Feature;
+use crate::client::{
+    concurrent::{map_concurrent, DEFAULT_CONCURRENCY},
+    dry_run::WriteOutcome,
+    endpoints::{IssueFilters, MAX_PAGE_SIZE},
+    RedmineClient,
+};
+use crate::config::ConfigPaths;
 use crate::error::{AppError, Result};
 use crate::models::{
     attachment::{guess_content_type, AttachmentRef},
-    AttachmentDownloaded, AttachmentList, AttachmentUploaded, CustomFieldValue, Issue, IssueList,
-    NewIssue, UpdateIssue,
+    Attachment, AttachmentDownloaded, AttachmentList, AttachmentUploaded, CustomField,
+    CustomFieldValue, GroupedIssues, Issue, IssueGroupByField, IssueList, IssueProjection,
+    IssueRelation, IssueRelationCreated, IssueRelationDeleted, IssueRelations, NewIssue,
+    NewIssueRelation, Priority, PriorityList, Status, StatusList, Tracker, TrackerList,
+    UpdateIssue, ISSUE_FIELDS, RELATION_TYPES,
+};
+use crate::output::{
+    markdown::{markdown_kv_table, markdown_table, shift_headings},
+    CsvOutput, MarkdownOutput, Meta,
 };
-use crate::output::{markdown::markdown_kv_table, MarkdownOutput, Meta};
 
 #[derive(Debug, Subcommand)]
 pub enum IssueCommand {
@@ -24,9 +45,94 @@ pub enum IssueCommand {
     Create(IssueCreateArgs),
     /// Update an issue.
     Update(IssueUpdateArgs),
+    /// Add a note to an issue.
+    Comment(IssueCommentArgs),
+    /// Watch every issue in a project.
+    WatchProject(IssueWatchProjectArgs),
+    /// Add or remove issue watchers.
+    #[command(subcommand)]
+    Watchers(WatcherCommand),
+    /// List issue statuses and their IDs.
+    Statuses(IssueLookupArgs),
+    /// List trackers and their IDs.
+    Trackers(IssueLookupArgs),
+    /// List issue priorities and their IDs.
+    Priorities(IssueLookupArgs),
     /// Attachment commands.
     #[command(subcommand)]
     Attachment(AttachmentCommand),
+    /// Relation commands.
+    #[command(subcommand)]
+    Relations(RelationCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RelationCommand {
+    /// List the relations of an issue.
+    List(RelationListArgs),
+    /// Relate an issue to another one.
+    Add(RelationAddArgs),
+    /// Delete a relation by ID.
+    Delete(RelationDeleteArgs),
+    /// Print the blocks/blocked-by dependency graph of an issue.
+    Graph(RelationGraphArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RelationListArgs {
+    /// Issue ID.
+    #[arg(long)]
+    pub id: u32,
+}
+
+#[derive(Debug, Args)]
+pub struct RelationAddArgs {
+    /// Issue ID.
+    #[arg(long)]
+    pub id: u32,
+    /// ID of the related issue.
+    #[arg(long, value_name = "ISSUE_ID")]
+    pub relates_to: u32,
+    /// Relation type: relates, duplicates, duplicated, blocks, blocked, precedes,
+    /// follows, copied_to or copied_from.
+    #[arg(long = "type", default_value = "relates")]
+    pub relation_type: String,
+    /// Delay in days (precedes/follows only).
+    #[arg(long)]
+    pub delay: Option<i32>,
+}
+
+#[derive(Debug, Args)]
+pub struct RelationDeleteArgs {
+    /// Relation ID (see `rdm issue relations list`).
+    #[arg(long)]
+    pub id: u32,
+}
+
+#[derive(Debug, Args)]
+pub struct RelationGraphArgs {
+    /// Issue ID to start from.
+    #[arg(long)]
+    pub id: u32,
+    /// Number of relation hops to follow in each direction.
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..=20))]
+    pub depth: u32,
+    /// Render as an indented tree (text) or a Graphviz digraph (dot).
+    #[arg(long, value_enum, default_value_t = GraphStyle::Text)]
+    pub style: GraphStyle,
+    /// Maximum number of concurrent requests per level.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+}
+
+/// Rendering of `issue relations graph` in Markdown mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum GraphStyle {
+    /// Indented "blocked by" and "blocks" trees.
+    #[default]
+    Text,
+    /// Graphviz DOT, e.g. `| dot -Tsvg > deps.svg`.
+    Dot,
 }
 
 #[derive(Debug, Subcommand)]
@@ -44,6 +150,9 @@ pub struct AttachmentListArgs {
     /// Issue ID.
     #[arg(long)]
     pub issue_id: u32,
+    /// Download every attachment into this directory (created if missing).
+    #[arg(long, value_name = "DIR")]
+    pub download: Option<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -72,20 +181,54 @@ pub struct AttachmentUploadArgs {
     pub description: Option<String>,
 }
 
-#[derive(Debug, Args)]
+#[derive(Debug, Clone, Args)]
 pub struct IssueListArgs {
-    /// Filter by project (ID or identifier).
+    /// Start from a named filter combination (see --list-presets); flags take precedence.
+    #[arg(long, value_name = "NAME")]
+    pub preset: Option<String>,
+    /// Shorthand for --preset i-reported (issues you authored).
+    #[arg(long, conflicts_with = "preset")]
+    pub i_reported: bool,
+    /// Shorthand for --preset delegated (issues you authored that others are assigned to).
+    #[arg(
+        long,
+        alias = "created-by-me-assigned-to-others",
+        conflicts_with_all = ["preset", "i_reported"]
+    )]
+    pub delegated: bool,
+    /// Print the available presets and exit.
+    #[arg(long)]
+    pub list_presets: bool,
+    /// Read filters from a TOML file; flags given on the command line take precedence.
+    #[arg(long, value_name = "PATH")]
+    pub filter_file: Option<PathBuf>,
+    /// Filter by project (ID or identifier); comma-separate several to merge their issues.
     #[arg(long)]
     pub project: Option<String>,
-    /// Filter by status (ID, "open", "closed", or "*").
+    /// Ignore the profile's default project and list issues from every project.
+    #[arg(long, conflicts_with = "project")]
+    pub all_projects: bool,
+    /// Default project of the active profile, used when no project is given.
+    #[arg(skip)]
+    pub default_project: Option<String>,
+    /// Filter by status (ID, name, "open", "closed", or "*").
     #[arg(long)]
     pub status: Option<String>,
     /// Filter by assignee (ID or "me").
     #[arg(long)]
     pub assigned_to: Option<String>,
+    /// Only issues with no assignee.
+    #[arg(long, alias = "assignee-unset", conflicts_with = "assigned_to")]
+    pub unassigned: bool,
+    /// Exclude issues assigned to this user (ID or "me"); unassigned issues are kept.
+    #[arg(long, value_name = "ID|me", conflicts_with_all = ["assigned_to", "unassigned"])]
+    pub not_assigned_to: Option<String>,
     /// Filter by author (ID or "me").
     #[arg(long)]
     pub author: Option<String>,
+    /// Filter by watcher (ID or "me").
+    #[arg(long, value_name = "ID|me")]
+    pub watched_by: Option<String>,
     /// Filter by tracker ID.
     #[arg(long)]
     pub tracker: Option<String>,
@@ -95,33 +238,103 @@ pub struct IssueListArgs {
     /// Search issues by text (searches subject and description).
     #[arg(long)]
     pub search: Option<String>,
+    /// Only closed issues closed on/after a date (YYYY-MM-DD) or window (e.g. 7d, 2w, 1m).
+    #[arg(long, value_name = "DATE|WINDOW", conflicts_with_all = ["status", "search"])]
+    pub closed_since: Option<String>,
+    /// Only open issues due between today and a window ahead (e.g. 3d, 2w, 1m), soonest first.
+    #[arg(long, value_name = "WINDOW", conflicts_with_all = ["search", "closed_since"])]
+    pub due_within: Option<String>,
+    /// Only open issues whose due date has passed, oldest first.
+    #[arg(long, conflicts_with_all = ["search", "closed_since", "due_within"])]
+    pub overdue: bool,
     /// Filter by custom field value (format: id=value, repeatable).
     #[arg(long = "cf", value_name = "ID=VALUE")]
     pub custom_fields: Vec<String>,
-    /// Maximum number of results.
-    #[arg(long, default_value = "25")]
+    /// Sort by one or more keys, e.g. updated_on:desc,priority:desc (overrides implied sorts).
+    #[arg(long, value_name = "KEY[:asc|desc],...")]
+    pub sort: Option<String>,
+    /// Group results by field (status, priority, assignee, tracker, or project).
+    #[arg(long)]
+    pub group_by: Option<String>,
+    /// Fetch each issue's full record so custom fields are complete (one extra request per issue).
+    #[arg(long)]
+    pub with_custom_fields: bool,
+    /// Add a custom field as a Markdown table column (repeatable).
+    #[arg(long = "cf-column", value_name = "ID")]
+    pub cf_columns: Vec<u32>,
+    /// Append a legend mapping tracker/status/priority IDs to names (Markdown only).
+    #[arg(long, conflicts_with = "group_by")]
+    pub legend: bool,
+    /// Maximum number of concurrent requests for --with-custom-fields.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+    /// Fetch every page instead of one (streamed line by line with --format ndjson).
+    #[arg(long, conflicts_with_all = ["search", "closed_since"])]
+    pub all: bool,
+    /// Write every matching issue to a file as NDJSON, page by page (implies --all).
+    #[arg(
+        long,
+        value_name = "PATH",
+        alias = "json-lines-to",
+        conflicts_with_all = ["search", "closed_since", "group_by"]
+    )]
+    pub stream_to: Option<PathBuf>,
+    /// Issues fetched per request with --all or --stream-to (1-100).
+    #[arg(
+        long,
+        alias = "limit-per-request",
+        value_name = "N",
+        default_value_t = MAX_PAGE_SIZE,
+        value_parser = page_size_parser()
+    )]
+    pub page_size: u32,
+    /// Exit with code 4 (not found) when no issue matches.
+    #[arg(long)]
+    pub require_results: bool,
+    /// Maximum number of results (at most 100; 0 uses the server default).
+    #[arg(long, default_value = "25", value_parser = limit_parser())]
     pub limit: u32,
     /// Offset for pagination.
     #[arg(long, default_value = "0")]
     pub offset: u32,
 }
 
+/// Parser for `--done-ratio`, shared by create and update.
+fn done_ratio_parser() -> clap::builder::RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(0..=100)
+}
+
 #[derive(Debug, Args)]
 pub struct IssueGetArgs {
-    /// Issue ID.
+    /// Issue ID, or a comma-separated list of IDs.
+    #[arg(long, value_delimiter = ',', num_args = 1.., required = true)]
+    pub id: Vec<u32>,
+    /// Maximum number of concurrent requests when fetching several issues.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+    /// Only show these fields of a single issue (comma-separated, e.g. id,subject,status).
+    #[arg(long, value_delimiter = ',')]
+    pub fields: Vec<String>,
+    /// Show the description verbatim in a code block (for Textile-formatted servers).
     #[arg(long)]
-    pub id: u32,
+    pub plain_description: bool,
+    /// Show the full change history (field changes as well as comments), oldest first.
+    #[arg(long)]
+    pub include_journals: bool,
 }
 
 #[derive(Debug, Args)]
 pub struct IssueCreateArgs {
-    /// Project ID.
+    /// Project ID or identifier (defaults to the profile's default project).
     #[arg(long)]
-    pub project: u32,
+    pub project: Option<String>,
+    /// Default project of the active profile, used when --project is omitted.
+    #[arg(skip)]
+    pub default_project: Option<String>,
     /// Issue subject.
     #[arg(long)]
     pub subject: String,
-    /// Issue description.
+    /// Issue description (`-` reads stdin, `@path` reads a file, `@@` escapes a leading `@`).
     #[arg(long)]
     pub description: Option<String>,
     /// Tracker ID.
@@ -136,18 +349,33 @@ pub struct IssueCreateArgs {
     /// Assignee ID.
     #[arg(long)]
     pub assigned_to: Option<u32>,
-    /// Start date (YYYY-MM-DD).
+    /// Target version (name or ID) available to the project.
+    #[arg(long, alias = "version", value_name = "VERSION")]
+    pub fixed_version: Option<String>,
+    /// Parent issue ID, making the new issue a subtask.
+    #[arg(long, value_name = "ISSUE_ID")]
+    pub parent: Option<u32>,
+    /// Add a watcher by user ID (repeatable).
+    #[arg(long = "watcher", value_name = "USER_ID")]
+    pub watchers: Vec<u32>,
+    /// Start date (YYYY-MM-DD, `today` or `yesterday`).
     #[arg(long)]
     pub start_date: Option<String>,
-    /// Due date (YYYY-MM-DD).
+    /// Due date (YYYY-MM-DD, `today` or `yesterday`).
     #[arg(long)]
     pub due_date: Option<String>,
     /// Estimated hours.
     #[arg(long)]
     pub estimated_hours: Option<f64>,
+    /// Done percentage (0-100).
+    #[arg(long, value_parser = done_ratio_parser())]
+    pub done_ratio: Option<u32>,
     /// Set custom field value (format: id=value, repeatable).
     #[arg(long = "cf", value_name = "ID=VALUE")]
     pub custom_fields: Vec<String>,
+    /// Attach a file (repeatable).
+    #[arg(long = "attach", value_name = "PATH")]
+    pub attachments: Vec<PathBuf>,
 }
 
 #[derive(Debug, Args)]
@@ -158,27 +386,122 @@ pub struct IssueUpdateArgs {
     /// New subject.
     #[arg(long)]
     pub subject: Option<String>,
-    /// New description.
+    /// New description (`-` reads stdin, `@path` reads a file, `@@` escapes a leading `@`).
     #[arg(long)]
     pub description: Option<String>,
-    /// New status ID.
+    /// New tracker (name or ID).
     #[arg(long)]
-    pub status: Option<u32>,
-    /// New priority ID.
+    pub tracker: Option<String>,
+    /// New status (name or ID).
     #[arg(long)]
-    pub priority: Option<u32>,
+    pub status: Option<String>,
+    /// New priority (name or ID).
+    #[arg(long)]
+    pub priority: Option<String>,
     /// New assignee ID.
     #[arg(long)]
     pub assigned_to: Option<u32>,
-    /// Done percentage (0-100).
+    /// New parent issue ID.
+    #[arg(long, value_name = "ISSUE_ID")]
+    pub parent: Option<u32>,
+    /// Remove the parent issue.
+    #[arg(long, conflicts_with = "parent")]
+    pub clear_parent: bool,
+    /// New start date (YYYY-MM-DD, `today` or `yesterday`).
+    #[arg(long)]
+    pub start_date: Option<String>,
+    /// Remove the start date.
+    #[arg(long, conflicts_with = "start_date")]
+    pub clear_start_date: bool,
+    /// New due date (YYYY-MM-DD, `today` or `yesterday`).
+    #[arg(long)]
+    pub due_date: Option<String>,
+    /// Remove the due date.
+    #[arg(long, conflicts_with = "due_date")]
+    pub clear_due_date: bool,
+    /// Estimated time (e.g. 2.5, 1h30m, 45m or 1:30).
     #[arg(long)]
+    pub estimated_hours: Option<String>,
+    /// Remove the estimated time.
+    #[arg(long, conflicts_with = "estimated_hours")]
+    pub clear_estimated_hours: bool,
+    /// Done percentage (0-100).
+    #[arg(long, value_parser = done_ratio_parser())]
     pub done_ratio: Option<u32>,
-    /// Add a note/comment.
+    /// Add a note/comment (`-` reads stdin, `@path` reads a file, `@@` escapes a leading `@`).
     #[arg(long)]
     pub notes: Option<String>,
     /// Set custom field value (format: id=value, repeatable).
     #[arg(long = "cf", value_name = "ID=VALUE")]
     pub custom_fields: Vec<String>,
+    /// Add a value to a custom field, keeping existing values (format: id=value, repeatable).
+    #[arg(long = "add-cf", value_name = "ID=VALUE")]
+    pub add_custom_fields: Vec<String>,
+    /// Remove a value from a custom field (format: id=value, repeatable).
+    #[arg(long = "remove-cf", value_name = "ID=VALUE")]
+    pub remove_custom_fields: Vec<String>,
+    /// Allow --add-cf to replace the value of an already-set single-value field.
+    #[arg(long)]
+    pub force: bool,
+    /// Abort if the issue's updated_on no longer matches this value (as last read).
+    #[arg(long, value_name = "UPDATED_ON")]
+    pub if_unchanged_since: Option<String>,
+    /// Let the server reject the update if the issue's lock_version is no longer this value.
+    #[arg(long, value_name = "N")]
+    pub lock_version: Option<u32>,
+}
+
+#[derive(Debug, Args)]
+pub struct IssueCommentArgs {
+    /// Issue ID.
+    #[arg(long)]
+    pub id: u32,
+    /// Note text (`-` reads stdin, `@path` reads a file, `@@` escapes a leading `@`).
+    pub note: String,
+    /// Only visible to users allowed to see private notes.
+    #[arg(long)]
+    pub private: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct IssueLookupArgs {
+    /// Force refresh from server (ignore cache).
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum WatcherCommand {
+    /// Add a watcher to an issue.
+    Add(IssueWatcherArgs),
+    /// Remove a watcher from an issue.
+    Remove(IssueWatcherArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct IssueWatcherArgs {
+    /// Issue ID.
+    #[arg(long)]
+    pub id: u32,
+    /// User ID, or "me" (needs a lookup, so not with --dry-run).
+    #[arg(long)]
+    pub user: String,
+}
+
+#[derive(Debug, Args)]
+pub struct IssueWatchProjectArgs {
+    /// Project ID or identifier.
+    #[arg(long)]
+    pub project: String,
+    /// Filter by status (ID, "open", "closed", or "*").
+    #[arg(long, default_value = "*")]
+    pub status: String,
+    /// Maximum number of concurrent watcher requests.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+    /// Confirm adding yourself as a watcher to every matching issue.
+    #[arg(long)]
+    pub yes: bool,
 }
 
 /// Result of issue creation.
@@ -188,27 +511,12 @@ pub struct IssueCreated {
 }
 
 impl MarkdownOutput for IssueCreated {
-    fn to_markdown(&self, _meta: &Meta) -> String {
-        let i = &self.issue;
-        let mut output = String::new();
-        output.push_str("## Issue Created\n\n");
-
-        let pairs = [
-            ("ID", i.id.to_string()),
-            ("Subject", i.subject.clone()),
-            ("Project", i.project.name.clone()),
-            ("Status", i.status.name.clone()),
-            ("Priority", i.priority.name.clone()),
-        ];
-
-        let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
-        output.push_str(&markdown_kv_table(&pairs_ref));
-
-        output.push_str(&format!(
-            "\n*Use `rdm issue get --id {}` to view full details*\n",
-            i.id
-        ));
-        output
+    fn to_markdown(&self, meta: &Meta) -> String {
+        // Show the issue as `issue get` would, nested under the confirmation heading.
+        format!(
+            "## Issue Created\n\n{}",
+            shift_headings(&self.issue.to_markdown(meta), 1)
+        )
     }
 }
 
@@ -224,179 +532,1907 @@ impl MarkdownOutput for IssueUpdated {
     }
 }
 
+/// Result of issue comment.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueCommented {
+    pub id: u32,
+    pub private: bool,
+}
+
+impl MarkdownOutput for IssueCommented {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        format!(
+            "## Comment Added\n\nAdded a {}note to issue #{}.\n\n*Use `rdm issue get --id {}` to view it*\n",
+            if self.private { "private " } else { "" },
+            self.id,
+            self.id
+        )
+    }
+}
+
+/// Result of streaming issues to a file.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuesStreamed {
+    pub path: PathBuf,
+    pub count: u64,
+}
+
+impl MarkdownOutput for IssuesStreamed {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        format!(
+            "## Issues Exported\n\nWrote {} issue{} to `{}` as NDJSON.\n",
+            self.count,
+            if self.count == 1 { "" } else { "s" },
+            self.path.display()
+        )
+    }
+}
+
+/// Result of adding or removing a watcher.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatcherChanged {
+    pub issue_id: u32,
+    pub user_id: u32,
+    pub watching: bool,
+}
+
+impl MarkdownOutput for WatcherChanged {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        if self.watching {
+            format!(
+                "## Watcher Added\n\nUser #{} is now watching issue #{}.\n",
+                self.user_id, self.issue_id
+            )
+        } else {
+            format!(
+                "## Watcher Removed\n\nUser #{} is no longer watching issue #{}.\n",
+                self.user_id, self.issue_id
+            )
+        }
+    }
+}
+
+/// An issue that could not be watched.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchFailure {
+    pub issue_id: u32,
+    pub error: String,
+}
+
+/// Result of watching all issues in a project.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectWatched {
+    pub project: String,
+    pub user_id: Option<u32>,
+    pub watched: Vec<u32>,
+    pub failed: Vec<WatchFailure>,
+}
+
+impl MarkdownOutput for ProjectWatched {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("## Watching Project: {}\n\n", self.project));
+
+        let pairs = [
+            ("Watched", self.watched.len().to_string()),
+            ("Failed", self.failed.len().to_string()),
+        ];
+        let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
+        output.push_str(&markdown_kv_table(&pairs_ref));
+
+        if !self.failed.is_empty() {
+            output.push_str("\n### Failures\n\n");
+            let rows = self
+                .failed
+                .iter()
+                .map(|f| vec![format!("#{}", f.issue_id), f.error.clone()])
+                .collect();
+            output.push_str(&markdown_table(&["Issue", "Error"], rows));
+        }
+
+        output
+    }
+}
+
 /// Parse custom field arguments into CustomFieldValue vec, or None if empty.
+///
+/// A repeated `--cf` for the same ID sets a multi-value field; commas are
+/// kept as part of the value.
 fn parse_custom_field_values(args: &[String]) -> Result<Option<Vec<CustomFieldValue>>> {
     if args.is_empty() {
         Ok(None)
     } else {
-        let parsed = parse_custom_fields(args)?;
-        Ok(Some(CustomFieldValue::from_tuples(parsed)))
+        Ok(Some(CustomFieldValue::from_tuples(parse_custom_fields(
+            args,
+        )?)))
     }
 }
 
-/// Execute issue list command.
-pub async fn list(client: &RedmineClient, args: &IssueListArgs) -> Result<IssueList> {
-    // Parse custom field filters
-    let custom_fields = parse_custom_fields(&args.custom_fields)?;
+/// Apply `--add-cf`/`--remove-cf` edits to an issue's current custom field values.
+///
+/// Multi-value fields are treated as sets. Single-value fields reject an add
+/// when a different value is already set, unless `force` is given.
+fn merge_custom_field_edits(
+    current: &[CustomField],
+    adds: &[(u32, String)],
+    removes: &[(u32, String)],
+    force: bool,
+) -> Result<Vec<CustomFieldValue>> {
+    let mut ids: Vec<u32> = Vec::new();
+    for (id, _) in adds.iter().chain(removes) {
+        if !ids.contains(id) {
+            ids.push(*id);
+        }
+    }
 
-    let filters = IssueFilters {
-        project: args.project.clone(),
-        status: args.status.clone(),
-        assigned_to: args.assigned_to.clone(),
-        author: args.author.clone(),
-        tracker: args.tracker.clone(),
-        subject: args.subject.clone(),
-        custom_fields,
-        limit: args.limit,
-        offset: args.offset,
-    };
+    let mut result = Vec::new();
+    for id in ids {
+        let field = current.iter().find(|cf| cf.id == id).ok_or_else(|| {
+            AppError::validation_with_hint(
+                format!("Custom field {} is not available on this issue", id),
+                "Use `rdm issue get --id <ID>` to see the issue's custom fields",
+            )
+        })?;
+        let to_add = adds.iter().filter(|(i, _)| *i == id).map(|(_, v)| v);
+        let to_remove: Vec<&String> = removes
+            .iter()
+            .filter(|(i, _)| *i == id)
+            .map(|(_, v)| v)
+            .collect();
 
-    // If search is specified, use search endpoint instead
-    if let Some(query) = &args.search {
-        return client
-            .search_issues(query, args.project.as_deref(), args.limit, args.offset)
-            .await;
+        if field.is_multiple() {
+            let mut values = field.values();
+            for value in to_add {
+                if !values.contains(value) {
+                    values.push(value.clone());
+                }
+            }
+            values.retain(|v| !to_remove.contains(&v));
+            result.push(CustomFieldValue::multiple(id, values));
+        } else {
+            let mut value = field.values().into_iter().next();
+            for new_value in to_add {
+                match &value {
+                    Some(existing) if existing != new_value && !force => {
+                        return Err(AppError::validation_with_hint(
+                            format!(
+                                "Custom field '{}' ({}) already has value '{}'",
+                                field.name, id, existing
+                            ),
+                            "It is a single-value field; use --force to replace the value",
+                        ));
+                    }
+                    _ => value = Some(new_value.clone()),
+                }
+            }
+            if value.as_ref().is_some_and(|v| to_remove.contains(&v)) {
+                value = None;
+            }
+            result.push(CustomFieldValue::new(id, value.unwrap_or_default()));
+        }
     }
 
-    client.list_issues(filters).await
+    Ok(result)
 }
 
-/// Execute issue get command.
-pub async fn get(client: &RedmineClient, args: &IssueGetArgs) -> Result<Issue> {
-    client.get_issue(args.id).await
+/// Saved issue filters loaded from `--filter-file`.
+///
+/// Keys mirror the `issue list` flags; custom fields go in a `[cf]` table
+/// keyed by field ID.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FilterFile {
+    project: Option<String>,
+    status: Option<String>,
+    assigned_to: Option<String>,
+    #[serde(default)]
+    unassigned: bool,
+    author: Option<String>,
+    watched_by: Option<String>,
+    tracker: Option<String>,
+    subject: Option<String>,
+    closed_since: Option<String>,
+    due_within: Option<String>,
+    sort: Option<String>,
+    #[serde(default)]
+    cf: BTreeMap<String, String>,
 }
 
-/// Execute issue create command.
-pub async fn create(client: &RedmineClient, args: &IssueCreateArgs) -> Result<IssueCreated> {
-    let custom_fields = parse_custom_field_values(&args.custom_fields)?;
+/// Issue columns Redmine can sort by (plus `cf_<id>`).
+const SORT_KEYS: &[&str] = &[
+    "id",
+    "project",
+    "tracker",
+    "status",
+    "priority",
+    "subject",
+    "author",
+    "assigned_to",
+    "category",
+    "fixed_version",
+    "parent",
+    "start_date",
+    "due_date",
+    "estimated_hours",
+    "spent_hours",
+    "done_ratio",
+    "created_on",
+    "updated_on",
+    "closed_on",
+];
 
-    let issue = NewIssue {
-        project_id: args.project,
-        subject: args.subject.clone(),
-        description: args.description.clone(),
-        tracker_id: args.tracker,
-        status_id: args.status,
-        priority_id: args.priority,
-        assigned_to_id: args.assigned_to,
-        start_date: args.start_date.clone(),
-        due_date: args.due_date.clone(),
-        estimated_hours: args.estimated_hours,
-        custom_fields,
-    };
+/// Built-in `--preset` names and the filters they expand to.
+const PRESETS: &[(&str, &str)] = &[
+    ("mine", "--assigned-to me --status open"),
+    ("i-reported", "--author me"),
+    ("delegated", "--author me --not-assigned-to me"),
+    ("overdue", "--overdue (open issues past their due date)"),
+    ("unassigned", "--unassigned --status open"),
+];
 
-    let created = client.create_issue(issue).await?;
-    Ok(IssueCreated { issue: created })
+/// Available issue list presets.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuePresets {
+    pub presets: Vec<IssuePreset>,
 }
 
-/// Execute issue update command.
-pub async fn update(client: &RedmineClient, args: &IssueUpdateArgs) -> Result<IssueUpdated> {
-    let custom_fields = parse_custom_field_values(&args.custom_fields)?;
+/// A named filter combination.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuePreset {
+    pub name: String,
+    pub expands_to: String,
+}
 
-    let update = UpdateIssue {
-        subject: args.subject.clone(),
-        description: args.description.clone(),
-        status_id: args.status,
-        priority_id: args.priority,
-        assigned_to_id: args.assigned_to,
-        done_ratio: args.done_ratio,
-        notes: args.notes.clone(),
-        custom_fields,
-        ..Default::default()
-    };
+impl MarkdownOutput for IssuePresets {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::from("## Issue List Presets\n\n");
+        let rows = self
+            .presets
+            .iter()
+            .map(|p| vec![p.name.clone(), format!("`{}`", p.expands_to)])
+            .collect();
+        output.push_str(&markdown_table(&["Preset", "Expands To"], rows));
+        output.push_str(
+            "\n*Use `rdm issue list --preset <name>`; explicit flags override the preset*\n",
+        );
+        output
+    }
+}
 
-    client.update_issue(args.id, update).await?;
-    Ok(IssueUpdated { id: args.id })
+/// Execute issue list --list-presets.
+pub fn presets() -> IssuePresets {
+    IssuePresets {
+        presets: PRESETS
+            .iter()
+            .map(|(name, expands_to)| IssuePreset {
+                name: name.to_string(),
+                expands_to: expands_to.to_string(),
+            })
+            .collect(),
+    }
 }
 
-/// List attachments on an issue.
-pub async fn attachment_list(
-    client: &RedmineClient,
-    args: &AttachmentListArgs,
-) -> Result<AttachmentList> {
-    let issue = client.get_issue(args.issue_id).await?;
-    Ok(AttachmentList {
-        issue_id: args.issue_id,
-        attachments: issue.attachments.unwrap_or_default(),
-    })
+/// Fill in the filters of the selected preset that were not given explicitly.
+fn with_preset(mut args: IssueListArgs) -> Result<IssueListArgs> {
+    let name = match (&args.preset, args.i_reported, args.delegated) {
+        (Some(name), _, _) => name.clone(),
+        (None, true, _) => "i-reported".to_string(),
+        (None, _, true) => "delegated".to_string(),
+        (None, false, false) => return Ok(args),
+    };
+    let open = Some("open".to_string());
+
+    match name.as_str() {
+        "mine" => {
+            if args.assigned_to.is_none() && !args.unassigned {
+                args.assigned_to = Some("me".to_string());
+            }
+            args.status = args.status.or(open);
+        }
+        "i-reported" => {
+            args.author = args.author.or_else(|| Some("me".to_string()));
+        }
+        "delegated" => {
+            args.author = args.author.or_else(|| Some("me".to_string()));
+            if args.assigned_to.is_none() && !args.unassigned {
+                args.not_assigned_to = args.not_assigned_to.or_else(|| Some("me".to_string()));
+            }
+        }
+        "overdue" => {
+            if args.due_within.is_none() {
+                args.overdue = true;
+            }
+        }
+        "unassigned" => {
+            if args.assigned_to.is_none() {
+                args.unassigned = true;
+            }
+            args.status = args.status.or(open);
+        }
+        _ => {
+            return Err(AppError::validation_with_hint(
+                format!("Unknown preset: '{}'", name),
+                "Use `rdm issue list --list-presets` to see the available presets",
+            ))
+        }
+    }
+    Ok(args)
 }
 
-/// Download an attachment.
-pub async fn attachment_download(
-    client: &RedmineClient,
-    args: &AttachmentDownloadArgs,
-) -> Result<AttachmentDownloaded> {
-    let attachment = client.get_attachment(args.id).await?;
-    let bytes = client.download_attachment(&attachment.content_url).await?;
+/// Fall back to the profile's default project when no project filter is set.
+fn with_default_project(mut args: IssueListArgs) -> IssueListArgs {
+    if args.project.is_none() && !args.all_projects {
+        args.project = args.default_project.clone();
+    }
+    args
+}
 
-    let output_path = match &args.output {
-        Some(p) if p.is_dir() => p.join(&attachment.filename),
-        Some(p) => p.clone(),
-        None => PathBuf::from(&attachment.filename),
+/// Merge `--filter-file` values into the list arguments, keeping explicit flags.
+fn with_filter_file(args: &IssueListArgs) -> Result<IssueListArgs> {
+    let mut merged = args.clone();
+    let Some(path) = &args.filter_file else {
+        return Ok(merged);
     };
 
-    tokio::fs::write(&output_path, &bytes).await.map_err(|e| {
-        AppError::api(
-            format!("Failed to write {}: {}", output_path.display(), e),
-            None,
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AppError::validation_with_hint(
+            format!("Cannot read filter file {}: {}", path.display(), e),
+            "Pass a path to a TOML file with keys such as project, status and assigned_to",
         )
     })?;
+    let file: FilterFile = toml::from_str(&content).map_err(|e| {
+        AppError::validation(format!("Invalid filter file {}: {}", path.display(), e))
+    })?;
 
-    Ok(AttachmentDownloaded {
-        id: attachment.id,
-        filename: attachment.filename,
-        saved_to: output_path,
-        bytes: bytes.len() as u64,
-    })
+    merged.project = merged.project.or(file.project);
+    merged.status = merged.status.or(file.status);
+    // Assignee filters from the command line replace the file's, whichever kind they are
+    if merged.assigned_to.is_none() && !merged.unassigned && merged.not_assigned_to.is_none() {
+        merged.assigned_to = file.assigned_to;
+        merged.unassigned = file.unassigned;
+    }
+    merged.author = merged.author.or(file.author);
+    merged.watched_by = merged.watched_by.or(file.watched_by);
+    merged.tracker = merged.tracker.or(file.tracker);
+    merged.subject = merged.subject.or(file.subject);
+    merged.closed_since = merged.closed_since.or(file.closed_since);
+    merged.due_within = merged.due_within.or(file.due_within);
+    merged.sort = merged.sort.or(file.sort);
+
+    // A field ID given with --cf replaces the file's value for it
+    let cli_ids: Vec<String> = merged
+        .custom_fields
+        .iter()
+        .filter_map(|cf| cf.split_once('=').map(|(id, _)| id.trim().to_string()))
+        .collect();
+    merged.custom_fields.extend(
+        file.cf
+            .iter()
+            .filter(|(id, _)| !cli_ids.contains(id))
+            .map(|(id, value)| format!("{}={}", id, value)),
+    );
+
+    check_filter_conflicts(&merged)?;
+    Ok(merged)
 }
 
-/// Upload a file and attach it to an issue.
-pub async fn attachment_upload(
+/// Flag pairs clap rejects together that a filter file can also set.
+const FILTER_CONFLICTS: &[(&str, &str)] = &[
+    ("--all-projects", "--project"),
+    ("--unassigned", "--assigned-to"),
+    ("--closed-since", "--status"),
+    ("--closed-since", "--search"),
+    ("--due-within", "--search"),
+    ("--due-within", "--closed-since"),
+    ("--overdue", "--closed-since"),
+    ("--overdue", "--due-within"),
+    ("--all", "--closed-since"),
+    ("--stream-to", "--closed-since"),
+];
+
+/// Re-check the flag conflicts after merging a filter file, since clap only
+/// sees the command line.
+fn check_filter_conflicts(args: &IssueListArgs) -> Result<()> {
+    let is_set = |flag: &str| match flag {
+        "--all-projects" => args.all_projects,
+        "--project" => args.project.is_some(),
+        "--unassigned" => args.unassigned,
+        "--assigned-to" => args.assigned_to.is_some(),
+        "--closed-since" => args.closed_since.is_some(),
+        "--status" => args.status.is_some(),
+        "--search" => args.search.is_some(),
+        "--due-within" => args.due_within.is_some(),
+        "--overdue" => args.overdue,
+        "--all" => args.all,
+        "--stream-to" => args.stream_to.is_some(),
+        _ => false,
+    };
+    match FILTER_CONFLICTS
+        .iter()
+        .find(|(a, b)| is_set(a) && is_set(b))
+    {
+        Some((a, b)) => Err(AppError::validation_with_hint(
+            format!("{} cannot be used with {}", a, b),
+            "One of them comes from --filter-file; remove it there or from the command line",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Status filter values Redmine accepts as they are.
+const STATUS_KEYWORDS: &[&str] = &["open", "closed", "*"];
+
+/// Apply the filter file, preset and default project, then resolve a status
+/// name to the ID Redmine's `status_id` filter expects.
+async fn prepare_list_args(
     client: &RedmineClient,
-    args: &AttachmentUploadArgs,
-) -> Result<AttachmentUploaded> {
-    if !args.file.exists() {
-        return Err(AppError::validation(format!(
-            "File not found: {}",
-            args.file.display()
-        )));
+    paths: &ConfigPaths,
+    args: &IssueListArgs,
+) -> Result<IssueListArgs> {
+    let mut args = with_default_project(with_preset(with_filter_file(args)?)?);
+    if let Some(status) = &args.status {
+        let keyword = status.to_ascii_lowercase();
+        args.status = Some(if STATUS_KEYWORDS.contains(&keyword.as_str()) {
+            keyword
+        } else {
+            resolve_named(client, status, |r| issue_statuses(client, paths, r))
+                .await?
+                .to_string()
+        });
     }
+    resolve_me(client, &mut args.assigned_to).await?;
+    resolve_me(client, &mut args.not_assigned_to).await?;
+    resolve_me(client, &mut args.author).await?;
+    resolve_me(client, &mut args.watched_by).await?;
+    Ok(args)
+}
 
-    let filename = args
-        .filename
-        .clone()
-        .or_else(|| {
-            args.file
-                .file_name()
-                .map(|n| n.to_string_lossy().into_owned())
+/// Execute issue list command.
+pub async fn list(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueListArgs,
+) -> Result<IssueListResult> {
+    let args = &prepare_list_args(client, paths, args).await?;
+    // Validate grouping before making any requests
+    let group_by = args
+        .group_by
+        .as_deref()
+        .map(|s| {
+            IssueGroupByField::parse(s).ok_or_else(|| {
+                AppError::validation_with_hint(
+                    format!("Invalid group-by field: '{}'", s),
+                    "Valid values: status, priority, assignee, tracker, project",
+                )
+            })
         })
-        .unwrap_or_else(|| "attachment".to_string());
+        .transpose()?;
 
-    let content_type = guess_content_type(&args.file).to_string();
-    let bytes = tokio::fs::read(&args.file).await.map_err(|e| {
-        AppError::api(
-            format!("Failed to read {}: {}", args.file.display(), e),
-            None,
-        )
-    })?;
+    let today = Local::now().date_naive();
+    let closed_since = args
+        .closed_since
+        .as_deref()
+        .map(|s| parse_since(s, today))
+        .transpose()?;
 
-    let token = client.upload_file(bytes, &filename).await?;
+    let filters = list_filters(args, today)?;
+    let projects = split_projects(args.project.as_deref());
+    if projects.len() > 1 && args.search.is_some() {
+        return Err(AppError::validation_with_hint(
+            "--search takes a single --project",
+            "Search each project separately, or drop --search to filter by subject",
+        ));
+    }
 
-    let upload_ref = AttachmentRef {
-        token,
-        filename: filename.clone(),
-        content_type,
-        description: args.description.clone(),
+    // If search is specified, use search endpoint instead
+    let mut issues = if let Some(query) = &args.search {
+        client
+            .search_issues(query, args.project.as_deref(), args.limit, args.offset)
+            .await?
+    } else if projects.len() > 1 {
+        list_project_union(client, args, &filters, projects, closed_since).await?
+    } else {
+        fetch_issues(client, args, filters, closed_since).await?
+    };
+
+    // Search results are already full records
+    if args.with_custom_fields && args.search.is_none() {
+        issues.issues = fetch_full_issues(client, issues.issues, args.concurrency).await;
+    }
+    require_results(args.require_results, issues.issues.len(), "issues")?;
+    issues.cf_columns = args.cf_columns.clone();
+    issues.legend = args.legend;
+
+    if let Some(group_by) = group_by {
+        let matched = issues.total_count;
+        let mut grouped = GroupedIssues::from_entries(issues.issues, &group_by);
+        grouped.matched_count = matched.unwrap_or(grouped.total_count);
+        return Ok(IssueListResult::Grouped(grouped));
+    }
+
+    Ok(IssueListResult::List(issues))
+}
+
+/// Fetch one page, or every page with `--all`, of the issues matching `filters`.
+async fn fetch_issues(
+    client: &RedmineClient,
+    args: &IssueListArgs,
+    filters: IssueFilters,
+    closed_since: Option<NaiveDate>,
+) -> Result<IssueList> {
+    if let Some(since) = closed_since {
+        list_closed_since(client, filters, since).await
+    } else if args.all {
+        client.list_all_issues(filters, args.page_size).await
+    } else {
+        client.list_issues(filters).await
+    }
+}
+
+/// Split a comma-separated `--project` value into project IDs or identifiers.
+fn split_projects(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Fetch several projects concurrently and merge them into one list.
+///
+/// Issues already listed for an earlier project (e.g. from a subproject) are
+/// dropped. Each project is paged on its own, so `--limit`/`--offset` apply
+/// per project and the total is only approximate for the union.
+async fn list_project_union(
+    client: &RedmineClient,
+    args: &IssueListArgs,
+    filters: &IssueFilters,
+    projects: Vec<String>,
+    closed_since: Option<NaiveDate>,
+) -> Result<IssueList> {
+    let lists = map_concurrent(projects, args.concurrency, |project| {
+        let filters = IssueFilters {
+            project: Some(project),
+            ..filters.clone()
+        };
+        fetch_issues(client, args, filters, closed_since)
+    })
+    .await;
+
+    let mut seen = BTreeSet::new();
+    let mut issues = Vec::new();
+    let mut total = 0u32;
+    let mut page_limit = args.limit;
+    for list in lists {
+        let list = list?;
+        // With --limit 0 the page size is whatever the server chose
+        if args.limit == 0 {
+            page_limit = page_limit.max(list.limit.unwrap_or(0));
+        }
+        let fetched = list.issues.len() as u32;
+        total += list.total_count.unwrap_or(fetched);
+        for issue in list.issues {
+            if seen.insert(issue.id) {
+                issues.push(issue);
+            } else {
+                total = total.saturating_sub(1);
+            }
+        }
+    }
+
+    let (offset, limit) = if args.all {
+        (0, issues.len() as u32)
+    } else {
+        (args.offset, page_limit)
+    };
+    Ok(IssueList {
+        issues,
+        total_count: Some(total),
+        offset: Some(offset),
+        limit: Some(limit),
+        cf_columns: Vec::new(),
+        legend: false,
+    })
+}
+
+/// Stream every issue matching the list filters, page by page, to `emit`.
+pub async fn stream_all(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueListArgs,
+    mut emit: impl FnMut(&Issue) -> Result<()>,
+) -> Result<()> {
+    let args = &prepare_list_args(client, paths, args).await?;
+    if split_projects(args.project.as_deref()).len() > 1 {
+        return Err(AppError::validation_with_hint(
+            "Streaming takes a single --project",
+            "Run one streaming export per project, or drop --stream-to/--format ndjson",
+        ));
+    }
+    let filters = list_filters(args, Local::now().date_naive())?;
+    let mut pages = client.issue_pages(filters, args.page_size);
+    let mut count = 0;
+    while let Some(mut page) = pages.next_page().await? {
+        if args.with_custom_fields {
+            page = fetch_full_issues(client, page, args.concurrency).await;
+        }
+        for issue in &page {
+            emit(issue)?;
+        }
+        count += page.len();
+    }
+    require_results(args.require_results, count, "issues")
+}
+
+/// Stream every matching issue to `path` as NDJSON without collecting them in memory.
+pub async fn stream_to_file(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueListArgs,
+    path: &Path,
+) -> Result<IssuesStreamed> {
+    // Create the file with the first issue so a rejected query leaves no file behind
+    let mut writer: Option<BufWriter<File>> = None;
+    let mut count = 0u64;
+    stream_all(client, paths, args, |issue| {
+        let writer = match &mut writer {
+            Some(writer) => writer,
+            None => writer.insert(BufWriter::new(File::create(path)?)),
+        };
+        serde_json::to_writer(&mut *writer, issue)?;
+        writer.write_all(b"\n")?;
+        count += 1;
+        Ok(())
+    })
+    .await?;
+    match writer {
+        Some(mut writer) => writer.flush()?,
+        None => {
+            File::create(path)?;
+        }
+    }
+
+    Ok(IssuesStreamed {
+        path: path.to_path_buf(),
+        count,
+    })
+}
+
+/// Build API filters from the list arguments.
+fn list_filters(args: &IssueListArgs, today: NaiveDate) -> Result<IssueFilters> {
+    let custom_fields = parse_custom_fields(&args.custom_fields)?;
+
+    let due_date = args
+        .due_within
+        .as_deref()
+        .map(|s| parse_within(s, today))
+        .transpose()?
+        .map(|end| format!("><{}|{}", today.format("%Y-%m-%d"), end.format("%Y-%m-%d")))
+        .or_else(|| {
+            // Redmine's "<=" is inclusive, so stop at yesterday
+            let yesterday = today.pred_opt()?;
+            args.overdue
+                .then(|| format!("<={}", yesterday.format("%Y-%m-%d")))
+        });
+    let status = match &due_date {
+        Some(_) => args.status.clone().or_else(|| Some("open".to_string())),
+        None => args.status.clone(),
+    };
+    // An explicit sort wins over the due date order implied by date filters
+    let sort = match &args.sort {
+        Some(sort) => Some(parse_sort(sort, SORT_KEYS)?),
+        None => due_date.as_ref().map(|_| "due_date".to_string()),
+    };
+
+    Ok(IssueFilters {
+        project: args.project.clone(),
+        status,
+        assigned_to: args.assigned_to.clone(),
+        unassigned: args.unassigned,
+        not_assigned_to: args.not_assigned_to.clone(),
+        author: args.author.clone(),
+        watched_by: args.watched_by.clone(),
+        tracker: args.tracker.clone(),
+        subject: args.subject.clone(),
+        sort,
+        due_date,
+        custom_fields,
+        limit: args.limit,
+        offset: args.offset,
+        ..Default::default()
+    })
+}
+
+/// List closed issues closed on/after `since`.
+///
+/// Uses the `closed_on` filter and falls back to `updated_on` when the server
+/// rejects or ignores it, or is known to predate it (`--api-version`).
+async fn list_closed_since(
+    client: &RedmineClient,
+    filters: IssueFilters,
+    since: NaiveDate,
+) -> Result<IssueList> {
+    let bound = format!(">={}", since.format("%Y-%m-%d"));
+    let filters = IssueFilters {
+        status: Some("closed".to_string()),
+        ..filters
+    };
+
+    let by_closed_on = IssueFilters {
+        closed_on: Some(bound.clone()),
+        ..filters.clone()
+    };
+    if client.supports(Feature::ClosedOnFilter) {
+        match client.list_issues(by_closed_on).await {
+            Ok(list) => {
+                let supported =
+                    list.issues.is_empty() || list.issues.iter().any(|i| i.closed_on.is_some());
+                if supported {
+                    return Ok(list);
+                }
+                tracing::debug!("Server did not report closed_on; falling back to updated_on");
+            }
+            Err(AppError::Api {
+                status: Some(422), ..
+            }) => tracing::debug!("Server rejected closed_on filter; falling back to updated_on"),
+            Err(e) => return Err(e),
+        }
+    } else {
+        tracing::debug!("Server predates closed_on; using updated_on");
+    }
+
+    let by_updated_on = IssueFilters {
+        updated_on: Some(bound),
+        ..filters
+    };
+    client.list_issues(by_updated_on).await
+}
+
+/// Parse a `--closed-since` value: an ISO date or a window such as `7d`, `2w` or `1m`.
+fn parse_since(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date);
+    }
+
+    shift_by_window(value, today, false).ok_or_else(|| {
+        AppError::validation_with_hint(
+            format!("Invalid date or window: '{}'", value),
+            "Use YYYY-MM-DD or a window like 7d, 2w or 1m",
+        )
+    })
+}
+
+/// Parse a `--due-within` window such as `3d`, `2w` or `1m` into an end date.
+fn parse_within(value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    shift_by_window(value, today, true).ok_or_else(|| {
+        AppError::validation_with_hint(
+            format!("Invalid window: '{}'", value),
+            "Use a window like 3d, 2w or 1m",
+        )
+    })
+}
+
+/// Move `today` back (or forward) by a relative window: `<n>d`, `<n>w` or `<n>m`.
+fn shift_by_window(value: &str, today: NaiveDate, forward: bool) -> Option<NaiveDate> {
+    let (split, _) = value.char_indices().last()?;
+    let (count, unit) = value.split_at(split);
+    let count: u32 = count.parse().ok()?;
+    match (unit, forward) {
+        ("d", false) => today.checked_sub_days(Days::new(count.into())),
+        ("d", true) => today.checked_add_days(Days::new(count.into())),
+        ("w", false) => today.checked_sub_days(Days::new(u64::from(count) * 7)),
+        ("w", true) => today.checked_add_days(Days::new(u64::from(count) * 7)),
+        ("m", false) => today.checked_sub_months(Months::new(count)),
+        ("m", true) => today.checked_add_months(Months::new(count)),
+        _ => None,
+    }
+}
+
+/// Replace list entries with full issue records, keeping the list entry on failure.
+async fn fetch_full_issues(
+    client: &RedmineClient,
+    issues: Vec<Issue>,
+    concurrency: usize,
+) -> Vec<Issue> {
+    map_concurrent(issues, concurrency, |issue| async move {
+        match client.get_issue(issue.id).await {
+            Ok(full) => full,
+            Err(e) => {
+                tracing::debug!("Keeping list entry for issue #{}: {}", issue.id, e);
+                issue
+            }
+        }
+    })
+    .await
+}
+
+/// Result of issue list command - either grouped or ungrouped.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum IssueListResult {
+    List(IssueList),
+    Grouped(GroupedIssues),
+}
+
+impl IssueListResult {
+    /// Get pagination metadata.
+    pub fn meta(&self) -> Meta {
+        match self {
+            IssueListResult::List(list) => Meta::paginated(
+                list.total_count.unwrap_or(0),
+                list.limit.unwrap_or(25),
+                list.offset.unwrap_or(0),
+            ),
+            IssueListResult::Grouped(grouped) => Meta {
+                total_count: Some(grouped.matched_count),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl MarkdownOutput for IssueListResult {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        match self {
+            IssueListResult::List(list) => list.to_markdown(meta),
+            IssueListResult::Grouped(grouped) => grouped.to_markdown(meta),
+        }
+    }
+}
+
+impl CsvOutput for IssueListResult {
+    fn csv_header(&self) -> Vec<String> {
+        match self {
+            IssueListResult::List(list) => list.csv_header(),
+            IssueListResult::Grouped(grouped) => grouped.csv_header(),
+        }
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        match self {
+            IssueListResult::List(list) => list.csv_rows(),
+            IssueListResult::Grouped(grouped) => grouped.csv_rows(),
+        }
+    }
+}
+
+/// Execute issue get command.
+pub async fn get(client: &RedmineClient, args: &IssueGetArgs) -> Result<IssueGetResult> {
+    validate_fields(&args.fields)?;
+
+    if let [id] = args.id.as_slice() {
+        let issue = client.get_issue(*id).await?;
+        if args.fields.is_empty() {
+            return Ok(IssueGetResult::Single(Box::new(issue)));
+        }
+        return Ok(IssueGetResult::Projected(Box::new(IssueProjection {
+            issue,
+            fields: args.fields.clone(),
+        })));
+    }
+
+    if !args.fields.is_empty() {
+        return Err(AppError::validation(
+            "--fields is only supported when getting a single issue",
+        ));
+    }
+
+    let results = map_concurrent(args.id.clone(), args.concurrency, |id| async move {
+        (id, client.get_issue(id).await)
+    })
+    .await;
+
+    let mut issues = Vec::new();
+    let mut missing = Vec::new();
+    for (id, result) in results {
+        match result {
+            Ok(issue) => issues.push(issue),
+            Err(AppError::NotFound { .. }) => missing.push(id),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let count = issues.len() as u32;
+    Ok(IssueGetResult::Multiple(IssuesFetched {
+        list: IssueList {
+            issues,
+            total_count: Some(count),
+            offset: Some(0),
+            limit: Some(count),
+            cf_columns: Vec::new(),
+            legend: false,
+        },
+        missing,
+    }))
+}
+
+/// Check `--fields` names against the known issue fields.
+fn validate_fields(fields: &[String]) -> Result<()> {
+    match fields.iter().find(|f| !ISSUE_FIELDS.contains(&f.as_str())) {
+        Some(unknown) => Err(AppError::validation_with_hint(
+            format!("Unknown issue field: '{}'", unknown),
+            format!("Known fields: {}", ISSUE_FIELDS.join(", ")),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Result of issue get command - a single issue or several requested IDs.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum IssueGetResult {
+    Single(Box<Issue>),
+    Projected(Box<IssueProjection>),
+    Multiple(IssuesFetched),
+}
+
+impl IssueGetResult {
+    /// Get pagination metadata.
+    pub fn meta(&self) -> Meta {
+        match self {
+            IssueGetResult::Single(_) | IssueGetResult::Projected(_) => Meta::default(),
+            IssueGetResult::Multiple(fetched) => {
+                let count = fetched.list.issues.len() as u32;
+                Meta::paginated(count, count, 0)
+            }
+        }
+    }
+}
+
+impl MarkdownOutput for IssueGetResult {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        match self {
+            IssueGetResult::Single(issue) => issue.to_markdown(meta),
+            IssueGetResult::Projected(projection) => projection.to_markdown(meta),
+            IssueGetResult::Multiple(fetched) => fetched.to_markdown(meta),
+        }
+    }
+}
+
+/// Issues fetched by ID, in the requested order.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuesFetched {
+    #[serde(flatten)]
+    pub list: IssueList,
+    /// Requested IDs that were not found.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<u32>,
+}
+
+impl MarkdownOutput for IssuesFetched {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        let mut output = self.list.to_markdown(meta);
+        if !self.missing.is_empty() {
+            let ids: Vec<String> = self.missing.iter().map(|id| format!("#{}", id)).collect();
+            output.push_str(&format!("\n*Skipped (not found): {}*\n", ids.join(", ")));
+        }
+        output
+    }
+}
+
+/// Execute issue create command.
+pub async fn create(
+    client: &RedmineClient,
+    args: &IssueCreateArgs,
+) -> Result<WriteOutcome<IssueCreated>> {
+    let custom_fields = parse_custom_field_values(&args.custom_fields)?;
+    let start_date = parse_date_arg("--start-date", args.start_date.as_deref())?;
+    let due_date = parse_date_arg("--due-date", args.due_date.as_deref())?;
+    for file in &args.attachments {
+        require_file(file)?;
+    }
+    let description = args
+        .description
+        .as_deref()
+        .map(read_arg_value)
+        .transpose()?;
+    let project = args
+        .project
+        .as_deref()
+        .or(args.default_project.as_deref())
+        .ok_or_else(|| {
+            AppError::validation_with_hint(
+                "--project is required",
+                "Pass --project, or set a default with `rdm profile set-default-project`",
+            )
+        })?;
+    let project_id = resolve_project_write_id(client, project).await?;
+    let fixed_version_id = match &args.fixed_version {
+        Some(version) => Some(resolve_version(client, &project_id.to_string(), version).await?),
+        None => None,
+    };
+    let mut uploads = Vec::new();
+    for file in &args.attachments {
+        uploads.push(upload_attachment(client, file, None, None).await?);
+    }
+
+    let issue = NewIssue {
+        project_id,
+        subject: args.subject.clone(),
+        description,
+        tracker_id: args.tracker,
+        status_id: args.status,
+        priority_id: args.priority,
+        assigned_to_id: args.assigned_to,
+        fixed_version_id,
+        parent_issue_id: args.parent,
+        start_date,
+        due_date,
+        estimated_hours: args.estimated_hours,
+        done_ratio: args.done_ratio,
+        custom_fields,
+        uploads: (!uploads.is_empty()).then_some(uploads),
+        watcher_user_ids: (!args.watchers.is_empty()).then(|| args.watchers.clone()),
+    };
+
+    let created = client.create_issue(issue).await?;
+    Ok(created.map(|issue| IssueCreated { issue }))
+}
+
+/// Execute issue update command.
+pub async fn update(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueUpdateArgs,
+) -> Result<WriteOutcome<IssueUpdated>> {
+    let mut custom_fields = parse_custom_field_values(&args.custom_fields)?;
+    let start_date = parse_date_arg("--start-date", args.start_date.as_deref())?;
+    let due_date = parse_date_arg("--due-date", args.due_date.as_deref())?;
+    let description = args
+        .description
+        .as_deref()
+        .map(read_arg_value)
+        .transpose()?;
+    let notes = args.notes.as_deref().map(read_arg_value).transpose()?;
+    let estimated_hours = if args.clear_estimated_hours {
+        Some(None)
+    } else {
+        args.estimated_hours
+            .as_deref()
+            .map(parse_hours)
+            .transpose()?
+            .map(Some)
+    };
+
+    let edits_custom_fields =
+        !args.add_custom_fields.is_empty() || !args.remove_custom_fields.is_empty();
+    // The merge needs the issue's current values, which --dry-run does not read
+    if edits_custom_fields && client.is_dry_run() {
+        return Err(AppError::validation_with_hint(
+            "Cannot merge --add-cf/--remove-cf edits with --dry-run",
+            "Pass the complete new value with --cf <id>=<value> to preview the request",
+        ));
+    }
+    // Under --dry-run nothing is sent, so there is no write to guard
+    let checks_unchanged = args.if_unchanged_since.is_some() && !client.is_dry_run();
+    let current = if edits_custom_fields || checks_unchanged {
+        Some(client.get_issue(args.id).await?)
+    } else {
+        None
+    };
+
+    if let (Some(expected), Some(issue)) = (&args.if_unchanged_since, &current) {
+        check_unchanged(issue, expected)?;
+    }
+    // Pin the update to the version that was checked or read
+    let lock_version = args
+        .lock_version
+        .or_else(|| current.as_ref().and_then(|issue| issue.lock_version));
+
+    // Fetch-modify-write for incremental custom field edits
+    if let Some(issue) = current.filter(|_| edits_custom_fields) {
+        let adds = parse_custom_fields(&args.add_custom_fields)?;
+        let removes = parse_custom_fields(&args.remove_custom_fields)?;
+
+        let explicit = custom_fields.get_or_insert_with(Vec::new);
+        if let Some(cf) = explicit
+            .iter()
+            .find(|cf| adds.iter().chain(&removes).any(|(id, _)| *id == cf.id))
+        {
+            return Err(AppError::validation(format!(
+                "Custom field {} cannot be combined with both --cf and --add-cf/--remove-cf",
+                cf.id
+            )));
+        }
+
+        let current = issue.custom_fields.unwrap_or_default();
+        explicit.extend(merge_custom_field_edits(
+            &current, &adds, &removes, args.force,
+        )?);
+    }
+
+    let tracker_id = match &args.tracker {
+        Some(tracker) => {
+            Some(resolve_named(client, tracker, |r| trackers(client, paths, r)).await?)
+        }
+        None => None,
+    };
+    let status_id = match &args.status {
+        Some(status) => {
+            Some(resolve_named(client, status, |r| issue_statuses(client, paths, r)).await?)
+        }
+        None => None,
+    };
+    let priority_id = match &args.priority {
+        Some(priority) => {
+            Some(resolve_named(client, priority, |r| priorities(client, paths, r)).await?)
+        }
+        None => None,
     };
 
     let update = UpdateIssue {
-        uploads: Some(vec![upload_ref]),
+        subject: args.subject.clone(),
+        description,
+        tracker_id,
+        status_id,
+        priority_id,
+        assigned_to_id: args.assigned_to,
+        parent_issue_id: if args.clear_parent {
+            Some(None)
+        } else {
+            args.parent.map(Some)
+        },
+        // Redmine clears a date when sent an empty string
+        start_date: cleared_or(args.clear_start_date, &start_date),
+        due_date: cleared_or(args.clear_due_date, &due_date),
+        estimated_hours,
+        done_ratio: args.done_ratio,
+        notes,
+        custom_fields,
+        lock_version,
         ..Default::default()
     };
 
-    client.update_issue(args.issue_id, update).await?;
+    let updated = client.update_issue(args.id, update).await?;
+    Ok(updated.map(|()| IssueUpdated { id: args.id }))
+}
 
-    Ok(AttachmentUploaded {
-        filename,
+/// Execute issue comment command.
+pub async fn comment(
+    client: &RedmineClient,
+    args: &IssueCommentArgs,
+) -> Result<WriteOutcome<IssueCommented>> {
+    let note = read_arg_value(&args.note)?;
+    if note.trim().is_empty() {
+        return Err(AppError::validation_with_hint(
+            "The note is empty",
+            "Pass the note text, e.g. `rdm issue comment --id 123 \"Deployed to staging\"`",
+        ));
+    }
+
+    let update = UpdateIssue {
+        notes: Some(note),
+        private_notes: args.private.then_some(true),
+        ..Default::default()
+    };
+
+    let commented = client.update_issue(args.id, update).await?;
+    Ok(commented.map(|()| IssueCommented {
+        id: args.id,
+        private: args.private,
+    }))
+}
+
+/// Fail with a conflict if the issue changed since `expected` (its last-read `updated_on`).
+///
+/// The check and the update are separate requests, so this only narrows the
+/// window for concurrent edits unless the server also reports a lock_version.
+fn check_unchanged(issue: &Issue, expected: &str) -> Result<()> {
+    let actual = issue.updated_on.as_deref().unwrap_or_default();
+    let same = match (
+        DateTime::parse_from_rfc3339(actual),
+        DateTime::parse_from_rfc3339(expected),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => actual == expected,
+    };
+    if same {
+        return Ok(());
+    }
+    Err(AppError::conflict_with_hint(
+        format!(
+            "Issue #{} was updated at {} (expected {})",
+            issue.id,
+            if actual.is_empty() {
+                "an unknown time"
+            } else {
+                actual
+            },
+            expected
+        ),
+        format!(
+            "Re-read it with `rdm issue get --id {}` and retry with the new updated_on",
+            issue.id
+        ),
+    ))
+}
+
+/// Resolve a version name (case-insensitive) or ID to the ID of a version
+/// the project can use, i.e. its own versions and ones shared with it.
+async fn resolve_version(client: &RedmineClient, project: &str, name_or_id: &str) -> Result<u32> {
+    // Versions are not listed under --dry-run, so only IDs can be used
+    if client.is_dry_run() {
+        return name_or_id.parse().map_err(|_| {
+            AppError::validation_with_hint(
+                format!("Cannot look up version '{}' with --dry-run", name_or_id),
+                "Pass the version ID instead of its name",
+            )
+        });
+    }
+    let versions = client.list_versions(project).await?.versions;
+    let found = match name_or_id.parse::<u32>() {
+        Ok(id) => versions.iter().find(|v| v.id == id),
+        Err(_) => versions
+            .iter()
+            .find(|v| v.name.eq_ignore_ascii_case(name_or_id)),
+    };
+
+    found.map(|v| v.id).ok_or_else(|| {
+        let names: Vec<&str> = versions.iter().map(|v| v.name.as_str()).collect();
+        AppError::validation_with_hint(
+            format!(
+                "Version '{}' is not available in project {}",
+                name_or_id, project
+            ),
+            if names.is_empty() {
+                "The project has no versions; create one with `rdm project versions create`"
+                    .to_string()
+            } else {
+                format!("Available versions: {}", names.join(", "))
+            },
+        )
+    })
+}
+
+/// Value for an optional update field: empty when cleared, otherwise the given value.
+fn cleared_or(clear: bool, value: &Option<String>) -> Option<String> {
+    if clear {
+        Some(String::new())
+    } else {
+        value.clone()
+    }
+}
+
+/// Issue attributes that can be given by name: statuses, trackers and priorities.
+trait Named {
+    /// Nouns used in errors, e.g. "status" and "statuses".
+    const SINGULAR: &'static str;
+    const PLURAL: &'static str;
+    fn id(&self) -> u32;
+    fn name(&self) -> &str;
+}
+
+impl Named for Status {
+    const SINGULAR: &'static str = "status";
+    const PLURAL: &'static str = "statuses";
+    fn id(&self) -> u32 {
+        self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Named for Tracker {
+    const SINGULAR: &'static str = "tracker";
+    const PLURAL: &'static str = "trackers";
+    fn id(&self) -> u32 {
+        self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Named for Priority {
+    const SINGULAR: &'static str = "priority";
+    const PLURAL: &'static str = "priorities";
+    fn id(&self) -> u32 {
+        self.id
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+async fn issue_statuses(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    refresh: bool,
+) -> Result<(Vec<Status>, bool)> {
+    cached_list(client, paths, "statuses.json", refresh, async {
+        Ok(client.list_issue_statuses().await?.issue_statuses)
+    })
+    .await
+}
+
+async fn trackers(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    refresh: bool,
+) -> Result<(Vec<Tracker>, bool)> {
+    cached_list(client, paths, "trackers.json", refresh, async {
+        Ok(client.list_trackers().await?.trackers)
+    })
+    .await
+}
+
+async fn priorities(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    refresh: bool,
+) -> Result<(Vec<Priority>, bool)> {
+    cached_list(client, paths, "priorities.json", refresh, async {
+        Ok(client.list_priorities().await?.issue_priorities)
+    })
+    .await
+}
+
+/// Resolve a name (case-insensitive) or ID to an ID using the list from `load`.
+///
+/// A name missing from a cached list is looked up again in a fresh one, in
+/// case it was added on the server since. Lists are not fetched under
+/// --dry-run, so there only a valid cache can resolve names.
+async fn resolve_named<T, F, Fut>(client: &RedmineClient, name_or_id: &str, load: F) -> Result<u32>
+where
+    T: Named,
+    F: Fn(bool) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, bool)>>,
+{
+    if let Ok(id) = name_or_id.parse::<u32>() {
+        return Ok(id);
+    }
+
+    let find = |items: &[T]| {
+        items
+            .iter()
+            .find(|i| i.name().eq_ignore_ascii_case(name_or_id))
+            .map(Named::id)
+    };
+    let (mut items, from_cache) = load(false).await?;
+    if items.is_empty() && client.is_dry_run() {
+        return Err(AppError::validation_with_hint(
+            format!(
+                "Cannot look up {} '{}' with --dry-run",
+                T::SINGULAR,
+                name_or_id
+            ),
+            format!(
+                "Named values need a live lookup; pass the {} ID instead",
+                T::SINGULAR
+            ),
+        ));
+    }
+    if from_cache && !client.is_dry_run() && find(&items).is_none() {
+        items = load(true).await?.0;
+    }
+    find(&items).ok_or_else(|| {
+        let names: Vec<&str> = items.iter().map(Named::name).collect();
+        AppError::validation_with_hint(
+            format!("Unknown {}: '{}'", T::SINGULAR, name_or_id),
+            format!("Available {}: {}", T::PLURAL, names.join(", ")),
+        )
+    })
+}
+
+/// Execute issue statuses command, using the cache when valid.
+pub async fn list_statuses(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueLookupArgs,
+) -> Result<StatusList> {
+    let (issue_statuses, _) = issue_statuses(client, paths, args.refresh).await?;
+    Ok(StatusList { issue_statuses })
+}
+
+/// Execute issue trackers command, using the cache when valid.
+pub async fn list_trackers(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueLookupArgs,
+) -> Result<TrackerList> {
+    let (trackers, _) = trackers(client, paths, args.refresh).await?;
+    Ok(TrackerList { trackers })
+}
+
+/// Execute issue priorities command, using the cache when valid.
+pub async fn list_priorities(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &IssueLookupArgs,
+) -> Result<PriorityList> {
+    let (issue_priorities, _) = priorities(client, paths, args.refresh).await?;
+    Ok(PriorityList { issue_priorities })
+}
+
+/// Execute issue watchers add/remove command.
+pub async fn change_watcher(
+    client: &RedmineClient,
+    args: &IssueWatcherArgs,
+    watching: bool,
+) -> Result<WriteOutcome<WatcherChanged>> {
+    let user_id = if args.user.eq_ignore_ascii_case("me") {
+        if client.is_dry_run() {
+            return Err(AppError::validation_with_hint(
+                "Cannot look up --user me with --dry-run",
+                "Pass your numeric user ID (see `rdm me`) to preview the request",
+            ));
+        }
+        client.current_user_id().await?
+    } else {
+        args.user.parse::<u32>().map_err(|_| {
+            AppError::validation_with_hint(
+                format!("Invalid --user value: '{}'", args.user),
+                "Use a numeric user ID or `me`",
+            )
+        })?
+    };
+
+    let changed = if watching {
+        client.add_watcher(args.id, user_id).await?
+    } else {
+        client.remove_watcher(args.id, user_id).await?
+    };
+    Ok(changed.map(|()| WatcherChanged {
+        issue_id: args.id,
+        user_id,
+        watching,
+    }))
+}
+
+/// Execute issue watch-project command.
+pub async fn watch_project(
+    client: &RedmineClient,
+    args: &IssueWatchProjectArgs,
+) -> Result<ProjectWatched> {
+    // The issues and the current user are not read under --dry-run
+    if client.is_dry_run() {
+        return Err(AppError::validation_with_hint(
+            "Cannot use --dry-run with issue watch-project",
+            "Preview a single issue with `rdm --dry-run issue watchers add --id <issue> --user <your ID>`",
+        ));
+    }
+
+    let filters = IssueFilters {
+        project: Some(args.project.clone()),
+        status: Some(args.status.clone()),
+        ..Default::default()
+    };
+    let issue_ids: Vec<u32> = client
+        .list_all_issues(filters, MAX_PAGE_SIZE)
+        .await?
+        .issues
+        .iter()
+        .map(|i| i.id)
+        .collect();
+
+    if issue_ids.is_empty() {
+        return Ok(ProjectWatched {
+            project: args.project.clone(),
+            user_id: None,
+            watched: vec![],
+            failed: vec![],
+        });
+    }
+
+    if !args.yes {
+        return Err(AppError::validation_with_hint(
+            format!(
+                "This will add you as a watcher on {} issues in project '{}'",
+                issue_ids.len(),
+                args.project
+            ),
+            "Re-run with --yes to proceed",
+        ));
+    }
+
+    let user_id = client.me().await?.id;
+    let total = issue_ids.len();
+
+    let results = map_concurrent(issue_ids, args.concurrency, |issue_id| async move {
+        let result = client.add_watcher(issue_id, user_id).await;
+        match &result {
+            Ok(_) => eprintln!("Watching #{}", issue_id),
+            Err(e) => eprintln!("Failed to watch #{}: {}", issue_id, e),
+        }
+        (issue_id, result)
+    })
+    .await;
+
+    let mut watched = Vec::new();
+    let mut failed = Vec::new();
+    for (issue_id, result) in results {
+        match result {
+            Ok(_) => watched.push(issue_id),
+            Err(e) => failed.push(WatchFailure {
+                issue_id,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    if watched.is_empty() {
+        return Err(AppError::api(
+            format!("Failed to watch all {} issues", total),
+            None,
+        ));
+    }
+
+    Ok(ProjectWatched {
+        project: args.project.clone(),
+        user_id: Some(user_id),
+        watched,
+        failed,
+    })
+}
+
+/// Direction in which `relations graph` follows `blocks` relations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    /// Towards the issues that block the current one.
+    BlockedBy,
+    /// Towards the issues the current one blocks.
+    Blocks,
+}
+
+/// A `blocks` relation: `from` blocks `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct RelationEdge {
+    pub from: u32,
+    pub to: u32,
+}
+
+/// Result of issue relations graph command.
+#[derive(Debug, Clone, Serialize)]
+pub struct RelationGraph {
+    pub root: u32,
+    pub depth: u32,
+    pub edges: Vec<RelationEdge>,
+    /// Issues at the depth limit were not expanded.
+    pub truncated: bool,
+    #[serde(skip)]
+    pub style: GraphStyle,
+}
+
+impl RelationGraph {
+    fn neighbours(&self, id: u32, direction: Direction) -> impl Iterator<Item = u32> + '_ {
+        self.edges.iter().filter_map(move |e| match direction {
+            Direction::BlockedBy => (e.to == id).then_some(e.from),
+            Direction::Blocks => (e.from == id).then_some(e.to),
+        })
+    }
+
+    /// Append an indented tree below the last issue in `path`, marking cycles.
+    fn render_tree(&self, direction: Direction, path: &mut Vec<u32>, output: &mut String) {
+        let Some(&id) = path.last() else {
+            return;
+        };
+        let indent = "  ".repeat(path.len() - 1);
+        for next in self.neighbours(id, direction) {
+            if path.contains(&next) {
+                output.push_str(&format!("{}- #{} (cycle)\n", indent, next));
+                continue;
+            }
+            output.push_str(&format!("{}- #{}\n", indent, next));
+            path.push(next);
+            self.render_tree(direction, path, output);
+            path.pop();
+        }
+    }
+
+    fn to_dot(&self) -> String {
+        let mut output = format!("digraph \"issue_{}\" {{\n", self.root);
+        output.push_str("  rankdir=LR;\n");
+        output.push_str(&format!(
+            "  \"{}\" [label=\"#{}\", style=bold];\n",
+            self.root, self.root
+        ));
+        let nodes: BTreeSet<u32> = self
+            .edges
+            .iter()
+            .flat_map(|e| [e.from, e.to])
+            .filter(|id| *id != self.root)
+            .collect();
+        for id in nodes {
+            output.push_str(&format!("  \"{}\" [label=\"#{}\"];\n", id, id));
+        }
+        for edge in &self.edges {
+            output.push_str(&format!("  \"{}\" -> \"{}\";\n", edge.from, edge.to));
+        }
+        output.push('}');
+        output
+    }
+}
+
+impl MarkdownOutput for RelationGraph {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        if self.style == GraphStyle::Dot {
+            // Raw DOT so the output can be piped straight into Graphviz
+            return self.to_dot();
+        }
+
+        let mut output = format!("## Dependencies of #{}\n", self.root);
+        for (title, direction) in [
+            ("Blocked by", Direction::BlockedBy),
+            ("Blocks", Direction::Blocks),
+        ] {
+            output.push_str(&format!("\n### {}\n\n", title));
+            let mut tree = String::new();
+            self.render_tree(direction, &mut vec![self.root], &mut tree);
+            if tree.is_empty() {
+                output.push_str("*None*\n");
+            } else {
+                output.push_str(&tree);
+            }
+        }
+        if self.truncated {
+            output.push_str(&format!(
+                "\n*Stopped after {} levels; use `--depth` to follow further.*\n",
+                self.depth
+            ));
+        }
+        output
+    }
+}
+
+/// Execute issue relations list command.
+pub async fn relation_list(
+    client: &RedmineClient,
+    args: &RelationListArgs,
+) -> Result<IssueRelations> {
+    Ok(IssueRelations {
+        issue_id: args.id,
+        relations: client.list_issue_relations(args.id).await?,
+    })
+}
+
+/// Execute issue relations add command.
+pub async fn relation_add(
+    client: &RedmineClient,
+    args: &RelationAddArgs,
+) -> Result<WriteOutcome<IssueRelationCreated>> {
+    let relation_type = args.relation_type.to_lowercase();
+    if !RELATION_TYPES.contains(&relation_type.as_str()) {
+        return Err(AppError::validation_with_hint(
+            format!("Invalid relation type: '{}'", args.relation_type),
+            format!("Valid types: {}", RELATION_TYPES.join(", ")),
+        ));
+    }
+    if args.delay.is_some() && !matches!(relation_type.as_str(), "precedes" | "follows") {
+        return Err(AppError::validation(
+            "--delay only applies to precedes and follows relations",
+        ));
+    }
+    if args.id == args.relates_to {
+        return Err(AppError::validation("An issue cannot be related to itself"));
+    }
+
+    let relation = NewIssueRelation {
+        issue_to_id: args.relates_to,
+        relation_type,
+        delay: args.delay,
+    };
+    let created = client.create_issue_relation(args.id, relation).await?;
+    Ok(created.map(|relation| IssueRelationCreated { relation }))
+}
+
+/// Execute issue relations delete command.
+pub async fn relation_delete(
+    client: &RedmineClient,
+    args: &RelationDeleteArgs,
+) -> Result<WriteOutcome<IssueRelationDeleted>> {
+    let deleted = client.delete_issue_relation(args.id).await?;
+    Ok(deleted.map(|()| IssueRelationDeleted { id: args.id }))
+}
+
+/// Execute issue relations graph command.
+///
+/// Follows `blocks` relations upstream and downstream breadth-first, up to
+/// `--depth` hops; issues already visited are not fetched again, so cycles
+/// terminate.
+pub async fn relation_graph(
+    client: &RedmineClient,
+    args: &RelationGraphArgs,
+) -> Result<RelationGraph> {
+    let mut relations: BTreeMap<u32, Vec<IssueRelation>> = BTreeMap::new();
+    let mut edges = BTreeSet::new();
+    let mut truncated = false;
+
+    for direction in [Direction::BlockedBy, Direction::Blocks] {
+        let mut visited = BTreeSet::from([args.id]);
+        let mut frontier = vec![args.id];
+
+        for _ in 0..args.depth {
+            if frontier.is_empty() {
+                break;
+            }
+
+            let missing: Vec<u32> = frontier
+                .iter()
+                .copied()
+                .filter(|id| !relations.contains_key(id))
+                .collect();
+            let results = map_concurrent(missing, args.concurrency, |id| async move {
+                (id, client.list_issue_relations(id).await)
+            })
+            .await;
+            for (id, result) in results {
+                let fetched = match result {
+                    Ok(fetched) => fetched,
+                    // Related issues we cannot see are shown without their own relations
+                    Err(AppError::NotFound { .. }) if id != args.id => Vec::new(),
+                    Err(e) => return Err(e),
+                };
+                relations.insert(id, fetched);
+            }
+
+            let mut next = Vec::new();
+            for id in &frontier {
+                for relation in relations.get(id).into_iter().flatten() {
+                    if relation.relation_type != "blocks" {
+                        continue;
+                    }
+                    let neighbour = match direction {
+                        Direction::BlockedBy if relation.issue_to_id == *id => relation.issue_id,
+                        Direction::Blocks if relation.issue_id == *id => relation.issue_to_id,
+                        _ => continue,
+                    };
+                    edges.insert(RelationEdge {
+                        from: relation.issue_id,
+                        to: relation.issue_to_id,
+                    });
+                    if visited.insert(neighbour) {
+                        next.push(neighbour);
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        truncated |= !frontier.is_empty();
+    }
+
+    Ok(RelationGraph {
+        root: args.id,
+        depth: args.depth,
+        edges: edges.into_iter().collect(),
+        truncated,
+        style: args.style,
+    })
+}
+
+/// List attachments on an issue, optionally downloading them all.
+pub async fn attachment_list(
+    client: &RedmineClient,
+    args: &AttachmentListArgs,
+) -> Result<AttachmentList> {
+    let issue = client.get_issue(args.issue_id).await?;
+    let attachments = issue.attachments.unwrap_or_default();
+
+    let mut downloaded = Vec::new();
+    if let Some(dir) = &args.download {
+        std::fs::create_dir_all(dir)?;
+        let mut used = BTreeSet::new();
+        for attachment in &attachments {
+            // Keep only the file name, and tell apart attachments sharing one
+            let name = Path::new(&attachment.filename)
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| attachment.id.to_string());
+            let name = if used.insert(name.clone()) {
+                name
+            } else {
+                format!("{}-{}", attachment.id, name)
+            };
+            downloaded.push(save_attachment(client, attachment, dir.join(name)).await?);
+        }
+    }
+
+    Ok(AttachmentList {
         issue_id: args.issue_id,
+        attachments,
+        downloaded,
+    })
+}
+
+/// Download an attachment.
+pub async fn attachment_download(
+    client: &RedmineClient,
+    args: &AttachmentDownloadArgs,
+) -> Result<AttachmentDownloaded> {
+    let attachment = client.get_attachment(args.id).await?;
+    let output_path = match &args.output {
+        Some(p) if p.is_dir() => p.join(&attachment.filename),
+        Some(p) => p.clone(),
+        None => PathBuf::from(&attachment.filename),
+    };
+    save_attachment(client, &attachment, output_path).await
+}
+
+/// Fetch an attachment's content and write it to `output_path`.
+async fn save_attachment(
+    client: &RedmineClient,
+    attachment: &Attachment,
+    output_path: PathBuf,
+) -> Result<AttachmentDownloaded> {
+    let bytes = client.download_attachment(&attachment.content_url).await?;
+
+    tokio::fs::write(&output_path, &bytes).await.map_err(|e| {
+        AppError::api(
+            format!("Failed to write {}: {}", output_path.display(), e),
+            None,
+        )
+    })?;
+
+    Ok(AttachmentDownloaded {
+        id: attachment.id,
+        filename: attachment.filename.clone(),
+        saved_to: output_path,
+        bytes: bytes.len() as u64,
     })
 }
+
+/// Fail before any request when a file to upload does not exist.
+fn require_file(file: &Path) -> Result<()> {
+    if !file.exists() {
+        return Err(AppError::validation(format!(
+            "File not found: {}",
+            file.display()
+        )));
+    }
+    Ok(())
+}
+
+/// Upload a file and build the reference that attaches it to an issue.
+async fn upload_attachment(
+    client: &RedmineClient,
+    file: &Path,
+    filename: Option<String>,
+    description: Option<String>,
+) -> Result<AttachmentRef> {
+    let filename = filename
+        .or_else(|| file.file_name().map(|n| n.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "attachment".to_string());
+
+    let content_type = guess_content_type(file).to_string();
+    let bytes = tokio::fs::read(file)
+        .await
+        .map_err(|e| AppError::api(format!("Failed to read {}: {}", file.display(), e), None))?;
+
+    let token = client.upload_file(bytes, &filename).await?;
+    Ok(AttachmentRef {
+        token,
+        filename,
+        content_type,
+        description,
+    })
+}
+
+/// Upload a file and attach it to an issue.
+pub async fn attachment_upload(
+    client: &RedmineClient,
+    args: &AttachmentUploadArgs,
+) -> Result<WriteOutcome<AttachmentUploaded>> {
+    require_file(&args.file)?;
+    let upload_ref = upload_attachment(
+        client,
+        &args.file,
+        args.filename.clone(),
+        args.description.clone(),
+    )
+    .await?;
+    let filename = upload_ref.filename.clone();
+
+    let update = UpdateIssue {
+        uploads: Some(vec![upload_ref]),
+        ..Default::default()
+    };
+
+    let attached = client.update_issue(args.issue_id, update).await?;
+    Ok(attached.map(|()| AttachmentUploaded {
+        filename,
+        issue_id: args.issue_id,
+    }))
+}
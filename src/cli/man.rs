@@ -0,0 +1,91 @@
+//! Manpage generation for packaging.
+
+use clap::{Args, CommandFactory};
+use serde::Serialize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::Cli;
+use crate::error::{AppError, Result};
+use crate::output::{markdown::markdown_table, MarkdownOutput, Meta};
+
+#[derive(Debug, Args)]
+pub struct ManArgs {
+    /// Write one page per command (rdm.1, rdm-issue.1, ...) into this directory
+    /// instead of printing the root page to stdout.
+    #[arg(long)]
+    pub output_dir: Option<PathBuf>,
+}
+
+/// Result of writing manpages to a directory.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManPagesWritten {
+    pub output_dir: String,
+    pub files: Vec<String>,
+}
+
+impl MarkdownOutput for ManPagesWritten {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str(&format!(
+            "## Manpages Written ({})\n\nDirectory: `{}`\n\n",
+            self.files.len(),
+            self.output_dir
+        ));
+        let rows = self.files.iter().map(|f| vec![f.clone()]).collect();
+        output.push_str(&markdown_table(&["File"], rows));
+        output
+    }
+}
+
+/// Render the root manpage to `out`.
+pub fn write_root_page(out: &mut dyn Write) -> Result<()> {
+    let cmd = Cli::command();
+    clap_mangen::Man::new(cmd).render(out)?;
+    Ok(())
+}
+
+/// Render manpages for the root command and every visible subcommand into `dir`.
+pub fn write_pages(dir: &Path) -> Result<ManPagesWritten> {
+    std::fs::create_dir_all(dir)?;
+
+    let mut cmd = Cli::command();
+    cmd.build();
+
+    let mut files = Vec::new();
+    write_page_tree(&cmd, None, dir, &mut files)?;
+
+    Ok(ManPagesWritten {
+        output_dir: dir.display().to_string(),
+        files,
+    })
+}
+
+fn write_page_tree(
+    cmd: &clap::Command,
+    parent: Option<&str>,
+    dir: &Path,
+    files: &mut Vec<String>,
+) -> Result<()> {
+    let name = match parent {
+        Some(parent) => format!("{}-{}", parent, cmd.get_name()),
+        None => cmd.get_name().to_string(),
+    };
+
+    let filename = format!("{}.1", name);
+    let mut buffer = Vec::new();
+    clap_mangen::Man::new(cmd.clone().name(name.clone()))
+        .render(&mut buffer)
+        .map_err(|e| AppError::api(format!("Failed to render {}: {}", filename, e), None))?;
+    std::fs::write(dir.join(&filename), buffer)?;
+    files.push(filename);
+
+    for sub in cmd.get_subcommands() {
+        if sub.is_hide_set() || sub.get_name() == "help" {
+            continue;
+        }
+        write_page_tree(sub, Some(&name), dir, files)?;
+    }
+
+    Ok(())
+}
@@ -1,15 +1,28 @@
 //! CLI command definitions.
 
+pub mod cache;
+pub mod doctor;
+pub mod git;
 pub mod issue;
+pub mod man;
 pub mod ping;
 pub mod profile;
 pub mod project;
+pub mod search;
 pub mod time;
 pub mod user;
+pub mod wiki;
 
+use crate::cache::ListCache;
+use crate::client::endpoints::{MAX_PAGE_SIZE, MAX_RETRY_MAX_ELAPSED_SECS, MAX_TIMEOUT_SECS};
+use crate::client::RedmineClient;
+use crate::config::ConfigPaths;
 use crate::error::{AppError, Result};
-use crate::output::OutputFormat;
-use clap::{Parser, Subcommand};
+use crate::models::ProjectWriteId;
+use crate::output::{ColorChoice, OutputFormat};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::future::Future;
 
 /// Parse custom field arguments in format "id=value".
 pub fn parse_custom_fields(args: &[String]) -> Result<Vec<(u32, String)>> {
@@ -33,11 +46,293 @@ pub fn parse_custom_fields(args: &[String]) -> Result<Vec<(u32, String)>> {
     Ok(result)
 }
 
+/// Parser for `--page-size`: Redmine caps list requests at 100 items.
+pub fn page_size_parser() -> clap::builder::RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(1..=i64::from(MAX_PAGE_SIZE))
+}
+
+/// Parser for `--limit`: Redmine caps pages at 100 items, and 0 leaves the
+/// page size to the server.
+pub fn limit_parser() -> clap::builder::RangedI64ValueParser<u32> {
+    clap::value_parser!(u32).range(0..=i64::from(MAX_PAGE_SIZE))
+}
+
+/// Parser for request timeouts in seconds.
+pub fn timeout_parser() -> clap::builder::RangedU64ValueParser<u64> {
+    clap::value_parser!(u64).range(1..=MAX_TIMEOUT_SECS)
+}
+
+/// Parser for the retry time in seconds; 0 disables retries.
+pub fn retry_max_elapsed_parser() -> clap::builder::RangedU64ValueParser<u64> {
+    clap::value_parser!(u64).range(0..=MAX_RETRY_MAX_ELAPSED_SECS)
+}
+
+/// Fail with a not found error when `--require-results` is set and nothing matched.
+pub fn require_results(required: bool, count: usize, resource: &str) -> Result<()> {
+    if required && count == 0 {
+        return Err(AppError::no_results(
+            format!("{} matching the filters", resource),
+            "Relax the filters, or drop --require-results to accept an empty list",
+        ));
+    }
+    Ok(())
+}
+
+/// Read a text argument: `-` reads stdin, `@path` reads a file, anything else is literal.
+///
+/// A leading `@@` escapes the `@`, so `@@alice` is the literal text `@alice`.
+pub fn read_arg_value(value: &str) -> Result<String> {
+    if value == "-" {
+        return Ok(std::io::read_to_string(std::io::stdin())?);
+    }
+    if let Some(literal) = value.strip_prefix("@@") {
+        return Ok(format!("@{}", literal));
+    }
+    match value.strip_prefix('@') {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| {
+            AppError::Io(std::io::Error::new(
+                e.kind(),
+                format!("Cannot read {}: {}", path, e),
+            ))
+        }),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// Load a lookup list of `client`'s server from `file` in the cache
+/// directory, or `fetch` it when the cache is missing, expired, belongs to
+/// another server or `refresh` is set.
+///
+/// Also returns whether the list came from the cache.
+pub async fn cached_list<T>(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    file: &str,
+    refresh: bool,
+    fetch: impl Future<Output = Result<Vec<T>>>,
+) -> Result<(Vec<T>, bool)>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let cache_file = paths.cache_dir.join(file);
+    let cached = ListCache::load(&cache_file).filter(|cache| cache.is_for(client.base_url()));
+    if let Some(cache) = cached.filter(|_| !refresh) {
+        return Ok((cache.items, true));
+    }
+
+    let items = fetch.await?;
+    // An empty list (e.g. from --dry-run) is not worth remembering
+    if !items.is_empty() {
+        let cache = ListCache::new(items).with_server(client.base_url());
+        let _ = cache.save(&cache_file);
+        return Ok((cache.items, false));
+    }
+    Ok((items, false))
+}
+
+/// Resolve a project ID or identifier to its numeric ID for a write request.
+///
+/// Under `--dry-run` an identifier is sent as given rather than looked up.
+pub async fn resolve_project_write_id(
+    client: &RedmineClient,
+    project: &str,
+) -> Result<ProjectWriteId> {
+    match project.parse() {
+        Ok(id) => Ok(ProjectWriteId::Id(id)),
+        Err(_) if client.is_dry_run() => Ok(ProjectWriteId::Identifier(project.to_string())),
+        Err(_) => Ok(ProjectWriteId::Id(client.get_project(project).await?.id)),
+    }
+}
+
+/// Replace a `me` user filter with the current user's numeric ID, since not
+/// every Redmine version accepts `me` in list filters.
+///
+/// Left as-is under `--dry-run`, where no list request is sent.
+pub async fn resolve_me(client: &RedmineClient, user: &mut Option<String>) -> Result<()> {
+    if client.is_dry_run() {
+        return Ok(());
+    }
+    if let Some(value) = user.as_mut() {
+        if value.eq_ignore_ascii_case("me") {
+            *value = client.current_user_id().await?.to_string();
+        }
+    }
+    Ok(())
+}
+
+/// A project identifier resolved to its numeric ID on a server.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProjectIdentifier {
+    #[serde(default)]
+    server: String,
+    identifier: String,
+    id: u32,
+}
+
+/// Resolve a project filter to its numeric ID, see [`resolve_project_id_cached`].
+///
+/// Left as-is under `--dry-run`, where no list request is sent.
+pub async fn resolve_project_filter(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    project: Option<&str>,
+) -> Result<Option<String>> {
+    match project {
+        Some(project) if !client.is_dry_run() => Ok(Some(
+            resolve_project_id_cached(client, paths, project)
+                .await?
+                .to_string(),
+        )),
+        project => Ok(project.map(str::to_string)),
+    }
+}
+
+/// Resolve a project ID or identifier to its numeric ID, remembering
+/// resolved identifiers per server in `project_ids.json` in the cache directory.
+pub async fn resolve_project_id_cached(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    project: &str,
+) -> Result<u32> {
+    if let Ok(id) = project.parse() {
+        return Ok(id);
+    }
+
+    let cache_file = paths.cache_dir.join("project_ids.json");
+    let mut known = ListCache::<ProjectIdentifier>::load(&cache_file)
+        .map(|cache| cache.items)
+        .unwrap_or_default();
+    let server = client.base_url();
+    if let Some(known) = known
+        .iter()
+        .find(|p| p.server == server && p.identifier == project)
+    {
+        return Ok(known.id);
+    }
+
+    let id = client.get_project(project).await?.id;
+    known.push(ProjectIdentifier {
+        server: server.to_string(),
+        identifier: project.to_string(),
+        id,
+    });
+    let _ = ListCache::new(known).save(&cache_file);
+    Ok(id)
+}
+
+/// Hint shown for malformed date arguments.
+const DATE_HINT: &str = "Use the YYYY-MM-DD format (e.g. 2024-03-31), `today` or `yesterday`";
+
+/// Parse a date argument into `YYYY-MM-DD`; `today` and `yesterday` use the local clock.
+pub fn parse_date(value: &str) -> Result<String> {
+    let today = chrono::Local::now().date_naive();
+    let date = match value.trim().to_lowercase().as_str() {
+        "today" => Some(today),
+        "yesterday" => today.pred_opt(),
+        date => chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok(),
+    };
+    date.map(|d| d.format("%Y-%m-%d").to_string())
+        .ok_or_else(|| {
+            AppError::validation_with_hint(format!("Invalid date: '{}'", value), DATE_HINT)
+        })
+}
+
+/// Parse an optional date flag, naming the flag in the error.
+pub fn parse_date_arg(flag: &str, value: Option<&str>) -> Result<Option<String>> {
+    value
+        .map(|date| {
+            parse_date(date).map_err(|_| {
+                AppError::validation_with_hint(
+                    format!("Invalid {} value: '{}'", flag, date),
+                    DATE_HINT,
+                )
+            })
+        })
+        .transpose()
+}
+
+/// Parse a duration in hours: `2.5`, `2h`, `45m`, `1h30m` or `1:30`.
+pub fn parse_hours(value: &str) -> Result<f64> {
+    let invalid = || {
+        AppError::validation_with_hint(
+            format!("Invalid duration: '{}'", value),
+            "Use hours (2.5), units (1h30m, 45m) or h:mm (1:30)",
+        )
+    };
+
+    let trimmed = value.trim();
+    let hours = if let Ok(hours) = trimmed.parse::<f64>() {
+        hours
+    } else if let Some((h, m)) = trimmed.split_once(':') {
+        let h: u32 = h.parse().map_err(|_| invalid())?;
+        let m: u32 = m.parse().map_err(|_| invalid())?;
+        if m >= 60 {
+            return Err(invalid());
+        }
+        f64::from(h) + f64::from(m) / 60.0
+    } else {
+        let (h, rest) = match trimmed.split_once('h') {
+            Some((h, rest)) => (h.parse::<f64>().map_err(|_| invalid())?, rest),
+            None => (0.0, trimmed),
+        };
+        let m = match rest.strip_suffix('m') {
+            Some(m) => m.parse::<f64>().map_err(|_| invalid())?,
+            None if rest.is_empty() && trimmed.contains('h') => 0.0,
+            None => return Err(invalid()),
+        };
+        h + m / 60.0
+    };
+
+    if !hours.is_finite() || hours < 0.0 {
+        return Err(invalid());
+    }
+    Ok(hours)
+}
+
+/// Parse a sort specification such as `updated_on:desc,priority` into
+/// Redmine's `sort` parameter, checking each key against `keys`.
+///
+/// Custom fields (`cf_<id>`) are always accepted; the direction defaults to
+/// ascending.
+pub fn parse_sort(value: &str, keys: &[&str]) -> Result<String> {
+    let mut parts = Vec::new();
+    for spec in value.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let (key, direction) = spec.split_once(':').unwrap_or((spec, "asc"));
+        let is_cf = key
+            .strip_prefix("cf_")
+            .is_some_and(|id| id.parse::<u32>().is_ok());
+        if !keys.contains(&key) && !is_cf {
+            return Err(AppError::validation_with_hint(
+                format!("Invalid sort key: '{}'", key),
+                format!("Valid keys: {}, cf_<id>", keys.join(", ")),
+            ));
+        }
+        match direction.to_ascii_lowercase().as_str() {
+            "asc" => parts.push(key.to_string()),
+            "desc" => parts.push(format!("{}:desc", key)),
+            _ => {
+                return Err(AppError::validation_with_hint(
+                    format!("Invalid sort direction: '{}'", direction),
+                    "Use asc or desc, e.g. updated_on:desc",
+                ))
+            }
+        }
+    }
+
+    if parts.is_empty() {
+        return Err(AppError::validation_with_hint(
+            "Empty sort specification",
+            "Use key[:asc|desc], comma-separated, e.g. updated_on:desc,priority:desc",
+        ));
+    }
+    Ok(parts.join(","))
+}
+
 /// Agent-first Redmine CLI with markdown-first output.
 #[derive(Debug, Parser)]
 #[command(name = "rdm", version, about, long_about = None)]
 pub struct Cli {
-    /// Output format (markdown or json).
+    /// Output format (markdown, json, json-array, ndjson or csv).
     #[arg(
         long,
         short = 'f',
@@ -47,6 +342,10 @@ pub struct Cli {
     )]
     pub format: OutputFormat,
 
+    /// Print bare JSON without the envelope (same as --format json-array).
+    #[arg(long, global = true, conflicts_with = "format")]
+    pub bare: bool,
+
     /// Redmine server URL (overrides env/config).
     #[arg(long, env = "REDMINE_URL", global = true)]
     pub url: Option<String>,
@@ -55,6 +354,27 @@ pub struct Cli {
     #[arg(long, env = "REDMINE_API_KEY", global = true)]
     pub api_key: Option<String>,
 
+    /// Language for localized names such as statuses and priorities (sent as Accept-Language).
+    #[arg(long, env = "REDMINE_LANG", global = true)]
+    pub lang: Option<String>,
+
+    /// Demote Markdown headings by this many levels (e.g. 1 turns `##` into `###`).
+    #[arg(
+        long,
+        global = true,
+        default_value_t = 0,
+        value_parser = clap::value_parser!(u8).range(0..=5)
+    )]
+    pub heading_offset: u8,
+
+    /// Render issue ids and project identifiers in Markdown lists as links to the server.
+    #[arg(long, global = true)]
+    pub links: bool,
+
+    /// Color issue status and priority cells in Markdown tables (auto: only on a terminal).
+    #[arg(long, value_enum, default_value = "auto", global = true)]
+    pub color: ColorChoice,
+
     /// Enable debug output to stderr.
     #[arg(long, global = true)]
     pub debug: bool,
@@ -63,6 +383,42 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub dry_run: bool,
 
+    /// Warn on stderr about response fields the CLI does not capture (schema drift).
+    #[arg(long, global = true)]
+    pub strict: bool,
+
+    /// Redmine server version (e.g. 5.1); remembered per server to gate version-specific features.
+    #[arg(long, env = "REDMINE_API_VERSION", global = true, value_name = "X.Y")]
+    pub api_version: Option<String>,
+
+    /// Request timeout in seconds (overrides the profile; default 30).
+    #[arg(
+        long,
+        env = "REDMINE_TIMEOUT",
+        global = true,
+        value_name = "SECS",
+        value_parser = timeout_parser()
+    )]
+    pub timeout: Option<u64>,
+
+    /// Retries of a transient failure (502/503/504, 429, timeouts) before giving up; 0 fails fast.
+    #[arg(long, env = "REDMINE_MAX_RETRIES", global = true, value_name = "N")]
+    pub max_retries: Option<u32>,
+
+    /// Seconds to keep retrying transient failures (default 30).
+    #[arg(
+        long,
+        env = "REDMINE_RETRY_MAX_ELAPSED",
+        global = true,
+        value_name = "SECS",
+        value_parser = retry_max_elapsed_parser()
+    )]
+    pub retry_max_elapsed: Option<u64>,
+
+    /// Bypass the response cache and the cached project list (always fetch fresh data).
+    #[arg(long, global = true)]
+    pub no_cache: bool,
+
     #[command(subcommand)]
     pub command: Command,
 }
@@ -73,14 +429,17 @@ pub enum Command {
     Ping,
 
     /// Show current user information.
-    Me,
+    Me(user::MeArgs),
+
+    /// Diagnose configuration, connectivity and environment problems.
+    Doctor,
 
     /// Manage configuration profiles.
     #[command(subcommand)]
     Profile(profile::ProfileCommand),
 
-    /// Show current configuration.
-    Config(profile::ConfigShow),
+    /// Show, export or import configuration.
+    Config(profile::ConfigArgs),
 
     /// Project commands.
     #[command(subcommand)]
@@ -88,7 +447,7 @@ pub enum Command {
 
     /// Issue commands.
     #[command(subcommand)]
-    Issue(issue::IssueCommand),
+    Issue(Box<issue::IssueCommand>),
 
     /// Time entry commands.
     #[command(subcommand)]
@@ -97,4 +456,123 @@ pub enum Command {
     /// User commands.
     #[command(subcommand)]
     User(user::UserCommand),
+
+    /// Wiki commands.
+    #[command(subcommand)]
+    Wiki(wiki::WikiCommand),
+
+    /// Search across issues, wiki pages, news and other content.
+    Search(search::SearchArgs),
+
+    /// Manage the local cache of lookup lists and responses.
+    #[command(subcommand)]
+    Cache(cache::CacheCommand),
+
+    /// Generate shell completion scripts.
+    Completions(CompletionsArgs),
+
+    /// Generate roff manpages.
+    #[command(hide = true)]
+    Man(man::ManArgs),
+}
+
+impl Command {
+    /// Hand the active profile's default project to the commands that fall back to it.
+    pub fn set_default_project(&mut self, project: Option<&str>) {
+        let project = project.map(str::to_string);
+        match self {
+            Command::Issue(cmd) => match cmd.as_mut() {
+                issue::IssueCommand::List(args) => args.default_project = project,
+                issue::IssueCommand::Create(args) => args.default_project = project,
+                _ => {}
+            },
+            Command::Time(time::TimeCommand::Create(args)) => args.default_project = project,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for.
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
+}
+
+/// Write the completion script for `shell` to `out`.
+pub fn write_completions(shell: clap_complete::Shell, out: &mut dyn std::io::Write) {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hours() {
+        assert_eq!(parse_hours("2.5").unwrap(), 2.5);
+        assert_eq!(parse_hours("2h").unwrap(), 2.0);
+        assert_eq!(parse_hours("45m").unwrap(), 0.75);
+        assert_eq!(parse_hours("1h30m").unwrap(), 1.5);
+        assert_eq!(parse_hours("1:30").unwrap(), 1.5);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2024-03-31").unwrap(), "2024-03-31");
+        let today = chrono::Local::now().date_naive();
+        assert_eq!(parse_date("Today").unwrap(), today.to_string());
+        assert_eq!(
+            parse_date("yesterday").unwrap(),
+            today.pred_opt().unwrap().to_string()
+        );
+        for value in ["2024-13-01", "2024-02-30", "31.03.2024", "tomorrow"] {
+            assert!(parse_date(value).is_err(), "{value} should be rejected");
+        }
+        let err = parse_date_arg("--from", Some("2024-13-01")).unwrap_err();
+        assert!(err.to_string().contains("Invalid --from value"));
+        assert_eq!(parse_date_arg("--from", None).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_sort() {
+        let keys = ["priority", "updated_on"];
+        assert_eq!(
+            parse_sort("updated_on:desc, priority", &keys).unwrap(),
+            "updated_on:desc,priority"
+        );
+        assert_eq!(parse_sort("cf_5:DESC", &keys).unwrap(), "cf_5:desc");
+        for value in ["", "colour", "priority:up", "cf_x"] {
+            assert!(
+                parse_sort(value, &keys).is_err(),
+                "{value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_arg_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("description.md");
+        std::fs::write(&file, "# Steps\n").unwrap();
+
+        assert_eq!(read_arg_value("plain text").unwrap(), "plain text");
+        assert_eq!(read_arg_value("@@alice ping").unwrap(), "@alice ping");
+        assert_eq!(
+            read_arg_value(&format!("@{}", file.display())).unwrap(),
+            "# Steps\n"
+        );
+        let missing = read_arg_value("@no-such-file.md").unwrap_err();
+        assert!(matches!(missing, AppError::Io(_)));
+        assert!(missing.to_string().contains("no-such-file.md"));
+    }
+
+    #[test]
+    fn test_parse_hours_invalid() {
+        for value in ["", "abc", "1:75", "-1", "h", "2x"] {
+            assert!(parse_hours(value).is_err(), "{value} should be rejected");
+        }
+    }
 }
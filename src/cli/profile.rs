@@ -2,9 +2,15 @@
 
 use clap::{Args, Subcommand};
 use serde::Serialize;
-
-use crate::config::{Config, ConfigPaths, Profile, ProfileStore};
-use crate::error::Result;
+use std::path::PathBuf;
+
+use super::timeout_parser;
+use crate::client::{
+    concurrent::{map_concurrent, DEFAULT_CONCURRENCY},
+    RedmineClient,
+};
+use crate::config::{Config, ConfigPaths, MergeSummary, Profile, ProfileStore};
+use crate::error::{AppError, Result};
 use crate::output::{markdown::markdown_table, MarkdownOutput, Meta};
 
 #[derive(Debug, Subcommand)]
@@ -17,6 +23,12 @@ pub enum ProfileCommand {
     List,
     /// Delete a profile.
     Delete(ProfileDelete),
+    /// Ping every profile and report reachability, authentication and latency.
+    Check(ProfileCheckArgs),
+    /// Replace a profile's API key, keeping the old one if the new key is rejected.
+    SetKey(ProfileSetKey),
+    /// Set the project used when issue list/create and time create omit --project.
+    SetDefaultProject(ProfileSetDefaultProject),
 }
 
 #[derive(Debug, Args)]
@@ -30,6 +42,9 @@ pub struct ProfileAdd {
     /// API key.
     #[arg(long)]
     pub api_key: String,
+    /// Request timeout in seconds (1-600, default 30).
+    #[arg(long, value_name = "SECS", value_parser = timeout_parser())]
+    pub timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Args)]
@@ -46,7 +61,87 @@ pub struct ProfileDelete {
 }
 
 #[derive(Debug, Args)]
-pub struct ConfigShow {}
+pub struct ProfileSetKey {
+    /// Profile name.
+    #[arg(long)]
+    pub name: String,
+    /// New API key.
+    #[arg(long, required_unless_present = "from_stdin")]
+    pub api_key: Option<String>,
+    /// Read the new API key from stdin (keeps it out of shell history).
+    #[arg(long, conflicts_with = "api_key")]
+    pub from_stdin: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProfileSetDefaultProject {
+    /// Profile name.
+    #[arg(long)]
+    pub name: String,
+    /// Project ID or identifier.
+    #[arg(long, required_unless_present = "clear")]
+    pub project: Option<String>,
+    /// Remove the default project.
+    #[arg(long, conflicts_with = "project")]
+    pub clear: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProfileCheckArgs {
+    /// Maximum number of profiles pinged at once.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: Option<ConfigCommand>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Show current configuration (default).
+    Show,
+    /// Print all profiles as TOML.
+    Export(ConfigExport),
+    /// Merge profiles from an exported TOML file.
+    Import(ConfigImport),
+    /// Print a setting from the `[settings]` table.
+    Get(ConfigGet),
+    /// Change a setting in the `[settings]` table.
+    Set(ConfigSet),
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigGet {
+    /// Setting name (time_rounding, branch_issue_pattern or default_activity.<project_id>).
+    pub key: String,
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigSet {
+    /// Setting name (time_rounding, branch_issue_pattern or default_activity.<project_id>).
+    pub key: String,
+    /// New value (empty removes a default_activity entry).
+    pub value: String,
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigExport {
+    /// Leave API keys blank in the output.
+    #[arg(long)]
+    pub no_keys: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigImport {
+    /// TOML file produced by `rdm config export`.
+    pub file: PathBuf,
+    /// Replace profiles that already exist.
+    #[arg(long)]
+    pub overwrite: bool,
+}
 
 /// Result of profile add command.
 #[derive(Debug, Clone, Serialize)]
@@ -143,6 +238,45 @@ impl MarkdownOutput for ProfileDeleted {
     }
 }
 
+/// Result of profile set-key command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileKeyUpdated {
+    pub name: String,
+    pub url: String,
+    pub api_key_redacted: String,
+}
+
+impl MarkdownOutput for ProfileKeyUpdated {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## API Key Updated\n\n");
+        output.push_str(&format!("- **Profile**: {}\n", self.name));
+        output.push_str(&format!("- **URL**: {}\n", self.url));
+        output.push_str(&format!("- **API Key**: {}\n", self.api_key_redacted));
+        output
+    }
+}
+
+/// Result of profile set-default-project command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileDefaultProjectSet {
+    pub name: String,
+    pub default_project: Option<String>,
+}
+
+impl MarkdownOutput for ProfileDefaultProjectSet {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Default Project Updated\n\n");
+        output.push_str(&format!("- **Profile**: {}\n", self.name));
+        match &self.default_project {
+            Some(project) => output.push_str(&format!("- **Default Project**: {}\n", project)),
+            None => output.push_str("- **Default Project**: *none*\n"),
+        }
+        output
+    }
+}
+
 /// Result of config show command.
 #[derive(Debug, Clone, Serialize)]
 pub struct ConfigInfo {
@@ -150,6 +284,8 @@ pub struct ConfigInfo {
     pub api_key_redacted: String,
     pub source: String,
     pub profile_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
 }
 
 impl MarkdownOutput for ConfigInfo {
@@ -162,6 +298,123 @@ impl MarkdownOutput for ConfigInfo {
         if let Some(name) = &self.profile_name {
             output.push_str(&format!("- **Profile**: {}\n", name));
         }
+        if let Some(project) = &self.default_project {
+            output.push_str(&format!("- **Default Project**: {}\n", project));
+        }
+        output
+    }
+}
+
+/// Result of config export command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigExported {
+    pub toml: String,
+}
+
+impl MarkdownOutput for ConfigExported {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        // Raw TOML so the output can be redirected straight into a file
+        self.toml.trim_end().to_string()
+    }
+}
+
+/// Result of config import command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigImported {
+    #[serde(flatten)]
+    pub summary: MergeSummary,
+    pub active: Option<String>,
+}
+
+impl MarkdownOutput for ConfigImported {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Configuration Imported\n\n");
+        for (label, names) in [
+            ("Added", &self.summary.added),
+            ("Updated", &self.summary.updated),
+            ("Skipped", &self.summary.skipped),
+        ] {
+            if !names.is_empty() {
+                output.push_str(&format!("- **{}**: {}\n", label, names.join(", ")));
+            }
+        }
+        if let Some(active) = &self.active {
+            output.push_str(&format!("- **Active**: {}\n", active));
+        }
+        if !self.summary.skipped.is_empty() {
+            output.push_str("\n*Use `--overwrite` to replace existing profiles*\n");
+        }
+        output
+    }
+}
+
+/// Result of config get/set commands.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSetting {
+    pub key: String,
+    pub value: Option<String>,
+}
+
+impl MarkdownOutput for ConfigSetting {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        match &self.value {
+            Some(value) => format!("- **{}**: {}\n", self.key, value),
+            None => format!("- **{}**: *not set*\n", self.key),
+        }
+    }
+}
+
+/// Health of a single profile.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileHealth {
+    pub name: String,
+    pub url: String,
+    /// The server answered.
+    pub reachable: bool,
+    /// The API key was accepted.
+    pub auth_ok: bool,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Result of profile check command.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileCheck {
+    pub profiles: Vec<ProfileHealth>,
+}
+
+impl MarkdownOutput for ProfileCheck {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Profile Check\n\n");
+
+        if self.profiles.is_empty() {
+            output.push_str("*No profiles configured*\n");
+            return output;
+        }
+
+        let yes_no = |b: bool| if b { "Yes" } else { "No" }.to_string();
+        let rows = self
+            .profiles
+            .iter()
+            .map(|p| {
+                vec![
+                    p.name.clone(),
+                    p.url.clone(),
+                    yes_no(p.reachable),
+                    yes_no(p.auth_ok),
+                    p.latency_ms
+                        .map(|ms| format!("{} ms", ms))
+                        .unwrap_or_else(|| "-".to_string()),
+                    p.error.clone().unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+        output.push_str(&markdown_table(
+            &["Profile", "URL", "Reachable", "Auth OK", "Latency", "Error"],
+            rows,
+        ));
         output
     }
 }
@@ -171,7 +424,9 @@ pub fn add_profile(args: &ProfileAdd, paths: &ConfigPaths) -> Result<ProfileAdde
     let mut store = ProfileStore::load(&paths.config_file)?;
     let is_first = store.profiles.is_empty();
 
-    store.add(Profile::new(&args.name, &args.url, &args.api_key));
+    let mut profile = Profile::new(&args.name, &args.url, &args.api_key);
+    profile.timeout_secs = args.timeout_secs;
+    store.add(profile);
     store.save(&paths.config_file)?;
 
     Ok(ProfileAdded {
@@ -212,6 +467,122 @@ pub fn list_profiles(paths: &ConfigPaths) -> Result<ProfileList> {
     })
 }
 
+/// Execute profile check command.
+pub async fn check_profiles(args: &ProfileCheckArgs, paths: &ConfigPaths) -> Result<ProfileCheck> {
+    let store = ProfileStore::load(&paths.config_file)?;
+    let mut profiles: Vec<Profile> = store.profiles.into_values().collect();
+    profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let profiles = map_concurrent(profiles, args.concurrency, |profile| async move {
+        let config = Config::from_profile(&profile);
+        let result = match RedmineClient::new(&config, false) {
+            Ok(client) => client.ping().await,
+            Err(e) => Err(e),
+        };
+        let mut health = ProfileHealth {
+            name: profile.name,
+            url: profile.url,
+            reachable: false,
+            auth_ok: false,
+            latency_ms: None,
+            error: None,
+        };
+        // One bad profile must not abort the others, so errors are recorded
+        match result {
+            Ok(ping) => {
+                health.reachable = true;
+                health.auth_ok = true;
+                health.latency_ms = Some(ping.latency_ms);
+            }
+            Err(e) => {
+                health.reachable = matches!(e, AppError::Auth { .. } | AppError::Api { .. });
+                health.error = Some(e.to_string());
+            }
+        }
+        health
+    })
+    .await;
+
+    Ok(ProfileCheck { profiles })
+}
+
+/// Execute profile set-key command.
+///
+/// The new key is pinged before the config file is written, so a rejected
+/// key leaves the stored one in place.
+pub async fn set_key(args: &ProfileSetKey, paths: &ConfigPaths) -> Result<ProfileKeyUpdated> {
+    let api_key = match &args.api_key {
+        Some(key) => key.trim().to_string(),
+        None => std::io::read_to_string(std::io::stdin())?
+            .trim()
+            .to_string(),
+    };
+    if api_key.is_empty() {
+        return Err(AppError::validation_with_hint(
+            "API key is empty",
+            "Pass the key with --api-key, or pipe it in with --from-stdin",
+        ));
+    }
+
+    let mut store = ProfileStore::load(&paths.config_file)?;
+    store.update_key(&args.name, &api_key)?;
+    let profile = store.profiles[&args.name].clone();
+
+    let config = Config::from_profile(&profile);
+    let ping = match RedmineClient::new(&config, false) {
+        Ok(client) => client.ping().await,
+        Err(e) => Err(e),
+    };
+    ping.map_err(|e| match e {
+        AppError::Auth { .. } => AppError::auth_with_hint(
+            format!("The new API key for profile '{}' was rejected", args.name),
+            "The previous key was kept. Copy the key from My account > API access key",
+        ),
+        e => AppError::api_with_hint(
+            format!(
+                "Could not verify the new API key for profile '{}': {}",
+                args.name, e
+            ),
+            None,
+            "The previous key was kept. Retry when the server is reachable",
+        ),
+    })?;
+    store.save(&paths.config_file)?;
+
+    Ok(ProfileKeyUpdated {
+        name: profile.name.clone(),
+        url: profile.url.clone(),
+        api_key_redacted: profile.redacted_api_key(),
+    })
+}
+
+/// Execute profile set-default-project command.
+pub fn set_default_project(
+    args: &ProfileSetDefaultProject,
+    paths: &ConfigPaths,
+) -> Result<ProfileDefaultProjectSet> {
+    let project = args
+        .project
+        .as_deref()
+        .map(str::trim)
+        .filter(|p| !p.is_empty());
+    if project.is_none() && !args.clear {
+        return Err(AppError::validation_with_hint(
+            "Project is empty",
+            "Pass a project ID or identifier, or --clear to remove the default",
+        ));
+    }
+
+    let mut store = ProfileStore::load(&paths.config_file)?;
+    store.set_default_project(&args.name, project)?;
+    store.save(&paths.config_file)?;
+
+    Ok(ProfileDefaultProjectSet {
+        name: args.name.clone(),
+        default_project: project.map(str::to_string),
+    })
+}
+
 /// Execute profile delete command.
 pub fn delete_profile(args: &ProfileDelete, paths: &ConfigPaths) -> Result<ProfileDeleted> {
     let mut store = ProfileStore::load(&paths.config_file)?;
@@ -238,5 +609,59 @@ pub fn show_config(config: &Config) -> ConfigInfo {
         api_key_redacted: config.redacted_api_key(),
         source: source.to_string(),
         profile_name: config.profile_name.clone(),
+        default_project: config.default_project.clone(),
     }
 }
+
+/// Execute config export command.
+pub fn export_config(args: &ConfigExport, paths: &ConfigPaths) -> Result<ConfigExported> {
+    let store = ProfileStore::load(&paths.config_file)?;
+    let store = if args.no_keys {
+        store.without_keys()
+    } else {
+        store
+    };
+    let toml = toml::to_string_pretty(&store)
+        .map_err(|e| AppError::config(format!("Failed to serialize config: {}", e)))?;
+    Ok(ConfigExported { toml })
+}
+
+/// Execute config import command.
+pub fn import_config(args: &ConfigImport, paths: &ConfigPaths) -> Result<ConfigImported> {
+    if !args.file.exists() {
+        return Err(AppError::validation_with_hint(
+            format!("File not found: {}", args.file.display()),
+            "Create one with `rdm config export > profiles.toml`.",
+        ));
+    }
+    let incoming = ProfileStore::load(&args.file)?;
+
+    let mut store = ProfileStore::load(&paths.config_file)?;
+    let summary = store.merge(incoming, args.overwrite);
+    store.save(&paths.config_file)?;
+
+    Ok(ConfigImported {
+        summary,
+        active: store.active.clone(),
+    })
+}
+
+/// Execute config get command.
+pub fn get_setting(args: &ConfigGet, paths: &ConfigPaths) -> Result<ConfigSetting> {
+    let store = ProfileStore::load(&paths.config_file)?;
+    Ok(ConfigSetting {
+        key: args.key.clone(),
+        value: store.settings.get(&args.key)?,
+    })
+}
+
+/// Execute config set command.
+pub fn set_setting(args: &ConfigSet, paths: &ConfigPaths) -> Result<ConfigSetting> {
+    let mut store = ProfileStore::load(&paths.config_file)?;
+    store.settings.set(&args.key, &args.value)?;
+    store.save(&paths.config_file)?;
+    Ok(ConfigSetting {
+        key: args.key.clone(),
+        value: store.settings.get(&args.key)?,
+    })
+}
@@ -2,9 +2,18 @@
 
 use clap::{Args, Subcommand};
 
-use crate::client::RedmineClient;
-use crate::error::Result;
-use crate::models::{Project, ProjectList};
+use super::{cached_list, limit_parser, page_size_parser, parse_date_arg, require_results};
+use crate::client::{
+    dry_run::WriteOutcome,
+    endpoints::{IssueFilters, MAX_PAGE_SIZE},
+    RedmineClient,
+};
+use crate::config::ConfigPaths;
+use crate::error::{AppError, Result};
+use crate::models::{
+    project_status_label, LastActivity, NewVersion, Project, ProjectList, ProjectNameFilter,
+    ProjectStateAction, ProjectStateChanged, VersionCreated, VersionSharing,
+};
 
 #[derive(Debug, Subcommand)]
 pub enum ProjectCommand {
@@ -12,12 +21,50 @@ pub enum ProjectCommand {
     List(ProjectListArgs),
     /// Get project details.
     Get(ProjectGetArgs),
+    /// Archive a project (administrators only).
+    Archive(ProjectStateArgs),
+    /// Unarchive a project (administrators only).
+    Unarchive(ProjectStateArgs),
+    /// Close a project, making it read-only.
+    Close(ProjectStateArgs),
+    /// Reopen a closed project.
+    Reopen(ProjectStateArgs),
+    /// Manage project versions (milestones).
+    #[command(subcommand)]
+    Versions(VersionCommand),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum VersionCommand {
+    /// Create a version.
+    Create(VersionCreateArgs),
 }
 
 #[derive(Debug, Args)]
 pub struct ProjectListArgs {
-    /// Maximum number of results.
-    #[arg(long, default_value = "25")]
+    /// Only projects whose name or identifier contains this text (case-insensitive).
+    #[arg(long, value_name = "TEXT")]
+    pub name_contains: Option<String>,
+    /// Fetch every page instead of one (cached for 24 hours).
+    #[arg(long)]
+    pub all: bool,
+    /// With --all, ignore the cached project list and fetch it again.
+    #[arg(long, requires = "all")]
+    pub refresh: bool,
+    /// Projects fetched per request with --all (1-100).
+    #[arg(
+        long,
+        alias = "limit-per-request",
+        value_name = "N",
+        default_value_t = MAX_PAGE_SIZE,
+        value_parser = page_size_parser()
+    )]
+    pub page_size: u32,
+    /// Exit with code 4 (not found) when no project matches.
+    #[arg(long)]
+    pub require_results: bool,
+    /// Maximum number of results (at most 100; 0 uses the server default).
+    #[arg(long, default_value = "25", value_parser = limit_parser())]
     pub limit: u32,
     /// Offset for pagination.
     #[arg(long, default_value = "0")]
@@ -32,11 +79,99 @@ pub struct ProjectGetArgs {
     /// Project identifier (slug).
     #[arg(long, conflicts_with = "id")]
     pub identifier: Option<String>,
+    /// Also show the most recently updated issue (one extra request).
+    #[arg(long)]
+    pub with_activity: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct ProjectStateArgs {
+    /// Project ID or identifier.
+    #[arg(long)]
+    pub project: String,
+}
+
+#[derive(Debug, Args)]
+pub struct VersionCreateArgs {
+    /// Project ID or identifier.
+    #[arg(long)]
+    pub project: String,
+    /// Version name.
+    #[arg(long)]
+    pub name: String,
+    /// Version description.
+    #[arg(long)]
+    pub description: Option<String>,
+    /// Due date (YYYY-MM-DD, `today` or `yesterday`).
+    #[arg(long, value_name = "DATE")]
+    pub due_date: Option<String>,
+    /// Which projects can use the version: none, descendants, hierarchy, tree or system.
+    #[arg(long)]
+    pub sharing: Option<String>,
 }
 
 /// Execute project list command.
-pub async fn list(client: &RedmineClient, args: &ProjectListArgs) -> Result<ProjectList> {
-    client.list_projects(args.limit, args.offset).await
+pub async fn list(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &ProjectListArgs,
+) -> Result<ProjectList> {
+    let list = if args.all {
+        let fetch = async { Ok(client.list_all_projects(args.page_size).await?.projects) };
+        let projects = if client.uses_cache() {
+            cached_list(client, paths, "projects.json", args.refresh, fetch)
+                .await?
+                .0
+        } else {
+            fetch.await?
+        };
+        let count = projects.len() as u32;
+        ProjectList {
+            projects,
+            total_count: Some(count),
+            offset: Some(0),
+            limit: Some(count),
+            name_filter: None,
+        }
+    } else {
+        client.list_projects(args.limit, args.offset).await?
+    };
+
+    let list = match &args.name_contains {
+        Some(query) => filter_by_name(list, query),
+        None => list,
+    };
+    require_results(args.require_results, list.projects.len(), "projects")?;
+    Ok(list)
+}
+
+/// Keep projects whose name or identifier contains `query`, ignoring case.
+///
+/// Redmine's project list has no name filter, so this runs on the fetched page(s).
+fn filter_by_name(list: ProjectList, query: &str) -> ProjectList {
+    let searched = list.projects.len() as u32;
+    let available = list.total_count.unwrap_or(searched).max(searched);
+    let needle = query.to_lowercase();
+    let projects: Vec<Project> = list
+        .projects
+        .into_iter()
+        .filter(|p| {
+            p.name.to_lowercase().contains(&needle) || p.identifier.to_lowercase().contains(&needle)
+        })
+        .collect();
+
+    let matched = projects.len() as u32;
+    ProjectList {
+        projects,
+        total_count: Some(matched),
+        offset: Some(0),
+        limit: Some(matched),
+        name_filter: Some(ProjectNameFilter {
+            query: query.to_string(),
+            searched,
+            available,
+        }),
+    }
 }
 
 /// Execute project get command.
@@ -46,11 +181,104 @@ pub async fn get(client: &RedmineClient, args: &ProjectGetArgs) -> Result<Projec
     } else if let Some(identifier) = &args.identifier {
         identifier.clone()
     } else {
-        return Err(crate::error::AppError::validation_with_hint(
+        return Err(AppError::validation_with_hint(
             "Either --id or --identifier is required",
             "Use `rdm project get --id 1` or `rdm project get --identifier my-project`",
         ));
     };
 
-    client.get_project(&id_or_identifier).await
+    let mut project = client.get_project(&id_or_identifier).await?;
+    if args.with_activity {
+        project.last_activity = Some(last_activity(client, project.id).await?);
+    }
+    Ok(project)
+}
+
+/// Find the most recently updated issue in a project.
+async fn last_activity(client: &RedmineClient, project_id: u32) -> Result<LastActivity> {
+    let filters = IssueFilters {
+        project: Some(project_id.to_string()),
+        status: Some("*".to_string()),
+        sort: Some("updated_on:desc".to_string()),
+        limit: 1,
+        ..Default::default()
+    };
+
+    match client.list_issues(filters).await {
+        Ok(list) => Ok(match list.issues.into_iter().next() {
+            Some(issue) => LastActivity::Issue {
+                issue_id: issue.id,
+                updated_on: issue.updated_on.unwrap_or_else(|| "-".to_string()),
+            },
+            None => LastActivity::NoIssues,
+        }),
+        Err(AppError::Auth { .. }) => Ok(LastActivity::Forbidden),
+        Err(e) => Err(e),
+    }
+}
+
+/// Execute a project archive/unarchive/close/reopen command.
+pub async fn set_state(
+    client: &RedmineClient,
+    args: &ProjectStateArgs,
+    action: ProjectStateAction,
+) -> Result<WriteOutcome<ProjectStateChanged>> {
+    if let WriteOutcome::Planned(request) = client.set_project_state(&args.project, action).await? {
+        return Ok(WriteOutcome::Planned(request));
+    }
+
+    // Confirm the new state. Redmine hides archived projects (403 or 404), so
+    // then the state is assumed rather than read back.
+    let (status, confirmed) = match client.get_project(&args.project).await {
+        Ok(project) => match project.status {
+            Some(status) => (status, true),
+            None => (action.resulting_status(), false),
+        },
+        Err(AppError::Auth { .. } | AppError::NotFound { .. })
+            if action == ProjectStateAction::Archive =>
+        {
+            (action.resulting_status(), false)
+        }
+        Err(e) => return Err(e),
+    };
+
+    Ok(WriteOutcome::Sent(ProjectStateChanged {
+        project: args.project.clone(),
+        action: action.past_tense().to_string(),
+        status,
+        status_name: project_status_label(status).to_string(),
+        confirmed,
+    }))
+}
+
+/// Execute project versions create command.
+pub async fn create_version(
+    client: &RedmineClient,
+    args: &VersionCreateArgs,
+) -> Result<WriteOutcome<VersionCreated>> {
+    if args.name.trim().is_empty() {
+        return Err(AppError::validation("--name must not be empty"));
+    }
+    let due_date = parse_date_arg("--due-date", args.due_date.as_deref())?;
+    let sharing = args
+        .sharing
+        .as_deref()
+        .map(|s| {
+            VersionSharing::parse(s).ok_or_else(|| {
+                AppError::validation_with_hint(
+                    format!("Invalid --sharing value: '{}'", s),
+                    format!("Valid values: {}", VersionSharing::VALUES.join(", ")),
+                )
+            })
+        })
+        .transpose()?;
+
+    let version = NewVersion {
+        name: args.name.clone(),
+        description: args.description.clone(),
+        due_date,
+        sharing,
+    };
+    let created = client.create_version(&args.project, version).await?;
+    Ok(created.map(|version| VersionCreated { version }))
 }
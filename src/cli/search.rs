@@ -0,0 +1,67 @@
+//! Search command.
+
+use clap::{Args, ValueEnum};
+
+use super::limit_parser;
+use crate::client::RedmineClient;
+use crate::error::Result;
+use crate::models::SearchResults;
+
+#[derive(Debug, Args)]
+pub struct SearchArgs {
+    /// Text to search for.
+    pub query: String,
+    /// Result types to include, comma-separated (default: all).
+    #[arg(long, value_enum, value_delimiter = ',')]
+    pub types: Vec<SearchType>,
+    /// Restrict the search to a project (ID or identifier).
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Maximum number of results (at most 100; 0 uses the server default).
+    #[arg(long, default_value = "25", value_parser = limit_parser())]
+    pub limit: u32,
+    /// Offset for pagination.
+    #[arg(long, default_value = "0")]
+    pub offset: u32,
+}
+
+/// Result type accepted by the search endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SearchType {
+    Issues,
+    News,
+    Documents,
+    Changesets,
+    Wiki,
+    Messages,
+    Projects,
+}
+
+impl SearchType {
+    /// Query parameter name used by the API to enable this type.
+    pub fn as_api_param(&self) -> &'static str {
+        match self {
+            Self::Issues => "issues",
+            Self::News => "news",
+            Self::Documents => "documents",
+            Self::Changesets => "changesets",
+            Self::Wiki => "wiki_pages",
+            Self::Messages => "messages",
+            Self::Projects => "projects",
+        }
+    }
+}
+
+/// Execute search command.
+pub async fn search(client: &RedmineClient, args: &SearchArgs) -> Result<SearchResults> {
+    let scopes: Vec<&str> = args.types.iter().map(|t| t.as_api_param()).collect();
+    client
+        .search(
+            &args.query,
+            args.project.as_deref(),
+            &scopes,
+            args.limit,
+            args.offset,
+        )
+        .await
+}
@@ -1,19 +1,31 @@
 //! Time entry commands.
 
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 use clap::{Args, Subcommand};
 use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
 
-use super::parse_custom_fields;
-use crate::cache::{resolve_activity, ActivityCache};
-use crate::client::{endpoints::TimeEntryFilters, RedmineClient};
-use crate::config::ConfigPaths;
+use super::git::{branch_issue_id, current_branch, DEFAULT_BRANCH_ISSUE_PATTERN};
+use super::{
+    cached_list, limit_parser, page_size_parser, parse_custom_fields, parse_date, parse_date_arg,
+    parse_hours, require_results, resolve_me, resolve_project_filter, resolve_project_id_cached,
+    resolve_project_write_id,
+};
+use crate::client::{
+    concurrent::{map_concurrent, DEFAULT_CONCURRENCY},
+    dry_run::{DryRunRequest, WriteOutcome},
+    endpoints::{TimeEntryFilters, MAX_PAGE_SIZE},
+    RedmineClient,
+};
+use crate::config::{ConfigPaths, Settings};
 use crate::error::{AppError, Result};
 use crate::models::{
-    ActivityList, GroupByField, GroupedTimeEntries, NewTimeEntry, TimeEntry, TimeEntryCreated,
-    TimeEntryDeleted, TimeEntryList, TimeEntryUpdated, UpdateTimeEntry,
+    Activity, ActivityList, GroupByField, GroupedTimeEntries, NewTimeEntry, ProjectWriteId,
+    TimeEntry, TimeEntryCreated, TimeEntryDeleted, TimeEntryList, TimeEntryUpdated, TimeSummary,
+    UpdateTimeEntry, WeeklyGrid,
 };
-use crate::output::{MarkdownOutput, Meta};
+use crate::output::{markdown::markdown_table, CsvOutput, MarkdownOutput, Meta};
 
 #[derive(Debug, Subcommand)]
 pub enum TimeCommand {
@@ -24,12 +36,16 @@ pub enum TimeCommand {
     Create(TimeCreateArgs),
     /// List time entries.
     List(TimeListArgs),
+    /// Total hours per day and per week.
+    Summary(TimeSummaryArgs),
     /// Get time entry details.
     Get(TimeGetArgs),
     /// Update a time entry.
     Update(TimeUpdateArgs),
     /// Delete a time entry.
     Delete(TimeDeleteArgs),
+    /// Create time entries from a JSON or CSV file.
+    Import(TimeImportArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -50,24 +66,37 @@ pub struct TimeCreateArgs {
     /// Issue ID.
     #[arg(long, conflicts_with = "project")]
     pub issue: Option<u32>,
-    /// Project ID (if not logging against an issue).
+    /// Project ID or identifier (if not logging against an issue; defaults to the
+    /// profile's default project).
     #[arg(long, conflicts_with = "issue")]
-    pub project: Option<u32>,
+    pub project: Option<String>,
+    /// Default project of the active profile, used when neither --issue nor --project is given.
+    #[arg(skip)]
+    pub default_project: Option<String>,
+    /// Infer the issue ID from the current git branch (e.g. feature/1234-foo).
+    #[arg(long, conflicts_with_all = ["issue", "project"])]
+    pub from_issue_branch: bool,
     /// Hours spent.
     #[arg(long)]
     pub hours: f64,
-    /// Activity name or ID.
+    /// Round hours to the nearest increment in minutes (must divide 60, e.g. 15).
+    #[arg(long, value_name = "MINUTES")]
+    pub round: Option<u32>,
+    /// Activity name or ID (defaults to the server's default activity).
     #[arg(long)]
-    pub activity: String,
-    /// Date spent (YYYY-MM-DD, defaults to today).
+    pub activity: Option<String>,
+    /// Date spent (YYYY-MM-DD, `today` or `yesterday`; defaults to today).
     #[arg(long)]
     pub spent_on: Option<String>,
     /// Comment.
     #[arg(long)]
     pub comment: Option<String>,
-    /// User ID (for admins logging time for others).
+    /// User ID (for logging time for others), or "me".
     #[arg(long)]
-    pub user: Option<u32>,
+    pub user: Option<String>,
+    /// Skip checking that the issue exists before creating the entry.
+    #[arg(long)]
+    pub no_verify_issue: bool,
 }
 
 #[derive(Debug, Args)]
@@ -81,10 +110,10 @@ pub struct TimeListArgs {
     /// Filter by user ID or "me".
     #[arg(long)]
     pub user: Option<String>,
-    /// Filter from date (YYYY-MM-DD).
+    /// Filter from date (YYYY-MM-DD, `today` or `yesterday`).
     #[arg(long)]
     pub from: Option<String>,
-    /// Filter to date (YYYY-MM-DD).
+    /// Filter to date (YYYY-MM-DD, `today` or `yesterday`).
     #[arg(long)]
     pub to: Option<String>,
     /// Filter by custom field value (format: id=value, repeatable).
@@ -93,19 +122,76 @@ pub struct TimeListArgs {
     /// Group results by field (user, project, activity, issue, spent_on, or cf_<id>).
     #[arg(long)]
     pub group_by: Option<String>,
-    /// Maximum number of results.
-    #[arg(long, default_value = "25")]
+    /// Show a week as an issue-by-day grid of hours (requires --from; --to defaults to 6 days later).
+    #[arg(long, conflicts_with = "group_by")]
+    pub weekly_grid: bool,
+    /// Only entries with at least this many hours (applied after fetching).
+    #[arg(long, value_name = "HOURS")]
+    pub min_hours: Option<f64>,
+    /// Only entries with at most this many hours (applied after fetching).
+    #[arg(long, value_name = "HOURS")]
+    pub max_hours: Option<f64>,
+    /// Fetch every page instead of one.
+    #[arg(long)]
+    pub all: bool,
+    /// Time entries fetched per request with --all or --weekly-grid (1-100).
+    #[arg(
+        long,
+        alias = "limit-per-request",
+        value_name = "N",
+        default_value_t = MAX_PAGE_SIZE,
+        value_parser = page_size_parser()
+    )]
+    pub page_size: u32,
+    /// Look up each referenced issue's subject (adds an issue.subject column to CSV).
+    #[arg(long)]
+    pub enrich: bool,
+    /// Maximum number of concurrent requests for --enrich.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    pub concurrency: usize,
+    /// Exit with code 4 (not found) when no time entry matches.
+    #[arg(long)]
+    pub require_results: bool,
+    /// Maximum number of results (at most 100; 0 uses the server default).
+    #[arg(long, default_value = "25", value_parser = limit_parser())]
     pub limit: u32,
     /// Offset for pagination.
     #[arg(long, default_value = "0")]
     pub offset: u32,
 }
 
+#[derive(Debug, Args)]
+pub struct TimeSummaryArgs {
+    /// Filter by project (ID or identifier).
+    #[arg(long)]
+    pub project: Option<String>,
+    /// Filter by user ID or "me".
+    #[arg(long)]
+    pub user: Option<String>,
+    /// First day to include (YYYY-MM-DD, `today` or `yesterday`).
+    #[arg(long)]
+    pub from: Option<String>,
+    /// Last day to include (YYYY-MM-DD, `today` or `yesterday`).
+    #[arg(long)]
+    pub to: Option<String>,
+    /// Time entries fetched per request (1-100).
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = MAX_PAGE_SIZE,
+        value_parser = page_size_parser()
+    )]
+    pub page_size: u32,
+}
+
 #[derive(Debug, Args)]
 pub struct TimeGetArgs {
     /// Time entry ID.
     #[arg(long)]
     pub id: u32,
+    /// Look up the subject of the referenced issue (one extra request).
+    #[arg(long)]
+    pub enrich: bool,
 }
 
 #[derive(Debug, Args)]
@@ -119,12 +205,15 @@ pub struct TimeUpdateArgs {
     /// New activity (name or ID).
     #[arg(long)]
     pub activity: Option<String>,
-    /// New date (YYYY-MM-DD).
+    /// New date (YYYY-MM-DD, `today` or `yesterday`).
     #[arg(long)]
     pub spent_on: Option<String>,
     /// New comment.
     #[arg(long)]
     pub comment: Option<String>,
+    /// Remove the comment.
+    #[arg(long, conflicts_with = "comment")]
+    pub clear_comment: bool,
 }
 
 #[derive(Debug, Args)]
@@ -134,41 +223,24 @@ pub struct TimeDeleteArgs {
     pub id: u32,
 }
 
-/// Get the cache file path.
-fn cache_path(paths: &ConfigPaths) -> std::path::PathBuf {
-    paths.cache_dir.join("activities.json")
+#[derive(Debug, Args)]
+pub struct TimeImportArgs {
+    /// JSON array of entries, or CSV with a header row (`.csv` extension). Fields:
+    /// issue or project, hours, activity, spent_on, comment.
+    #[arg(long)]
+    pub file: PathBuf,
 }
 
 /// Load or fetch activities, using cache when valid.
 async fn get_activities(
     client: &RedmineClient,
     paths: &ConfigPaths,
-    force_refresh: bool,
-) -> Result<(ActivityList, bool)> {
-    let cache_file = cache_path(paths);
-
-    // Try loading from cache
-    if !force_refresh {
-        if let Ok(Some(cache)) = ActivityCache::load(&cache_file) {
-            if cache.is_valid() {
-                return Ok((
-                    ActivityList {
-                        time_entry_activities: cache.activities,
-                    },
-                    true,
-                ));
-            }
-        }
-    }
-
-    // Fetch from server
-    let activities = client.list_activities().await?;
-
-    // Update cache
-    let cache = ActivityCache::new(activities.time_entry_activities.clone());
-    let _ = cache.save(&cache_file);
-
-    Ok((activities, false))
+    refresh: bool,
+) -> Result<(Vec<Activity>, bool)> {
+    cached_list(client, paths, "activities.json", refresh, async {
+        Ok(client.list_activities().await?.time_entry_activities)
+    })
+    .await
 }
 
 /// Execute activities list command.
@@ -177,16 +249,20 @@ pub async fn list_activities(
     paths: &ConfigPaths,
     args: &ActivitiesListArgs,
 ) -> Result<ActivityList> {
-    let (activities, _from_cache) = get_activities(client, paths, args.refresh).await?;
-    Ok(activities)
+    let (time_entry_activities, _) = get_activities(client, paths, args.refresh).await?;
+    Ok(ActivityList {
+        time_entry_activities,
+    })
 }
 
 /// Execute time create command.
 pub async fn create(
     client: &RedmineClient,
     paths: &ConfigPaths,
+    settings: &Settings,
     args: &TimeCreateArgs,
-) -> Result<TimeEntryCreated> {
+) -> Result<WriteOutcome<TimeEntryCreated>> {
+    let spent_on = parse_date_arg("--spent-on", args.spent_on.as_deref())?;
     // Validate hours
     if args.hours <= 0.0 {
         return Err(AppError::validation_with_hint(
@@ -195,57 +271,291 @@ pub async fn create(
         ));
     }
 
-    // Validate issue or project
-    if args.issue.is_none() && args.project.is_none() {
+    let rounding = match args.round {
+        Some(minutes) => Some(minutes),
+        None => settings.time_rounding,
+    };
+    let hours = match rounding {
+        Some(minutes) => round_hours(args.hours, minutes)?,
+        None => args.hours,
+    };
+
+    let issue = if args.from_issue_branch {
+        Some(issue_from_branch(settings)?)
+    } else {
+        args.issue
+    };
+
+    let user_id = args.user.as_deref().map(parse_user).transpose()?.flatten();
+
+    // Validate issue or project; the profile's default project only applies without an issue
+    let project = match (&args.project, issue) {
+        (Some(project), _) => Some(project.as_str()),
+        (None, None) => args.default_project.as_deref(),
+        (None, Some(_)) => None,
+    };
+    if issue.is_none() && project.is_none() {
         return Err(AppError::validation_with_hint(
             "Either --issue or --project is required",
             "Use `--issue 123` to log time against an issue or `--project 1` for project-level time",
         ));
     }
+    let project_id = match project {
+        Some(project) => Some(resolve_project_write_id(client, project).await?),
+        None => None,
+    };
+
+    // Catch a mistyped issue ID before Redmine rejects the entry with a generic 422
+    let issue_project = match issue {
+        Some(id) if !args.no_verify_issue && !client.is_dry_run() => {
+            Some(verify_issue(client, id).await?)
+        }
+        _ => None,
+    };
 
     // Resolve activity
     let (activities, _) = get_activities(client, paths, false).await?;
-    let cache = ActivityCache::new(activities.time_entry_activities);
-    let activity_id = resolve_activity(&cache, &args.activity)?;
+    let project = project_id
+        .as_ref()
+        .and_then(ProjectWriteId::id)
+        .or(issue_project);
+    let activity_id = match &args.activity {
+        Some(activity) => activity_id(client, &activities, activity)?,
+        None => match project_default_activity(client, settings, issue, project).await? {
+            Some(activity) => activity_id(client, &activities, &activity)?,
+            None => default_activity_id(client, &activities)?,
+        },
+    };
 
     // Default to today
-    let spent_on = args
-        .spent_on
-        .clone()
-        .unwrap_or_else(|| Local::now().format("%Y-%m-%d").to_string());
+    let spent_on = spent_on.unwrap_or_else(|| Local::now().format("%Y-%m-%d").to_string());
 
     let entry = NewTimeEntry {
-        issue_id: args.issue,
-        project_id: args.project,
-        hours: args.hours,
+        issue_id: issue,
+        project_id,
+        hours,
         activity_id,
         spent_on: Some(spent_on),
         comments: args.comment.clone(),
-        user_id: args.user,
+        user_id,
     };
 
     let created = client.create_time_entry(entry).await?;
-    Ok(TimeEntryCreated {
-        time_entry: created,
+    Ok(created.map(|time_entry| TimeEntryCreated {
+        time_entry,
+        requested_hours: (hours != args.hours).then_some(args.hours),
+    }))
+}
+
+/// Check that an issue exists and is visible, returning its project ID.
+async fn verify_issue(client: &RedmineClient, id: u32) -> Result<u32> {
+    match client.get_issue(id).await {
+        Ok(issue) => Ok(issue.project.id),
+        Err(AppError::NotFound { .. }) => Err(AppError::not_found_with_hint(
+            "Issue",
+            id.to_string(),
+            "Check the ID with `rdm issue list`, or pass --no-verify-issue to skip this check",
+        )),
+        Err(e) => Err(e),
+    }
+}
+
+/// Resolve an activity name or ID.
+///
+/// Activities are not listed under --dry-run, so without a valid cache only
+/// IDs can be used.
+fn activity_id(client: &RedmineClient, activities: &[Activity], name_or_id: &str) -> Result<u32> {
+    if client.is_dry_run() && activities.is_empty() {
+        return name_or_id.parse().map_err(|_| {
+            AppError::validation_with_hint(
+                format!("Cannot look up activity '{}' with --dry-run", name_or_id),
+                "Pass the activity ID instead of its name",
+            )
+        });
+    }
+    find_activity(activities, name_or_id)
+        .map(|a| a.id)
+        .ok_or_else(|| {
+            AppError::validation_with_hint(
+                format!("Unknown activity: '{}'", name_or_id),
+                "Use `rdm time activities list` to see available activities.",
+            )
+        })
+}
+
+/// Resolve the server's default activity; like [`activity_id`], this needs
+/// the activity list.
+fn default_activity_id(client: &RedmineClient, activities: &[Activity]) -> Result<u32> {
+    if client.is_dry_run() && activities.is_empty() {
+        return Err(AppError::validation_with_hint(
+            "Cannot look up the default activity with --dry-run",
+            "Pass the activity ID with --activity",
+        ));
+    }
+    activities
+        .iter()
+        .find(|a| a.is_default.unwrap_or(false))
+        .map(|a| a.id)
+        .ok_or_else(|| {
+            AppError::validation_with_hint(
+                "No default activity is configured",
+                "Pass `--activity <name|id>`; use `rdm time activities list` to see available activities.",
+            )
+        })
+}
+
+/// Find an activity by ID, or else by name (case-insensitive).
+fn find_activity<'a>(activities: &'a [Activity], name_or_id: &str) -> Option<&'a Activity> {
+    let by_id = name_or_id
+        .parse::<u32>()
+        .ok()
+        .and_then(|id| activities.iter().find(|a| a.id == id));
+    let name = name_or_id.to_lowercase();
+    by_id.or_else(|| activities.iter().find(|a| a.name.to_lowercase() == name))
+}
+
+/// Look up the configured default activity for the entry's project.
+///
+/// The issue is only fetched when some project has a default configured.
+async fn project_default_activity(
+    client: &RedmineClient,
+    settings: &Settings,
+    issue: Option<u32>,
+    project: Option<u32>,
+) -> Result<Option<String>> {
+    if settings.default_activity.is_empty() {
+        return Ok(None);
+    }
+
+    let project_id = match (project, issue) {
+        (Some(project), _) => project,
+        // The issue cannot be read under --dry-run
+        (None, Some(_)) if client.is_dry_run() => return Ok(None),
+        (None, Some(issue)) => client.get_issue(issue).await?.project.id,
+        (None, None) => return Ok(None),
+    };
+    Ok(settings.project_activity(project_id).map(str::to_string))
+}
+
+/// Parse `--user`: a user ID, or `me` for the API key's own user.
+///
+/// Redmine logs time for the authenticated user when no user is sent, so `me`
+/// needs no lookup and maps to `None`.
+fn parse_user(value: &str) -> Result<Option<u32>> {
+    if value.eq_ignore_ascii_case("me") {
+        return Ok(None);
+    }
+    value.parse::<u32>().map(Some).map_err(|_| {
+        AppError::validation_with_hint(
+            format!("Invalid --user value: '{}'", value),
+            "Use a numeric user ID or `me`",
+        )
+    })
+}
+
+/// Round hours to the nearest `minutes` increment, never below one increment.
+fn round_hours(hours: f64, minutes: u32) -> Result<f64> {
+    if minutes == 0 || 60 % minutes != 0 {
+        return Err(AppError::validation_with_hint(
+            format!("Invalid rounding increment: {} minutes", minutes),
+            "Use an increment that divides 60, such as 5, 6, 10, 15, 20 or 30",
+        ));
+    }
+    let increments = (hours * 60.0 / f64::from(minutes)).round().max(1.0);
+    Ok(increments * f64::from(minutes) / 60.0)
+}
+
+/// Infer the issue ID from the current git branch name.
+fn issue_from_branch(settings: &Settings) -> Result<u32> {
+    let pattern = settings
+        .branch_issue_pattern
+        .as_deref()
+        .unwrap_or(DEFAULT_BRANCH_ISSUE_PATTERN);
+
+    let branch = current_branch()?;
+    branch_issue_id(&branch, pattern)?.ok_or_else(|| {
+        AppError::validation_with_hint(
+            format!("Could not find an issue ID in branch '{}'", branch),
+            "Name the branch like `feature/1234-description`, set one with `rdm config set branch_issue_pattern <regex>`, or pass --issue",
+        )
     })
 }
 
 /// Execute time list command.
-pub async fn list(client: &RedmineClient, args: &TimeListArgs) -> Result<TimeListResult> {
+pub async fn list(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &TimeListArgs,
+) -> Result<TimeListResult> {
     // Parse custom field filters
     let custom_fields = parse_custom_fields(&args.custom_fields)?;
+    if let (Some(min), Some(max)) = (args.min_hours, args.max_hours) {
+        if min > max {
+            return Err(AppError::validation_with_hint(
+                format!(
+                    "--min-hours ({}) is greater than --max-hours ({})",
+                    min, max
+                ),
+                "Swap the values or drop one of the bounds",
+            ));
+        }
+    }
+
+    let from = parse_date_arg("--from", args.from.as_deref())?;
+    let to = parse_date_arg("--to", args.to.as_deref())?;
+    let week = if args.weekly_grid {
+        Some(grid_week(from.as_deref(), to.as_deref())?)
+    } else {
+        None
+    };
+
+    let project = resolve_project_filter(client, paths, args.project.as_deref()).await?;
+
+    let mut user = args.user.clone();
+    resolve_me(client, &mut user).await?;
 
     let filters = TimeEntryFilters {
-        project: args.project.clone(),
+        project,
         issue: args.issue,
-        user: args.user.clone(),
-        from: args.from.clone(),
-        to: args.to.clone(),
+        user,
+        from,
+        to: match week {
+            Some((_, to)) => Some(to.format("%Y-%m-%d").to_string()),
+            None => to,
+        },
         custom_fields,
         limit: args.limit,
         offset: args.offset,
     };
-    let entries = client.list_time_entries(filters).await?;
+    // The grid needs the whole week, not a page of it.
+    let entries = if args.all || week.is_some() {
+        client
+            .list_all_time_entries(filters, args.page_size)
+            .await?
+    } else {
+        client.list_time_entries(filters).await?
+    };
+    let mut entries = if args.min_hours.is_some() || args.max_hours.is_some() {
+        filter_by_hours(entries, args.min_hours, args.max_hours)
+    } else {
+        entries
+    };
+    require_results(
+        args.require_results,
+        entries.time_entries.len(),
+        "time entries",
+    )?;
+    if args.enrich {
+        enrich_issue_subjects(client, &mut entries.time_entries, args.concurrency).await?;
+    }
+
+    if let Some((from, to)) = week {
+        return Ok(TimeListResult::Weekly(WeeklyGrid::from_entries(
+            &entries.time_entries,
+            from,
+            to,
+        )));
+    }
 
     // If grouping is requested, group the results
     if let Some(group_by_str) = &args.group_by {
@@ -256,31 +566,152 @@ pub async fn list(client: &RedmineClient, args: &TimeListArgs) -> Result<TimeLis
             )
         })?;
 
-        let grouped = GroupedTimeEntries::from_entries(entries.time_entries, &group_by);
+        let matched = entries.total_count;
+        let mut grouped = GroupedTimeEntries::from_entries(entries.time_entries, &group_by);
+        grouped.matched_count = matched.unwrap_or(grouped.total_count);
         return Ok(TimeListResult::Grouped(grouped));
     }
 
     Ok(TimeListResult::List(entries))
 }
 
+/// Execute time summary command, fetching every matching entry.
+pub async fn summary(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    args: &TimeSummaryArgs,
+) -> Result<TimeSummary> {
+    let from = parse_date_arg("--from", args.from.as_deref())?;
+    let to = parse_date_arg("--to", args.to.as_deref())?;
+    let project = resolve_project_filter(client, paths, args.project.as_deref()).await?;
+    let mut user = args.user.clone();
+    resolve_me(client, &mut user).await?;
+    let filters = TimeEntryFilters {
+        project,
+        user,
+        from: from.clone(),
+        to: to.clone(),
+        ..Default::default()
+    };
+    let entries = client
+        .list_all_time_entries(filters, args.page_size)
+        .await?;
+    Ok(TimeSummary::from_entries(entries.time_entries, from, to))
+}
+
+/// Resolve the `--weekly-grid` date range: `--from` up to 7 days, `--to` defaulting to the 7th.
+fn grid_week(from: Option<&str>, to: Option<&str>) -> Result<(NaiveDate, NaiveDate)> {
+    let parse = |flag: &str, value: &str| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+            AppError::validation_with_hint(
+                format!("Invalid {} value: '{}'", flag, value),
+                "Use the YYYY-MM-DD format, e.g. 2024-03-31",
+            )
+        })
+    };
+    let from = from.ok_or_else(|| {
+        AppError::validation_with_hint(
+            "--weekly-grid requires --from",
+            "Pass the first day of the week, e.g. `--from 2024-01-15`",
+        )
+    })?;
+    let from = parse("--from", from)?;
+    let to = match to {
+        Some(to) => parse("--to", to)?,
+        None => from + chrono::Duration::days(6),
+    };
+    let days = (to - from).num_days();
+    if !(0..7).contains(&days) {
+        return Err(AppError::validation_with_hint(
+            format!(
+                "--weekly-grid covers at most 7 days, got {} to {}",
+                from, to
+            ),
+            "Use a --to date within 6 days after --from, or omit --to",
+        ));
+    }
+    Ok((from, to))
+}
+
+/// Fill in the subject of every referenced issue, fetching each issue once.
+///
+/// Issues that no longer exist or are not visible keep an empty subject.
+async fn enrich_issue_subjects(
+    client: &RedmineClient,
+    entries: &mut [TimeEntry],
+    concurrency: usize,
+) -> Result<()> {
+    let ids: BTreeSet<u32> = entries
+        .iter()
+        .filter_map(|t| t.issue.as_ref().map(|i| i.id))
+        .collect();
+    let results = map_concurrent(ids.into_iter().collect(), concurrency, |id| async move {
+        (id, client.get_issue(id).await)
+    })
+    .await;
+
+    let mut subjects = BTreeMap::new();
+    for (id, result) in results {
+        match result {
+            Ok(issue) => {
+                subjects.insert(id, issue.subject);
+            }
+            Err(AppError::NotFound { .. }) => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    for issue in entries.iter_mut().filter_map(|t| t.issue.as_mut()) {
+        issue.subject = subjects.get(&issue.id).cloned();
+    }
+    Ok(())
+}
+
+/// Keep entries whose hours fall within the bounds (inclusive).
+///
+/// Redmine cannot filter by hours, so this runs on the fetched page(s) and the
+/// totals describe the filtered set.
+fn filter_by_hours(list: TimeEntryList, min: Option<f64>, max: Option<f64>) -> TimeEntryList {
+    let time_entries: Vec<TimeEntry> = list
+        .time_entries
+        .into_iter()
+        .filter(|t| !min.is_some_and(|min| t.hours < min) && !max.is_some_and(|max| t.hours > max))
+        .collect();
+
+    let count = time_entries.len() as u32;
+    TimeEntryList {
+        time_entries,
+        total_count: Some(count),
+        offset: Some(0),
+        limit: Some(count),
+    }
+}
+
 /// Result of time list command - either grouped or ungrouped.
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum TimeListResult {
     List(TimeEntryList),
     Grouped(GroupedTimeEntries),
+    Weekly(WeeklyGrid),
 }
 
 impl TimeListResult {
-    /// Get pagination metadata.
+    /// Get pagination metadata and the total hours.
     pub fn meta(&self) -> Meta {
         match self {
             TimeListResult::List(list) => Meta::paginated(
                 list.total_count.unwrap_or(0),
                 list.limit.unwrap_or(25),
                 list.offset.unwrap_or(0),
-            ),
-            TimeListResult::Grouped(grouped) => Meta::paginated(grouped.total_count, 0, 0),
+            )
+            .with_total_hours(list.total_hours()),
+            TimeListResult::Grouped(grouped) => Meta {
+                total_count: Some(grouped.matched_count),
+                ..Default::default()
+            }
+            .with_total_hours(grouped.total_hours),
+            TimeListResult::Weekly(grid) => Meta::default().with_total_hours(grid.total_hours),
         }
     }
 }
@@ -290,13 +721,45 @@ impl MarkdownOutput for TimeListResult {
         match self {
             TimeListResult::List(list) => list.to_markdown(meta),
             TimeListResult::Grouped(grouped) => grouped.to_markdown(meta),
+            TimeListResult::Weekly(grid) => grid.to_markdown(meta),
+        }
+    }
+}
+
+impl CsvOutput for TimeListResult {
+    fn csv_header(&self) -> Vec<String> {
+        match self {
+            TimeListResult::List(list) => list.csv_header(),
+            TimeListResult::Grouped(grouped) => grouped.csv_header(),
+            TimeListResult::Weekly(grid) => grid.csv_header(),
+        }
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        match self {
+            TimeListResult::List(list) => list.csv_rows(),
+            TimeListResult::Grouped(grouped) => grouped.csv_rows(),
+            TimeListResult::Weekly(grid) => grid.csv_rows(),
         }
     }
 }
 
 /// Execute time get command.
-pub async fn get(client: &RedmineClient, args: &TimeGetArgs) -> Result<TimeEntry> {
-    client.get_time_entry(args.id).await
+pub async fn get(
+    client: &RedmineClient,
+    args: &TimeGetArgs,
+    server_url: &str,
+) -> Result<TimeEntry> {
+    let mut entry = client.get_time_entry(args.id).await?;
+    if args.enrich {
+        enrich_issue_subjects(client, std::slice::from_mut(&mut entry), 1).await?;
+    }
+    entry.url = Some(format!(
+        "{}/time_entries/{}/edit",
+        server_url.trim_end_matches('/'),
+        entry.id
+    ));
+    Ok(entry)
 }
 
 /// Execute time update command.
@@ -304,12 +767,12 @@ pub async fn update(
     client: &RedmineClient,
     paths: &ConfigPaths,
     args: &TimeUpdateArgs,
-) -> Result<TimeEntryUpdated> {
+) -> Result<WriteOutcome<TimeEntryUpdated>> {
+    let spent_on = parse_date_arg("--spent-on", args.spent_on.as_deref())?;
     // Resolve activity if provided
     let activity_id = if let Some(activity) = &args.activity {
         let (activities, _) = get_activities(client, paths, false).await?;
-        let cache = ActivityCache::new(activities.time_entry_activities);
-        Some(resolve_activity(&cache, activity)?)
+        Some(activity_id(client, &activities, activity)?)
     } else {
         None
     };
@@ -317,18 +780,545 @@ pub async fn update(
     let update = UpdateTimeEntry {
         hours: args.hours,
         activity_id,
-        spent_on: args.spent_on.clone(),
-        comments: args.comment.clone(),
+        spent_on,
+        // `None` leaves the comment alone; an empty string clears it
+        comments: if args.clear_comment {
+            Some(String::new())
+        } else {
+            args.comment.clone()
+        },
     };
 
     let updated = client.update_time_entry(args.id, update).await?;
-    Ok(TimeEntryUpdated {
-        time_entry: updated,
-    })
+    Ok(updated.map(|time_entry| TimeEntryUpdated { time_entry }))
 }
 
 /// Execute time delete command.
-pub async fn delete(client: &RedmineClient, args: &TimeDeleteArgs) -> Result<TimeEntryDeleted> {
-    client.delete_time_entry(args.id).await?;
-    Ok(TimeEntryDeleted { id: args.id })
+pub async fn delete(
+    client: &RedmineClient,
+    args: &TimeDeleteArgs,
+) -> Result<WriteOutcome<TimeEntryDeleted>> {
+    let deleted = client.delete_time_entry(args.id).await?;
+    Ok(deleted.map(|()| TimeEntryDeleted { id: args.id }))
+}
+
+/// One entry of a `time import` file.
+#[derive(Debug, Clone, PartialEq)]
+struct ImportRecord {
+    issue: Option<u32>,
+    project: Option<String>,
+    hours: f64,
+    activity: Option<String>,
+    spent_on: Option<String>,
+    comment: Option<String>,
+}
+
+/// Hint shown for malformed `time import` rows.
+const IMPORT_HINT: &str =
+    "Each entry needs `hours` and an `issue` or `project`; `activity`, `spent_on` and `comment` are optional";
+
+/// Build an import record from its named fields; empty values count as missing.
+fn import_record(fields: BTreeMap<String, String>) -> std::result::Result<ImportRecord, String> {
+    let mut record = ImportRecord {
+        issue: None,
+        project: None,
+        hours: 0.0,
+        activity: None,
+        spent_on: None,
+        comment: None,
+    };
+    let mut hours = None;
+    for (key, value) in fields {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            continue;
+        }
+        match key.as_str() {
+            "issue" | "issue_id" => {
+                record.issue = Some(
+                    value
+                        .trim_start_matches('#')
+                        .parse()
+                        .map_err(|_| format!("invalid issue '{}'", value))?,
+                )
+            }
+            "project" | "project_id" => record.project = Some(value),
+            "hours" => {
+                hours = Some(parse_hours(&value).map_err(|_| format!("invalid hours '{}'", value))?)
+            }
+            "activity" => record.activity = Some(value),
+            "spent_on" => {
+                record.spent_on =
+                    Some(parse_date(&value).map_err(|_| format!("invalid spent_on '{}'", value))?)
+            }
+            "comment" | "comments" => record.comment = Some(value),
+            other => return Err(format!("unknown field '{}'", other)),
+        }
+    }
+
+    record.hours = hours.ok_or("missing hours")?;
+    if record.hours <= 0.0 {
+        return Err("hours must be positive".to_string());
+    }
+    match (&record.issue, &record.project) {
+        (None, None) => Err("missing issue or project".to_string()),
+        (Some(_), Some(_)) => Err("give either issue or project, not both".to_string()),
+        _ => Ok(record),
+    }
+}
+
+/// Split CSV text into rows of fields; quoted fields may hold commas, line breaks and `""`.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            '\r' if !quoted => {}
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows.retain(|r| r.iter().any(|f| !f.trim().is_empty()));
+    rows
+}
+
+/// Read the records of a `time import` file, CSV for a `.csv` extension and JSON otherwise.
+fn read_import_file(path: &Path) -> Result<Vec<ImportRecord>> {
+    let text = std::fs::read_to_string(path).map_err(|e| {
+        AppError::Io(std::io::Error::new(
+            e.kind(),
+            format!("Cannot read {}: {}", path.display(), e),
+        ))
+    })?;
+    let is_csv = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+
+    let rows: Vec<BTreeMap<String, String>> = if is_csv {
+        let mut lines = parse_csv(&text).into_iter();
+        let header: Vec<String> = lines
+            .next()
+            .unwrap_or_default()
+            .iter()
+            .map(|h| h.trim().to_lowercase())
+            .collect();
+        lines
+            .enumerate()
+            .map(|(i, fields)| {
+                if fields.len() != header.len() {
+                    return Err(AppError::validation_with_hint(
+                        format!(
+                            "Row {}: expected {} fields, found {}",
+                            i + 1,
+                            header.len(),
+                            fields.len()
+                        ),
+                        "Quote fields that contain commas, e.g. \"Review, part 2\"",
+                    ));
+                }
+                Ok(header.iter().cloned().zip(fields).collect())
+            })
+            .collect::<Result<_>>()?
+    } else {
+        let values: Vec<BTreeMap<String, serde_json::Value>> = serde_json::from_str(&text)
+            .map_err(|e| {
+                AppError::validation_with_hint(
+                    format!("Invalid JSON in {}: {}", path.display(), e),
+                    "Use a JSON array of objects, or a .csv file with a header row",
+                )
+            })?;
+        values
+            .into_iter()
+            .map(|fields| {
+                fields
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let value = match value {
+                            serde_json::Value::String(s) => s,
+                            serde_json::Value::Null => String::new(),
+                            other => other.to_string(),
+                        };
+                        (key.to_lowercase(), value)
+                    })
+                    .collect()
+            })
+            .collect()
+    };
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, fields)| {
+            import_record(fields).map_err(|e| {
+                AppError::validation_with_hint(format!("Row {}: {}", i + 1, e), IMPORT_HINT)
+            })
+        })
+        .collect()
+}
+
+/// Prefix a validation error with the import row it came from.
+fn in_row(row: usize, error: AppError) -> AppError {
+    match error {
+        AppError::Validation { message, hint } => AppError::Validation {
+            message: format!("Row {}: {}", row, message),
+            hint,
+        },
+        other => other,
+    }
+}
+
+/// Outcome of one imported row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BulkRowStatus {
+    Created,
+    Failed,
+    /// Described but not sent (`--dry-run`).
+    Planned,
+}
+
+/// One row of a bulk import.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkRow {
+    pub row: usize,
+    pub status: BulkRowStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_entry_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub spent_on: String,
+    pub hours: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The request `--dry-run` stopped before sending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<DryRunRequest>,
+}
+
+/// Result of time import command.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkResult {
+    pub file: String,
+    pub created: usize,
+    pub failed: usize,
+    pub rows: Vec<BulkRow>,
+}
+
+impl BulkResult {
+    /// Summary of the failed rows, when any row failed.
+    pub fn failure_summary(&self) -> Option<String> {
+        let failed: Vec<String> = self
+            .rows
+            .iter()
+            .filter_map(|r| {
+                r.error
+                    .as_ref()
+                    .map(|error| format!("Row {}: {}", r.row, error))
+            })
+            .collect();
+        (!failed.is_empty()).then(|| {
+            format!(
+                "{} of {} time entries failed to import: {}",
+                failed.len(),
+                self.rows.len(),
+                failed.join("; ")
+            )
+        })
+    }
+}
+
+impl MarkdownOutput for BulkResult {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = format!("## Time Import: {}\n\n", self.file);
+        let rows = self
+            .rows
+            .iter()
+            .map(|r| {
+                vec![
+                    r.row.to_string(),
+                    match r.status {
+                        BulkRowStatus::Created => "created".to_string(),
+                        BulkRowStatus::Failed => "**failed**".to_string(),
+                        BulkRowStatus::Planned => "planned".to_string(),
+                    },
+                    r.time_entry_id
+                        .map(|id| id.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    match (&r.issue, &r.project) {
+                        (Some(issue), _) => format!("#{}", issue),
+                        (None, Some(project)) => format!("project {}", project),
+                        (None, None) => "-".to_string(),
+                    },
+                    r.spent_on.clone(),
+                    format!("{:.2}", r.hours),
+                    r.error.clone().unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+        output.push_str(&markdown_table(
+            &[
+                "Row",
+                "Status",
+                "Entry",
+                "Issue/Project",
+                "Date",
+                "Hours",
+                "Error",
+            ],
+            rows,
+        ));
+        if self.rows.iter().any(|r| r.status == BulkRowStatus::Planned) {
+            output.push_str(&format!(
+                "\n**{} planned**\n\n*No request was sent*\n",
+                self.rows.len()
+            ));
+        } else {
+            output.push_str(&format!(
+                "\n**{} created, {} failed**\n",
+                self.created, self.failed
+            ));
+        }
+        output
+    }
+}
+
+/// Execute time import command.
+///
+/// Every row is checked and resolved before the first entry is created, so a
+/// typo does not leave a half-imported file; server-side failures are
+/// reported per row.
+pub async fn import(
+    client: &RedmineClient,
+    paths: &ConfigPaths,
+    settings: &Settings,
+    args: &TimeImportArgs,
+) -> Result<BulkResult> {
+    let records = read_import_file(&args.file)?;
+    if records.is_empty() {
+        return Err(AppError::validation_with_hint(
+            format!("No time entries in {}", args.file.display()),
+            IMPORT_HINT,
+        ));
+    }
+
+    let (activities, _) = get_activities(client, paths, false).await?;
+    let today = Local::now().format("%Y-%m-%d").to_string();
+
+    let mut entries = Vec::new();
+    for (i, record) in records.into_iter().enumerate() {
+        let row = i + 1;
+        let project_id = match &record.project {
+            Some(project) if client.is_dry_run() && project.parse::<u32>().is_err() => {
+                Some(ProjectWriteId::Identifier(project.clone()))
+            }
+            Some(project) => Some(ProjectWriteId::Id(
+                resolve_project_id_cached(client, paths, project)
+                    .await
+                    .map_err(|e| in_row(row, e))?,
+            )),
+            None => None,
+        };
+        let project = project_id.as_ref().and_then(ProjectWriteId::id);
+        let activity_id = match &record.activity {
+            Some(activity) => activity_id(client, &activities, activity),
+            None => {
+                match project_default_activity(client, settings, record.issue, project).await? {
+                    Some(activity) => activity_id(client, &activities, &activity),
+                    None => default_activity_id(client, &activities),
+                }
+            }
+        }
+        .map_err(|e| in_row(row, e))?;
+        let entry = NewTimeEntry {
+            issue_id: record.issue,
+            project_id,
+            hours: record.hours,
+            activity_id,
+            spent_on: Some(record.spent_on.clone().unwrap_or_else(|| today.clone())),
+            comments: record.comment.clone(),
+            user_id: None,
+        };
+        entries.push((row, record, entry));
+    }
+
+    let mut rows = Vec::new();
+    for (row, record, entry) in entries {
+        let spent_on = entry.spent_on.clone().unwrap_or_default();
+        let (status, time_entry_id, error, request) = match client.create_time_entry(entry).await {
+            Ok(WriteOutcome::Sent(created)) => {
+                (BulkRowStatus::Created, Some(created.id), None, None)
+            }
+            Ok(WriteOutcome::Planned(request)) => {
+                (BulkRowStatus::Planned, None, None, Some(request))
+            }
+            Err(e) => (BulkRowStatus::Failed, None, Some(e.to_string()), None),
+        };
+        rows.push(BulkRow {
+            row,
+            status,
+            time_entry_id,
+            issue: record.issue,
+            project: record.project,
+            spent_on,
+            hours: record.hours,
+            error,
+            request,
+        });
+    }
+
+    let count = |status| rows.iter().filter(|r| r.status == status).count();
+    let (created, failed) = (count(BulkRowStatus::Created), count(BulkRowStatus::Failed));
+    Ok(BulkResult {
+        file: args.file.display().to_string(),
+        created,
+        failed,
+        rows,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activities() -> Vec<Activity> {
+        serde_json::from_value(serde_json::json!([
+            {"id": 1, "name": "Development", "is_default": true},
+            {"id": 2, "name": "Design"},
+            {"id": 3, "name": "Testing"}
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_activity_by_id_or_name() {
+        let activities = activities();
+        assert_eq!(find_activity(&activities, "2").unwrap().name, "Design");
+        assert_eq!(find_activity(&activities, "testing").unwrap().id, 3);
+        assert!(find_activity(&activities, "Support").is_none());
+    }
+
+    fn entry(id: u32, hours: f64) -> TimeEntry {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "hours": hours,
+            "spent_on": "2024-01-15",
+            "activity": {"id": 1, "name": "Development"},
+            "user": {"id": 1, "name": "Test User"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_filter_by_hours() {
+        let list = TimeEntryList {
+            time_entries: vec![entry(1, 0.5), entry(2, 8.0), entry(3, 24.0)],
+            total_count: Some(30),
+            offset: Some(0),
+            limit: Some(25),
+        };
+
+        let filtered = filter_by_hours(list.clone(), Some(8.0), None);
+        let ids: Vec<u32> = filtered.time_entries.iter().map(|t| t.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(filtered.total_count, Some(2));
+
+        let filtered = filter_by_hours(list, Some(1.0), Some(10.0));
+        assert_eq!(filtered.time_entries.len(), 1);
+        assert_eq!(filtered.time_entries[0].id, 2);
+    }
+
+    #[test]
+    fn test_grid_week() {
+        let (from, to) = grid_week(Some("2024-01-15"), None).unwrap();
+        assert_eq!(to - from, chrono::Duration::days(6));
+        assert_eq!(to.to_string(), "2024-01-21");
+
+        assert!(grid_week(Some("2024-01-15"), Some("2024-01-19")).is_ok());
+        assert!(grid_week(Some("2024-01-15"), Some("2024-01-22")).is_err());
+        assert!(grid_week(Some("2024-01-15"), Some("2024-01-14")).is_err());
+        assert!(grid_week(None, Some("2024-01-21")).is_err());
+    }
+
+    #[test]
+    fn test_parse_user() {
+        assert_eq!(parse_user("me").unwrap(), None);
+        assert_eq!(parse_user("ME").unwrap(), None);
+        assert_eq!(parse_user("42").unwrap(), Some(42));
+        assert!(parse_user("alice").is_err());
+    }
+
+    #[test]
+    fn test_round_hours() {
+        assert_eq!(round_hours(1.1, 15).unwrap(), 1.0);
+        assert_eq!(round_hours(1.2, 15).unwrap(), 1.25);
+        assert_eq!(round_hours(0.05, 15).unwrap(), 0.25);
+        assert_eq!(round_hours(2.5, 30).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn test_round_hours_rejects_non_divisor() {
+        assert!(round_hours(1.0, 7).is_err());
+        assert!(round_hours(1.0, 0).is_err());
+        assert!(round_hours(1.0, 90).is_err());
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("issue,hours,comment\r\n123,1.5,\"Review, \"\"part\"\" 2\"\n\n");
+        assert_eq!(
+            rows,
+            vec![
+                vec!["issue", "hours", "comment"],
+                vec!["123", "1.5", "Review, \"part\" 2"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_import_record() {
+        let fields = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let record = import_record(fields(&[
+            ("issue", "#123"),
+            ("hours", "2"),
+            ("project", ""),
+        ]))
+        .unwrap();
+        assert_eq!(record.issue, Some(123));
+        assert_eq!(record.project, None);
+        assert_eq!(record.hours, 2.0);
+
+        assert!(import_record(fields(&[("issue", "123")])).is_err());
+        assert!(import_record(fields(&[("hours", "1")])).is_err());
+        assert!(
+            import_record(fields(&[("issue", "1"), ("project", "x"), ("hours", "1")])).is_err()
+        );
+        assert!(import_record(fields(&[("issue", "1"), ("hours", "-1")])).is_err());
+        assert!(import_record(fields(&[("issue", "1"), ("hours", "1"), ("hour", "1")])).is_err());
+    }
 }
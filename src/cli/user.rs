@@ -3,11 +3,14 @@
 use clap::{Args, Subcommand, ValueEnum};
 use serde::Serialize;
 
-use crate::client::RedmineClient;
+use super::{limit_parser, page_size_parser};
+use crate::client::{endpoints::MAX_PAGE_SIZE, RedmineClient};
 use crate::error::Result;
+use crate::models::{Capabilities, CurrentUser};
 use crate::output::{
+    csv::{csv_columns, csv_opt},
     markdown::{markdown_table, pagination_hint},
-    MarkdownOutput, Meta,
+    CsvOutput, MarkdownOutput, Meta,
 };
 
 #[derive(Debug, Subcommand)]
@@ -15,7 +18,14 @@ pub enum UserCommand {
     /// List users.
     List(UserListArgs),
     /// Get current user info (alias for 'rdm me').
-    Me,
+    Me(MeArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct MeArgs {
+    /// Probe what the API key may do and report it as `capabilities`.
+    #[arg(long)]
+    pub probe: bool,
 }
 
 #[derive(Debug, Args)]
@@ -23,8 +33,20 @@ pub struct UserListArgs {
     /// Filter by status (active, registered, locked).
     #[arg(long, value_enum)]
     pub status: Option<UserStatus>,
-    /// Maximum number of results.
-    #[arg(long, default_value = "25")]
+    /// Fetch every page instead of one.
+    #[arg(long)]
+    pub all: bool,
+    /// Users fetched per request with --all (1-100).
+    #[arg(
+        long,
+        alias = "limit-per-request",
+        value_name = "N",
+        default_value_t = MAX_PAGE_SIZE,
+        value_parser = page_size_parser()
+    )]
+    pub page_size: u32,
+    /// Maximum number of results (at most 100; 0 uses the server default).
+    #[arg(long, default_value = "25", value_parser = limit_parser())]
     pub limit: u32,
     /// Offset for pagination.
     #[arg(long, default_value = "0")]
@@ -99,6 +121,39 @@ pub struct UserList {
     pub limit: Option<u32>,
 }
 
+impl CsvOutput for UserList {
+    fn csv_header(&self) -> Vec<String> {
+        csv_columns(&[
+            "id",
+            "login",
+            "firstname",
+            "lastname",
+            "mail",
+            "status",
+            "created_on",
+            "last_login_on",
+        ])
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.users
+            .iter()
+            .map(|u| {
+                vec![
+                    u.id.to_string(),
+                    u.login.clone(),
+                    u.firstname.clone(),
+                    u.lastname.clone(),
+                    csv_opt(u.mail.as_ref()),
+                    csv_opt(u.status.map(|_| u.status_display())),
+                    csv_opt(u.created_on.as_ref()),
+                    csv_opt(u.last_login_on.as_ref()),
+                ]
+            })
+            .collect()
+    }
+}
+
 impl MarkdownOutput for UserList {
     fn to_markdown(&self, meta: &Meta) -> String {
         let mut output = String::new();
@@ -148,11 +203,23 @@ impl MarkdownOutput for UserList {
 
 /// Execute user list command.
 pub async fn list(client: &RedmineClient, args: &UserListArgs) -> Result<UserList> {
-    client
-        .list_users(
-            args.status.map(|s| s.as_api_value()),
-            args.limit,
-            args.offset,
-        )
-        .await
+    let status = args.status.map(|s| s.as_api_value());
+    if args.all {
+        return client.list_all_users(status, args.page_size).await;
+    }
+    client.list_users(status, args.limit, args.offset).await
+}
+
+/// Execute me command, optionally probing the key's capabilities.
+pub async fn me(client: &RedmineClient, args: &MeArgs) -> Result<CurrentUser> {
+    let mut user = client.me().await?;
+    if args.probe {
+        let can_list_users = client.can_list_users().await?;
+        user.capabilities = Some(Capabilities {
+            // Listing users requires admin rights, so it stands in when `admin` is omitted
+            is_admin: user.admin.unwrap_or(can_list_users),
+            can_list_users,
+        });
+    }
+    Ok(user)
 }
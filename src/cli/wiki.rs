@@ -0,0 +1,85 @@
+//! Wiki commands.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+
+use crate::client::{dry_run::WriteOutcome, RedmineClient};
+use crate::error::{AppError, Result};
+use crate::models::{UpdateWikiPage, WikiPageList, WikiPageUpdated};
+
+#[derive(Debug, Subcommand)]
+pub enum WikiCommand {
+    /// List a project's wiki pages.
+    List(WikiListArgs),
+    /// Create or replace a wiki page's content.
+    Update(WikiUpdateArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct WikiListArgs {
+    /// Project ID or identifier.
+    #[arg(long)]
+    pub project: String,
+}
+
+#[derive(Debug, Args)]
+pub struct WikiUpdateArgs {
+    /// Project ID or identifier.
+    #[arg(long)]
+    pub project: String,
+    /// Page title (created if it does not exist).
+    #[arg(long)]
+    pub page: String,
+    /// File with the new page text, or `-` to read from stdin.
+    #[arg(long)]
+    pub file: PathBuf,
+    /// Comment for the new version.
+    #[arg(long)]
+    pub comment: Option<String>,
+}
+
+/// Execute wiki list command.
+pub async fn list(client: &RedmineClient, args: &WikiListArgs) -> Result<WikiPageList> {
+    client.list_wiki_pages(&args.project).await
+}
+
+/// Execute wiki update command.
+pub async fn update(
+    client: &RedmineClient,
+    args: &WikiUpdateArgs,
+) -> Result<WriteOutcome<WikiPageUpdated>> {
+    let text = read_text(&args.file)?;
+    let page = UpdateWikiPage {
+        text,
+        comments: args.comment.clone(),
+    };
+    let created = match client
+        .update_wiki_page(&args.project, &args.page, page)
+        .await?
+    {
+        WriteOutcome::Sent(created) => created,
+        WriteOutcome::Planned(request) => return Ok(WriteOutcome::Planned(request)),
+    };
+
+    // The update response has no body; fetch the page for its new version.
+    let page = client.get_wiki_page(&args.project, &args.page).await?;
+    Ok(WriteOutcome::Sent(WikiPageUpdated {
+        project: args.project.clone(),
+        title: page.title,
+        created,
+        version: page.version,
+    }))
+}
+
+/// Read page text from a file, or from stdin for `-`.
+fn read_text(path: &Path) -> Result<String> {
+    let result = if path.as_os_str() == "-" {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text).map(|_| text)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    result.map_err(|e| AppError::validation(format!("Cannot read {}: {}", path.display(), e)))
+}
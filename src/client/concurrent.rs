@@ -0,0 +1,22 @@
+//! Bounded-concurrency helpers for fan-out requests.
+
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+
+/// Default number of requests kept in flight for fan-out operations.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Run `f` over `items` with at most `limit` futures in flight.
+///
+/// Results are returned in the same order as `items`.
+pub async fn map_concurrent<T, R, F, Fut>(items: Vec<T>, limit: usize, f: F) -> Vec<R>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future<Output = R>,
+{
+    stream::iter(items)
+        .map(f)
+        .buffered(limit.max(1))
+        .collect()
+        .await
+}
@@ -0,0 +1,71 @@
+//! Write requests described instead of sent under `--dry-run`.
+
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::output::{MarkdownOutput, Meta};
+
+/// A request that `--dry-run` stopped before sending.
+#[derive(Debug, Clone, Serialize)]
+pub struct DryRunRequest {
+    pub method: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+}
+
+impl DryRunRequest {
+    /// Describe a request, serializing its body if any.
+    pub fn new(
+        method: &str,
+        path: impl Into<String>,
+        body: Option<&impl Serialize>,
+    ) -> Result<Self> {
+        Ok(Self {
+            method: method.to_string(),
+            path: path.into(),
+            payload: body.map(serde_json::to_value).transpose()?,
+        })
+    }
+}
+
+/// Outcome of a write: the server's response, or the request `--dry-run`
+/// described instead of sending.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+#[must_use]
+pub enum WriteOutcome<T> {
+    Sent(T),
+    Planned(DryRunRequest),
+}
+
+impl<T> WriteOutcome<T> {
+    /// Map the response, leaving a planned request as it is.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> WriteOutcome<U> {
+        match self {
+            WriteOutcome::Sent(value) => WriteOutcome::Sent(f(value)),
+            WriteOutcome::Planned(request) => WriteOutcome::Planned(request),
+        }
+    }
+}
+
+impl<T: MarkdownOutput> MarkdownOutput for WriteOutcome<T> {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        match self {
+            WriteOutcome::Sent(value) => value.to_markdown(meta),
+            WriteOutcome::Planned(request) => request.to_markdown(meta),
+        }
+    }
+}
+
+impl MarkdownOutput for DryRunRequest {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = format!("## Dry Run: {} {}\n\n", self.method, self.path);
+        if let Some(payload) = &self.payload {
+            let body = serde_json::to_string_pretty(payload).unwrap_or_default();
+            output.push_str(&format!("```json\n{}\n```\n\n", body));
+        }
+        output.push_str("*No request was sent*\n");
+        output
+    }
+}
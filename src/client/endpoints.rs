@@ -3,28 +3,95 @@
 use backoff::{future::retry, ExponentialBackoff};
 use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 use tracing::{debug, warn};
 
+use super::dry_run::{DryRunRequest, WriteOutcome};
+use crate::cache::{CachedResponse, Feature, ResponseCache, ServerCapabilities};
 use crate::config::Config;
 use crate::error::{AppError, Result};
 use crate::models::*;
 use urlencoding;
 
+/// Largest page size Redmine accepts for list endpoints.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Request timeout used when neither `--timeout` nor the profile sets one.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Longest accepted request timeout.
+pub const MAX_TIMEOUT_SECS: u64 = 600;
+
+/// How long transient failures are retried when neither `--retry-max-elapsed`
+/// nor the environment sets it.
+pub const DEFAULT_RETRY_MAX_ELAPSED_SECS: u64 = 30;
+
+/// Longest accepted retry time.
+pub const MAX_RETRY_MAX_ELAPSED_SECS: u64 = 3600;
+
+/// Safety ceiling on the number of items a `--all` listing collects.
+pub const MAX_ALL_RESULTS: u32 = 10_000;
+
+/// Cap an `--all` listing at [`MAX_ALL_RESULTS`], warning on stderr when
+/// items were left out.
+fn cap_all_results<T>(items: &mut Vec<T>, total: u32, resource: &str) {
+    if items.len() as u32 > MAX_ALL_RESULTS {
+        items.truncate(MAX_ALL_RESULTS as usize);
+    }
+    if total > MAX_ALL_RESULTS {
+        eprintln!(
+            "warning: --all stopped at {} of {} {}; narrow the filters to fetch the rest",
+            MAX_ALL_RESULTS, total, resource
+        );
+    }
+}
+
+/// `limit`/`offset` query parameters; a zero limit is omitted so the server
+/// default applies.
+fn page_params(limit: u32, offset: u32) -> Vec<String> {
+    let mut params = Vec::new();
+    if limit > 0 {
+        params.push(format!("limit={}", limit));
+    }
+    params.push(format!("offset={}", offset));
+    params
+}
+
 /// Redmine API client.
 pub struct RedmineClient {
     client: Client,
     base_url: String,
     api_key: String,
     dry_run: bool,
+    language: Option<String>,
+    response_cache: Option<ResponseCache>,
+    capabilities: ServerCapabilities,
+    strict: bool,
+    current_user_id: OnceLock<u32>,
+    max_retries: Option<u32>,
+    retry_max_elapsed: Duration,
 }
 
 impl RedmineClient {
     /// Create a new Redmine client.
     pub fn new(config: &Config, dry_run: bool) -> Result<Self> {
+        let timeout = config.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS);
+        if timeout == 0 || timeout > MAX_TIMEOUT_SECS {
+            return Err(AppError::config_with_hint(
+                format!("Invalid timeout_secs: {}", timeout),
+                format!(
+                    "Use 1-{} seconds in the profile, or pass --timeout",
+                    MAX_TIMEOUT_SECS
+                ),
+            ));
+        }
+
         let client = Client::builder()
             .use_rustls_tls()
-            .timeout(Duration::from_secs(30))
+            .timeout(Duration::from_secs(timeout))
             .connect_timeout(Duration::from_secs(10))
             .user_agent(format!("rdm/{}", env!("CARGO_PKG_VERSION")))
             .gzip(true)
@@ -38,26 +105,94 @@ impl RedmineClient {
             base_url,
             api_key: config.api_key.clone(),
             dry_run,
+            language: None,
+            response_cache: None,
+            capabilities: ServerCapabilities::default(),
+            strict: false,
+            current_user_id: OnceLock::new(),
+            max_retries: config.max_retries,
+            retry_max_elapsed: Duration::from_secs(
+                config
+                    .retry_max_elapsed_secs
+                    .unwrap_or(DEFAULT_RETRY_MAX_ELAPSED_SECS),
+            ),
         })
     }
 
+    /// Request localized names (statuses, priorities, ...) via `Accept-Language`.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        self.language = language.filter(|l| !l.trim().is_empty());
+        self
+    }
+
+    /// Gate version-dependent features on what is known about the server.
+    pub fn with_capabilities(mut self, capabilities: ServerCapabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Report response fields the models do not capture instead of dropping them silently.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Server URL without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Check if requests are only printed (`--dry-run`), not sent.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Check if the server is known to support `feature` (assumed when unknown).
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.capabilities.supports(feature)
+    }
+
+    /// Cache GET responses and revalidate them with conditional requests.
+    pub fn with_response_cache(mut self, cache: Option<ResponseCache>) -> Self {
+        self.response_cache = cache;
+        self
+    }
+
+    /// Check if caching is enabled, i.e. `--no-cache` was not given.
+    pub fn uses_cache(&self) -> bool {
+        self.response_cache.is_some()
+    }
+
     /// Build a request with authentication.
     fn request(&self, method: Method, path: &str) -> RequestBuilder {
         let url = format!("{}{}", self.base_url, path);
         debug!("Building request: {} {}", method, url);
-        self.client
+        let builder = self
+            .client
             .request(method, &url)
             .header("X-Redmine-API-Key", &self.api_key)
-            .header("Content-Type", "application/json")
+            .header("Content-Type", "application/json");
+        match &self.language {
+            Some(language) => builder.header("Accept-Language", language),
+            None => builder,
+        }
     }
 
     /// Execute a request with retry for transient errors.
     async fn execute(&self, request: RequestBuilder) -> Result<Response> {
         let backoff = ExponentialBackoff {
-            max_elapsed_time: Some(Duration::from_secs(30)),
+            max_elapsed_time: Some(self.retry_max_elapsed),
             ..Default::default()
         };
+        self.execute_with(request, backoff).await
+    }
 
+    /// Execute a request, retrying transient errors until `backoff` gives up.
+    async fn execute_with(
+        &self,
+        request: RequestBuilder,
+        backoff: ExponentialBackoff,
+    ) -> Result<Response> {
         let request = request
             .build()
             .map_err(|e| AppError::network(format!("Failed to build request: {}", e)))?;
@@ -67,12 +202,48 @@ impl RedmineClient {
         let client = self.client.clone();
         let method = request.method().clone();
         let url = request.url().clone();
-        let headers = request.headers().clone();
+        let mut headers = request.headers().clone();
         let body = request
             .body()
             .and_then(|b| b.as_bytes().map(|b| b.to_vec()));
 
-        retry(backoff, || async {
+        let cache = self
+            .response_cache
+            .as_ref()
+            .filter(|_| method == Method::GET)
+            .map(|cache| {
+                let key = ResponseCache::key(&[
+                    url.as_str(),
+                    &self.api_key,
+                    self.language.as_deref().unwrap_or_default(),
+                ]);
+                let cached = cache.load(&key);
+                (cache, key, cached)
+            });
+        if let Some((_, _, Some(cached))) = &cache {
+            if let Some(value) = cached.etag.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(reqwest::header::IF_NONE_MATCH, value);
+            }
+            if let Some(value) = cached.last_modified.as_deref().and_then(|v| v.parse().ok()) {
+                headers.insert(reqwest::header::IF_MODIFIED_SINCE, value);
+            }
+        }
+
+        let attempts = AtomicU32::new(0);
+        let started = Instant::now();
+        let max_elapsed = backoff.max_elapsed_time.unwrap_or(Duration::MAX);
+
+        let result = retry(backoff, || async {
+            let retries = attempts.fetch_add(1, Ordering::Relaxed);
+            // Out of retries (`--max-retries`): report the failure as final
+            let final_attempt = self.max_retries.is_some_and(|max| retries >= max);
+            let transient = |error| {
+                if final_attempt {
+                    backoff::Error::permanent(error)
+                } else {
+                    backoff::Error::transient(error)
+                }
+            };
             let mut req_builder = client.request(method.clone(), url.clone());
             for (key, value) in headers.iter() {
                 req_builder = req_builder.header(key, value);
@@ -84,7 +255,7 @@ impl RedmineClient {
             let response = req_builder.send().await.map_err(|e| {
                 if e.is_timeout() || e.is_connect() {
                     warn!("Transient error, will retry: {}", e);
-                    backoff::Error::transient(AppError::network(format!("Request failed: {}", e)))
+                    transient(AppError::network(format!("Request failed: {}", e)))
                 } else {
                     backoff::Error::permanent(AppError::network(format!("Request failed: {}", e)))
                 }
@@ -101,19 +272,130 @@ impl RedmineClient {
                     | StatusCode::GATEWAY_TIMEOUT
             ) {
                 warn!("Server error {}, will retry", status);
-                return Err(backoff::Error::transient(AppError::api(
+                return Err(transient(AppError::api(
                     format!("Server error: {}", status),
                     Some(status.as_u16()),
                 )));
             }
 
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let error = AppError::api_with_hint(
+                    "Rate limited by the server (429 Too Many Requests)",
+                    Some(status.as_u16()),
+                    "Wait a moment before retrying, or send fewer requests at once (bulk commands take --concurrency)",
+                );
+                return Err(match retry_after(&response) {
+                    // Honour the server's delay, unless it outlasts the retry budget
+                    Some(wait) if !final_attempt && started.elapsed() + wait <= max_elapsed => {
+                        warn!("Rate limited, retrying in {}s", wait.as_secs());
+                        backoff::Error::retry_after(error, wait)
+                    }
+                    Some(_) => backoff::Error::permanent(error),
+                    None => {
+                        warn!("Rate limited, will retry");
+                        transient(error)
+                    }
+                });
+            }
+
             Ok(response)
         })
-        .await
+        .await;
+
+        let attempts = attempts.into_inner();
+        let outcome = if result.is_ok() {
+            "succeeded"
+        } else {
+            "failed"
+        };
+        debug!(
+            "{} {} {} after {} attempt{}, {:.1}s total",
+            method,
+            url,
+            outcome,
+            attempts,
+            if attempts == 1 { "" } else { "s" },
+            started.elapsed().as_secs_f64()
+        );
+
+        match cache {
+            Some((cache, key, cached)) => Self::apply_cache(cache, &key, cached, result?).await,
+            None => result,
+        }
+    }
+
+    /// Serve a cached body on 304 and store fresh responses that carry validators.
+    async fn apply_cache(
+        cache: &ResponseCache,
+        key: &str,
+        cached: Option<CachedResponse>,
+        response: Response,
+    ) -> Result<Response> {
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            return match cached {
+                Some(cached) => {
+                    debug!("Not modified, serving cached response");
+                    Self::rebuild_response(
+                        StatusCode::OK,
+                        cached.content_type.as_deref(),
+                        cached.body.into_bytes(),
+                    )
+                }
+                None => Ok(response),
+            };
+        }
+        if status != StatusCode::OK {
+            return Ok(response);
+        }
+
+        let header = |name: reqwest::header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(reqwest::header::ETAG);
+        let last_modified = header(reqwest::header::LAST_MODIFIED);
+        if etag.is_none() && last_modified.is_none() {
+            return Ok(response);
+        }
+        let content_type = header(reqwest::header::CONTENT_TYPE);
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::network(format!("Failed to read response: {}", e)))?;
+        let entry = CachedResponse::new(etag, last_modified, content_type, body);
+        if let Err(e) = cache.store(key, &entry) {
+            warn!("Failed to cache response: {}", e);
+        }
+        Self::rebuild_response(
+            status,
+            entry.content_type.as_deref(),
+            entry.body.into_bytes(),
+        )
+    }
+
+    /// Turn a buffered body back into a `Response` for `parse_json`.
+    fn rebuild_response(
+        status: StatusCode,
+        content_type: Option<&str>,
+        body: Vec<u8>,
+    ) -> Result<Response> {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(content_type) = content_type {
+            builder = builder.header(reqwest::header::CONTENT_TYPE, content_type);
+        }
+        builder
+            .body(body)
+            .map(Response::from)
+            .map_err(|e| AppError::network(format!("Failed to rebuild response: {}", e)))
     }
 
     /// Parse a JSON response.
-    async fn parse_json<T: DeserializeOwned>(response: Response) -> Result<T> {
+    async fn parse_json<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
         let status = response.status();
         let body = response
             .text()
@@ -144,6 +426,10 @@ impl RedmineClient {
             ));
         }
 
+        if self.strict {
+            return Self::parse_strict(&body);
+        }
+
         serde_json::from_str(&body).map_err(|e| {
             AppError::api(
                 format!("Failed to parse response: {} - body: {}", e, body),
@@ -152,24 +438,83 @@ impl RedmineClient {
         })
     }
 
+    /// Parse a JSON body, reporting where parsing failed and warning on stderr
+    /// about every field the models do not capture (`--strict`).
+    fn parse_strict<T: DeserializeOwned>(body: &str) -> Result<T> {
+        let mut json = serde_json::Deserializer::from_str(body);
+        let mut track = serde_path_to_error::Track::new();
+        let mut unknown = BTreeSet::new();
+        let parsed = serde_ignored::deserialize(
+            serde_path_to_error::Deserializer::new(&mut json, &mut track),
+            |path| {
+                unknown.insert(field_path(&path));
+            },
+        );
+        let parsed: T = parsed.map_err(|e| {
+            AppError::api(
+                format!(
+                    "Failed to parse response at '{}': {} - body: {}",
+                    track.path(),
+                    e,
+                    body
+                ),
+                None,
+            )
+        })?;
+
+        for field in &unknown {
+            eprintln!("strict: unknown field in response: {}", field);
+        }
+        Ok(parsed)
+    }
+
+    /// The server's Redmine version, when known.
+    pub fn server_version(&self) -> Option<String> {
+        self.capabilities.version.map(|v| v.to_string())
+    }
+
     /// Ping the server to check connectivity.
+    ///
+    /// Makes a single attempt so the reported latency and failures reflect
+    /// the server as it is, rather than after retries.
     pub async fn ping(&self) -> Result<PingResponse> {
         if self.dry_run {
             return Ok(PingResponse {
                 status: "dry-run".to_string(),
                 url: self.base_url.clone(),
+                latency_ms: 0,
+                server_version: self.server_version(),
+                server_date: None,
             });
         }
 
+        let single_attempt = ExponentialBackoff {
+            max_elapsed_time: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        let started = Instant::now();
         let response = self
-            .execute(self.request(Method::GET, "/users/current.json"))
+            .execute_with(
+                self.request(Method::GET, "/users/current.json"),
+                single_attempt,
+            )
             .await?;
+        let latency_ms = started.elapsed().as_millis() as u64;
         let status = response.status();
+        let server_date = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| chrono::DateTime::parse_from_rfc2822(v).ok())
+            .map(|d| d.with_timezone(&chrono::Utc));
 
         if status.is_success() {
             Ok(PingResponse {
                 status: "ok".to_string(),
                 url: self.base_url.clone(),
+                latency_ms,
+                server_version: self.server_version(),
+                server_date,
             })
         } else if status == StatusCode::UNAUTHORIZED {
             Err(AppError::auth_with_hint(
@@ -195,10 +540,38 @@ impl RedmineClient {
         let response = self
             .execute(self.request(Method::GET, "/users/current.json"))
             .await?;
-        let wrapper: CurrentUserResponse = Self::parse_json(response).await?;
+        let wrapper: CurrentUserResponse = self.parse_json(response).await?;
         Ok(wrapper.user)
     }
 
+    /// Get the current user's ID, looked up once per client.
+    pub async fn current_user_id(&self) -> Result<u32> {
+        if let Some(id) = self.current_user_id.get() {
+            return Ok(*id);
+        }
+        let id = self.me().await?.id;
+        Ok(*self.current_user_id.get_or_init(|| id))
+    }
+
+    /// Check whether the API key may list users (admin-only in Redmine).
+    pub async fn can_list_users(&self) -> Result<bool> {
+        if self.dry_run {
+            return Err(AppError::validation(
+                "Cannot use --dry-run with 'me --probe' command",
+            ));
+        }
+
+        let response = self
+            .execute(self.request(Method::GET, "/users.json?limit=1"))
+            .await?;
+        if response.status() == StatusCode::FORBIDDEN {
+            return Ok(false);
+        }
+        self.parse_json::<serde_json::Value>(response)
+            .await
+            .map(|_| true)
+    }
+
     /// List users with optional status filter.
     pub async fn list_users(
         &self,
@@ -215,7 +588,7 @@ impl RedmineClient {
             });
         }
 
-        let mut params = vec![format!("limit={}", limit), format!("offset={}", offset)];
+        let mut params = page_params(limit, offset);
 
         if let Some(s) = status {
             params.push(format!("status={}", s));
@@ -223,7 +596,36 @@ impl RedmineClient {
 
         let path = format!("/users.json?{}", params.join("&"));
         let response = self.execute(self.request(Method::GET, &path)).await?;
-        Self::parse_json(response).await
+        self.parse_json(response).await
+    }
+
+    /// List every user by following pagination, `page_size` per request.
+    pub async fn list_all_users(
+        &self,
+        status: Option<u32>,
+        page_size: u32,
+    ) -> Result<crate::cli::user::UserList> {
+        let mut users = Vec::new();
+        let total = loop {
+            let page = self
+                .list_users(status, page_size, users.len() as u32)
+                .await?;
+            let total = page.total_count.unwrap_or(0);
+            let fetched = page.users.len();
+            users.extend(page.users);
+            if fetched == 0 || users.len() as u32 >= total.min(MAX_ALL_RESULTS) {
+                break total;
+            }
+        };
+        cap_all_results(&mut users, total, "users");
+
+        let limit = users.len() as u32;
+        Ok(crate::cli::user::UserList {
+            users,
+            total_count: Some(total.max(limit)),
+            offset: Some(0),
+            limit: Some(limit),
+        })
     }
 
     // === Projects ===
@@ -236,12 +638,37 @@ impl RedmineClient {
                 total_count: Some(0),
                 offset: Some(offset),
                 limit: Some(limit),
+                name_filter: None,
             });
         }
 
-        let path = format!("/projects.json?limit={}&offset={}", limit, offset);
+        let path = format!("/projects.json?{}", page_params(limit, offset).join("&"));
         let response = self.execute(self.request(Method::GET, &path)).await?;
-        Self::parse_json(response).await
+        self.parse_json(response).await
+    }
+
+    /// List every project by following pagination, `page_size` per request.
+    pub async fn list_all_projects(&self, page_size: u32) -> Result<ProjectList> {
+        let mut projects = Vec::new();
+        let total = loop {
+            let page = self.list_projects(page_size, projects.len() as u32).await?;
+            let total = page.total_count.unwrap_or(0);
+            let fetched = page.projects.len();
+            projects.extend(page.projects);
+            if fetched == 0 || projects.len() as u32 >= total.min(MAX_ALL_RESULTS) {
+                break total;
+            }
+        };
+        cap_all_results(&mut projects, total, "projects");
+
+        let limit = projects.len() as u32;
+        Ok(ProjectList {
+            projects,
+            total_count: Some(total.max(limit)),
+            offset: Some(0),
+            limit: Some(limit),
+            name_filter: None,
+        })
     }
 
     /// Get a project by ID or identifier.
@@ -264,10 +691,122 @@ impl RedmineClient {
             ));
         }
 
-        let wrapper: ProjectResponse = Self::parse_json(response).await?;
+        let wrapper: ProjectResponse = self.parse_json(response).await?;
         Ok(wrapper.project)
     }
 
+    /// Archive, unarchive, close, or reopen a project.
+    pub async fn set_project_state(
+        &self,
+        id_or_identifier: &str,
+        action: ProjectStateAction,
+    ) -> Result<WriteOutcome<()>> {
+        self.capabilities.require(Feature::ProjectState)?;
+
+        let path = format!(
+            "/projects/{}/{}.json",
+            urlencoding::encode(id_or_identifier),
+            action.path_segment()
+        );
+        if self.dry_run {
+            return DryRunRequest::new("PUT", path, None::<&()>).map(WriteOutcome::Planned);
+        }
+
+        let response = self.execute(self.request(Method::PUT, &path)).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            // Older Redmine versions lack these routes; tell that apart from a missing project.
+            if self.get_project(id_or_identifier).await.is_ok() {
+                return Err(AppError::api_with_hint(
+                    format!(
+                        "This Redmine server does not support `{}` for projects",
+                        action.path_segment()
+                    ),
+                    Some(404),
+                    format!(
+                        "Project archive/close endpoints require Redmine {} or later; use the web UI instead.",
+                        Feature::ProjectState.min_version()
+                    ),
+                ));
+            }
+            return Err(AppError::not_found_with_hint(
+                "Project",
+                id_or_identifier,
+                "Use `rdm project list` to see available projects.",
+            ));
+        }
+
+        if status == StatusCode::FORBIDDEN {
+            return Err(AppError::auth_with_hint(
+                "Access forbidden - check your permissions",
+                "Archiving requires an administrator; closing and reopening require project manager rights.",
+            ));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::api(
+                format!("Failed to {} project: {}", action.path_segment(), body),
+                Some(status.as_u16()),
+            ));
+        }
+
+        Ok(WriteOutcome::Sent(()))
+    }
+
+    /// List the versions available to a project, including shared ones.
+    pub async fn list_versions(&self, project: &str) -> Result<VersionList> {
+        if self.dry_run {
+            return Ok(VersionList {
+                versions: vec![],
+                total_count: Some(0),
+            });
+        }
+
+        let path = format!("/projects/{}/versions.json", urlencoding::encode(project));
+        let response = self.execute(self.request(Method::GET, &path)).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Project",
+                project,
+                "Use `rdm project list` to see available projects.",
+            ));
+        }
+
+        self.parse_json(response).await
+    }
+
+    /// Create a version (milestone) in a project.
+    pub async fn create_version(
+        &self,
+        project: &str,
+        version: NewVersion,
+    ) -> Result<WriteOutcome<Version>> {
+        let path = format!("/projects/{}/versions.json", urlencoding::encode(project));
+        if self.dry_run {
+            return DryRunRequest::new("POST", path, Some(&NewVersionRequest { version }))
+                .map(WriteOutcome::Planned);
+        }
+
+        let request = self
+            .request(Method::POST, &path)
+            .json(&NewVersionRequest { version });
+        let response = self.execute(request).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Project",
+                project,
+                "Use `rdm project list` to see available projects.",
+            ));
+        }
+
+        let wrapper: VersionResponse = self.parse_json(response).await?;
+        Ok(WriteOutcome::Sent(wrapper.version))
+    }
+
     // === Issues ===
 
     /// List issues with optional filters.
@@ -278,13 +817,12 @@ impl RedmineClient {
                 total_count: Some(0),
                 offset: Some(filters.offset),
                 limit: Some(filters.limit),
+                cf_columns: Vec::new(),
+                legend: false,
             });
         }
 
-        let mut params = vec![
-            format!("limit={}", filters.limit),
-            format!("offset={}", filters.offset),
-        ];
+        let mut params = page_params(filters.limit, filters.offset);
 
         if let Some(project) = &filters.project {
             params.push(format!("project_id={}", project));
@@ -295,15 +833,36 @@ impl RedmineClient {
         if let Some(assigned_to) = &filters.assigned_to {
             params.push(format!("assigned_to_id={}", assigned_to));
         }
+        if filters.unassigned {
+            params.push("assigned_to_id=!*".to_string());
+        }
+        if let Some(not_assigned_to) = &filters.not_assigned_to {
+            params.push(format!("assigned_to_id=!{}", not_assigned_to));
+        }
         if let Some(author) = &filters.author {
             params.push(format!("author_id={}", author));
         }
+        if let Some(watcher) = &filters.watched_by {
+            params.push(format!("watcher_id={}", watcher));
+        }
         if let Some(tracker) = &filters.tracker {
             params.push(format!("tracker_id={}", tracker));
         }
         if let Some(subject) = &filters.subject {
             params.push(format!("subject={}", urlencoding::encode(subject)));
         }
+        if let Some(closed_on) = &filters.closed_on {
+            params.push(format!("closed_on={}", urlencoding::encode(closed_on)));
+        }
+        if let Some(updated_on) = &filters.updated_on {
+            params.push(format!("updated_on={}", urlencoding::encode(updated_on)));
+        }
+        if let Some(due_date) = &filters.due_date {
+            params.push(format!("due_date={}", urlencoding::encode(due_date)));
+        }
+        if let Some(sort) = &filters.sort {
+            params.push(format!("sort={}", sort));
+        }
         // Add custom field filters
         for (cf_id, cf_value) in &filters.custom_fields {
             params.push(format!("cf_{}={}", cf_id, urlencoding::encode(cf_value)));
@@ -311,7 +870,46 @@ impl RedmineClient {
 
         let path = format!("/issues.json?{}", params.join("&"));
         let response = self.execute(self.request(Method::GET, &path)).await?;
-        Self::parse_json(response).await
+        self.parse_json(response).await
+    }
+
+    /// List every issue matching the filters by following pagination.
+    pub async fn list_all_issues(
+        &self,
+        filters: IssueFilters,
+        page_size: u32,
+    ) -> Result<IssueList> {
+        let mut pages = self.issue_pages(filters, page_size);
+        let mut issues = Vec::new();
+        while let Some(page) = pages.next_page().await? {
+            issues.extend(page);
+            if issues.len() as u32 >= MAX_ALL_RESULTS {
+                break;
+            }
+        }
+        cap_all_results(&mut issues, pages.total, "issues");
+
+        let limit = issues.len() as u32;
+        Ok(IssueList {
+            issues,
+            total_count: Some(pages.total.max(limit)),
+            offset: Some(0),
+            limit: Some(limit),
+            cf_columns: Vec::new(),
+            legend: false,
+        })
+    }
+
+    /// Page through every issue matching the filters, `page_size` per request.
+    pub fn issue_pages(&self, mut filters: IssueFilters, page_size: u32) -> IssuePages<'_> {
+        filters.limit = page_size;
+        filters.offset = 0;
+        IssuePages {
+            client: self,
+            filters,
+            total: 0,
+            done: false,
+        }
     }
 
     /// Get an issue by ID.
@@ -334,39 +932,115 @@ impl RedmineClient {
             ));
         }
 
-        let wrapper: IssueResponse = Self::parse_json(response).await?;
+        let wrapper: IssueResponse = self.parse_json(response).await?;
         Ok(wrapper.issue)
     }
 
+    /// List the relations of an issue.
+    pub async fn list_issue_relations(&self, id: u32) -> Result<Vec<IssueRelation>> {
+        if self.dry_run {
+            return Err(AppError::validation(
+                "Cannot use --dry-run with relation commands",
+            ));
+        }
+
+        let path = format!("/issues/{}/relations.json", id);
+        let response = self.execute(self.request(Method::GET, &path)).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Issue",
+                id.to_string(),
+                "Use `rdm issue list` to find available issues.",
+            ));
+        }
+
+        let wrapper: IssueRelationList = self.parse_json(response).await?;
+        Ok(wrapper.relations)
+    }
+
+    /// Relate an issue to another one.
+    pub async fn create_issue_relation(
+        &self,
+        id: u32,
+        relation: NewIssueRelation,
+    ) -> Result<WriteOutcome<IssueRelation>> {
+        let path = format!("/issues/{}/relations.json", id);
+        let body = NewIssueRelationRequest { relation };
+        if self.dry_run {
+            return DryRunRequest::new("POST", path, Some(&body)).map(WriteOutcome::Planned);
+        }
+
+        let response = self
+            .execute(self.request(Method::POST, &path).json(&body))
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Issue",
+                id.to_string(),
+                "Use `rdm issue list` to find available issues.",
+            ));
+        }
+
+        let wrapper: IssueRelationResponse = self.parse_json(response).await?;
+        Ok(WriteOutcome::Sent(wrapper.relation))
+    }
+
+    /// Delete an issue relation.
+    pub async fn delete_issue_relation(&self, id: u32) -> Result<WriteOutcome<()>> {
+        let path = format!("/relations/{}.json", id);
+        if self.dry_run {
+            return DryRunRequest::new("DELETE", path, None::<&()>).map(WriteOutcome::Planned);
+        }
+
+        let response = self.execute(self.request(Method::DELETE, &path)).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Relation",
+                id.to_string(),
+                "Use `rdm issue relations list --id <issue>` to find relation IDs.",
+            ));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::api(
+                format!("Failed to delete relation: {}", body),
+                Some(status.as_u16()),
+            ));
+        }
+
+        Ok(WriteOutcome::Sent(()))
+    }
+
     /// Create a new issue.
-    pub async fn create_issue(&self, issue: NewIssue) -> Result<Issue> {
+    pub async fn create_issue(&self, issue: NewIssue) -> Result<WriteOutcome<Issue>> {
         if self.dry_run {
-            let body = serde_json::to_string_pretty(&NewIssueRequest { issue })
-                .map_err(|e| AppError::validation(format!("Failed to serialize: {}", e)))?;
-            println!("DRY RUN: POST /issues.json");
-            println!("{}", body);
-            return Err(AppError::validation("Dry run - no request sent"));
+            return DryRunRequest::new("POST", "/issues.json", Some(&NewIssueRequest { issue }))
+                .map(WriteOutcome::Planned);
         }
 
         let request = self
             .request(Method::POST, "/issues.json")
             .json(&NewIssueRequest { issue });
         let response = self.execute(request).await?;
-        let wrapper: IssueResponse = Self::parse_json(response).await?;
-        Ok(wrapper.issue)
+        let wrapper: IssueResponse = self.parse_json(response).await?;
+        Ok(WriteOutcome::Sent(wrapper.issue))
     }
 
     /// Update an issue.
-    pub async fn update_issue(&self, id: u32, update: UpdateIssue) -> Result<()> {
+    pub async fn update_issue(&self, id: u32, update: UpdateIssue) -> Result<WriteOutcome<()>> {
         if self.dry_run {
-            let body = serde_json::to_string_pretty(&UpdateIssueRequest { issue: update })
-                .map_err(|e| AppError::validation(format!("Failed to serialize: {}", e)))?;
-            println!("DRY RUN: PUT /issues/{}.json", id);
-            println!("{}", body);
-            return Err(AppError::validation("Dry run - no request sent"));
+            let body = UpdateIssueRequest { issue: update };
+            return DryRunRequest::new("PUT", format!("/issues/{}.json", id), Some(&body))
+                .map(WriteOutcome::Planned);
         }
 
         let path = format!("/issues/{}.json", id);
+        let sent_lock_version = update.lock_version;
         let request = self
             .request(Method::PUT, &path)
             .json(&UpdateIssueRequest { issue: update });
@@ -383,55 +1057,169 @@ impl RedmineClient {
 
         if !status.is_success() {
             let body = response.text().await.unwrap_or_default();
+            // Redmine reports a stale lock_version as 409, or as 422 on older
+            // versions. The 422 message is localized, so compare the issue's
+            // current lock_version instead of matching its text.
+            let stale = match sent_lock_version {
+                Some(sent) if status == StatusCode::UNPROCESSABLE_ENTITY => self
+                    .get_issue(id)
+                    .await
+                    .is_ok_and(|issue| issue.lock_version != Some(sent)),
+                _ => false,
+            };
+            if status == StatusCode::CONFLICT || stale {
+                return Err(AppError::conflict_with_hint(
+                    format!("Issue #{} was modified by someone else", id),
+                    format!(
+                        "Re-read it with `rdm issue get --id {}` and retry with the new lock_version",
+                        id
+                    ),
+                ));
+            }
             return Err(AppError::api(
                 format!("Failed to update issue: {}", body),
                 Some(status.as_u16()),
             ));
         }
 
-        Ok(())
+        Ok(WriteOutcome::Sent(()))
     }
 
-    /// Search issues using Redmine's search endpoint.
-    /// Returns matching issues by fetching full issue data for each search result.
-    pub async fn search_issues(
+    /// Add a user as a watcher of an issue.
+    pub async fn add_watcher(&self, issue_id: u32, user_id: u32) -> Result<WriteOutcome<()>> {
+        let body = serde_json::json!({ "user_id": user_id });
+        if self.dry_run {
+            let path = format!("/issues/{}/watchers.json", issue_id);
+            return DryRunRequest::new("POST", path, Some(&body)).map(WriteOutcome::Planned);
+        }
+
+        let path = format!("/issues/{}/watchers.json", issue_id);
+        let request = self.request(Method::POST, &path).json(&body);
+        let response = self.execute(request).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Issue",
+                issue_id.to_string(),
+                "Use `rdm issue list` to find available issues.",
+            ));
+        }
+
+        if status == StatusCode::FORBIDDEN {
+            return Err(AppError::auth(
+                "Access forbidden - you may not have permission to add watchers",
+            ));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::api(
+                format!("Failed to add watcher: {}", body),
+                Some(status.as_u16()),
+            ));
+        }
+
+        Ok(WriteOutcome::Sent(()))
+    }
+
+    /// Remove a user from the watchers of an issue.
+    pub async fn remove_watcher(&self, issue_id: u32, user_id: u32) -> Result<WriteOutcome<()>> {
+        let path = format!("/issues/{}/watchers/{}.json", issue_id, user_id);
+        if self.dry_run {
+            return DryRunRequest::new("DELETE", path, None::<&()>).map(WriteOutcome::Planned);
+        }
+
+        let response = self.execute(self.request(Method::DELETE, &path)).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Watcher",
+                format!("user {} on issue #{}", user_id, issue_id),
+                "Check the issue ID and that the user is watching it.",
+            ));
+        }
+
+        if status == StatusCode::FORBIDDEN {
+            return Err(AppError::auth(
+                "Access forbidden - you may not have permission to remove watchers",
+            ));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::api(
+                format!("Failed to remove watcher: {}", body),
+                Some(status.as_u16()),
+            ));
+        }
+
+        Ok(WriteOutcome::Sent(()))
+    }
+
+    /// Search using Redmine's search endpoint.
+    /// `scopes` are result type flags such as `issues` or `wiki_pages`; empty searches all types.
+    pub async fn search(
         &self,
         query: &str,
         project: Option<&str>,
+        scopes: &[&str],
         limit: u32,
         offset: u32,
-    ) -> Result<IssueList> {
+    ) -> Result<SearchResults> {
         if self.dry_run {
-            return Ok(IssueList {
-                issues: vec![],
+            return Ok(SearchResults {
+                results: vec![],
                 total_count: Some(0),
                 offset: Some(offset),
                 limit: Some(limit),
             });
         }
 
-        let params = [
-            format!("q={}", urlencoding::encode(query)),
-            "issues=1".to_string(),
-            format!("limit={}", limit),
-            format!("offset={}", offset),
-        ];
+        let mut params = vec![format!("q={}", urlencoding::encode(query))];
+        for scope in scopes {
+            params.push(format!("{}=1", scope));
+        }
+        params.extend(page_params(limit, offset));
 
-        if let Some(project_id) = project {
+        let path = match project {
             // Scope search to a specific project
-            let path = format!(
+            Some(project_id) => format!(
                 "/projects/{}/search.json?{}",
                 urlencoding::encode(project_id),
                 params.join("&")
-            );
-            let response = self.execute(self.request(Method::GET, &path)).await?;
-            let search_results: SearchResults = Self::parse_json(response).await?;
-            return self.fetch_issues_from_search(search_results).await;
-        }
+            ),
+            None => format!("/search.json?{}", params.join("&")),
+        };
 
-        let path = format!("/search.json?{}", params.join("&"));
         let response = self.execute(self.request(Method::GET, &path)).await?;
-        let search_results: SearchResults = Self::parse_json(response).await?;
+        self.parse_json(response).await
+    }
+
+    /// Search issues using Redmine's search endpoint.
+    /// Returns matching issues by fetching full issue data for each search result.
+    pub async fn search_issues(
+        &self,
+        query: &str,
+        project: Option<&str>,
+        limit: u32,
+        offset: u32,
+    ) -> Result<IssueList> {
+        if self.dry_run {
+            return Ok(IssueList {
+                issues: vec![],
+                total_count: Some(0),
+                offset: Some(offset),
+                limit: Some(limit),
+                cf_columns: Vec::new(),
+                legend: false,
+            });
+        }
+
+        let search_results = self
+            .search(query, project, &["issues"], limit, offset)
+            .await?;
         self.fetch_issues_from_search(search_results).await
     }
 
@@ -451,6 +1239,8 @@ impl RedmineClient {
                 total_count: Some(0),
                 offset: search_results.offset,
                 limit: search_results.limit,
+                cf_columns: Vec::new(),
+                legend: false,
             });
         }
 
@@ -471,9 +1261,160 @@ impl RedmineClient {
             total_count: search_results.total_count,
             offset: search_results.offset,
             limit: search_results.limit,
+            cf_columns: Vec::new(),
+            legend: false,
         })
     }
 
+    /// List issue trackers.
+    pub async fn list_trackers(&self) -> Result<TrackerList> {
+        if self.dry_run {
+            return Ok(TrackerList { trackers: vec![] });
+        }
+
+        let response = self
+            .execute(self.request(Method::GET, "/trackers.json"))
+            .await?;
+        self.parse_json(response).await
+    }
+
+    /// List issue statuses.
+    pub async fn list_issue_statuses(&self) -> Result<StatusList> {
+        if self.dry_run {
+            return Ok(StatusList {
+                issue_statuses: vec![],
+            });
+        }
+
+        let response = self
+            .execute(self.request(Method::GET, "/issue_statuses.json"))
+            .await?;
+        self.parse_json(response).await
+    }
+
+    /// List issue priorities.
+    pub async fn list_priorities(&self) -> Result<PriorityList> {
+        if self.dry_run {
+            return Ok(PriorityList {
+                issue_priorities: vec![],
+            });
+        }
+
+        let response = self
+            .execute(self.request(Method::GET, "/enumerations/issue_priorities.json"))
+            .await?;
+        self.parse_json(response).await
+    }
+
+    // === Wiki ===
+
+    /// List all wiki pages of a project.
+    pub async fn list_wiki_pages(&self, project: &str) -> Result<WikiPageList> {
+        if self.dry_run {
+            return Ok(WikiPageList { wiki_pages: vec![] });
+        }
+
+        let path = format!("/projects/{}/wiki/index.json", urlencoding::encode(project));
+        let response = self.execute(self.request(Method::GET, &path)).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            // Redmine answers 404 both for unknown projects and for projects without a wiki.
+            self.get_project(project).await?;
+            return Err(AppError::not_found_with_hint(
+                "Wiki",
+                project,
+                "This project has no wiki; enable the Wiki module in the project settings.",
+            ));
+        }
+
+        if status == StatusCode::FORBIDDEN {
+            return Err(AppError::auth_with_hint(
+                "Access to this project's wiki is forbidden",
+                "The Wiki module may be disabled for the project, or your role cannot view wiki pages.",
+            ));
+        }
+
+        self.parse_json(response).await
+    }
+
+    /// Get a wiki page with its content.
+    pub async fn get_wiki_page(&self, project: &str, title: &str) -> Result<WikiPage> {
+        if self.dry_run {
+            return Err(AppError::validation(
+                "Cannot use --dry-run with 'get' command",
+            ));
+        }
+
+        let path = Self::wiki_page_path(project, title);
+        let response = self.execute(self.request(Method::GET, &path)).await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found_with_hint(
+                "Wiki page",
+                title,
+                "Use `rdm wiki list --project <id>` to see available pages.",
+            ));
+        }
+
+        let wrapper: WikiPageResponse = self.parse_json(response).await?;
+        Ok(wrapper.wiki_page)
+    }
+
+    /// Create or update a wiki page; returns `true` when the page was created.
+    pub async fn update_wiki_page(
+        &self,
+        project: &str,
+        title: &str,
+        page: UpdateWikiPage,
+    ) -> Result<WriteOutcome<bool>> {
+        let path = Self::wiki_page_path(project, title);
+        if self.dry_run {
+            let body = UpdateWikiPageRequest { wiki_page: page };
+            return DryRunRequest::new("PUT", path, Some(&body)).map(WriteOutcome::Planned);
+        }
+
+        let request = self
+            .request(Method::PUT, &path)
+            .json(&UpdateWikiPageRequest { wiki_page: page });
+        let response = self.execute(request).await?;
+        let status = response.status();
+
+        if status == StatusCode::NOT_FOUND {
+            self.get_project(project).await?;
+            return Err(AppError::not_found_with_hint(
+                "Wiki",
+                project,
+                "This project has no wiki; enable the Wiki module in the project settings.",
+            ));
+        }
+
+        if status == StatusCode::FORBIDDEN {
+            return Err(AppError::auth_with_hint(
+                "Editing this project's wiki is forbidden",
+                "Your role needs the \"Edit wiki pages\" permission, and the Wiki module must be enabled.",
+            ));
+        }
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::api(
+                format!("Failed to update wiki page: {}", body),
+                Some(status.as_u16()),
+            ));
+        }
+
+        Ok(WriteOutcome::Sent(status == StatusCode::CREATED))
+    }
+
+    fn wiki_page_path(project: &str, title: &str) -> String {
+        format!(
+            "/projects/{}/wiki/{}.json",
+            urlencoding::encode(project),
+            urlencoding::encode(title)
+        )
+    }
+
     // === Attachments ===
 
     /// Get attachment metadata by ID.
@@ -496,7 +1437,7 @@ impl RedmineClient {
             ));
         }
 
-        let wrapper: AttachmentResponse = Self::parse_json(response).await?;
+        let wrapper: AttachmentResponse = self.parse_json(response).await?;
         Ok(wrapper.attachment)
     }
 
@@ -523,7 +1464,7 @@ impl RedmineClient {
             )
             .await?;
 
-        let wrapper: UploadResponse = Self::parse_json(response).await?;
+        let wrapper: UploadResponse = self.parse_json(response).await?;
         Ok(wrapper.upload.token)
     }
 
@@ -564,7 +1505,7 @@ impl RedmineClient {
         let response = self
             .execute(self.request(Method::GET, "/enumerations/time_entry_activities.json"))
             .await?;
-        Self::parse_json(response).await
+        self.parse_json(response).await
     }
 
     /// List time entries with optional filters.
@@ -578,10 +1519,7 @@ impl RedmineClient {
             });
         }
 
-        let mut params = vec![
-            format!("limit={}", filters.limit),
-            format!("offset={}", filters.offset),
-        ];
+        let mut params = page_params(filters.limit, filters.offset);
 
         if let Some(project) = &filters.project {
             params.push(format!("project_id={}", project));
@@ -605,7 +1543,40 @@ impl RedmineClient {
 
         let path = format!("/time_entries.json?{}", params.join("&"));
         let response = self.execute(self.request(Method::GET, &path)).await?;
-        Self::parse_json(response).await
+        self.parse_json(response).await
+    }
+
+    /// List every time entry matching the filters by following pagination.
+    pub async fn list_all_time_entries(
+        &self,
+        filters: TimeEntryFilters,
+        page_size: u32,
+    ) -> Result<TimeEntryList> {
+        let mut time_entries = Vec::new();
+        let total = loop {
+            let page = self
+                .list_time_entries(TimeEntryFilters {
+                    limit: page_size,
+                    offset: time_entries.len() as u32,
+                    ..filters.clone()
+                })
+                .await?;
+            let total = page.total_count.unwrap_or(0);
+            let fetched = page.time_entries.len();
+            time_entries.extend(page.time_entries);
+            if fetched == 0 || time_entries.len() as u32 >= total.min(MAX_ALL_RESULTS) {
+                break total;
+            }
+        };
+        cap_all_results(&mut time_entries, total, "time entries");
+
+        let limit = time_entries.len() as u32;
+        Ok(TimeEntryList {
+            time_entries,
+            total_count: Some(total.max(limit)),
+            offset: Some(0),
+            limit: Some(limit),
+        })
     }
 
     /// Get a time entry by ID.
@@ -628,36 +1599,45 @@ impl RedmineClient {
             ));
         }
 
-        let wrapper: TimeEntryResponse = Self::parse_json(response).await?;
+        let wrapper: TimeEntryResponse = self.parse_json(response).await?;
         Ok(wrapper.time_entry)
     }
 
     /// Create a new time entry.
-    pub async fn create_time_entry(&self, entry: NewTimeEntry) -> Result<TimeEntry> {
+    pub async fn create_time_entry(&self, entry: NewTimeEntry) -> Result<WriteOutcome<TimeEntry>> {
         if self.dry_run {
-            let body = serde_json::to_string_pretty(&NewTimeEntryRequest { time_entry: entry })
-                .map_err(|e| AppError::validation(format!("Failed to serialize: {}", e)))?;
-            println!("DRY RUN: POST /time_entries.json");
-            println!("{}", body);
-            return Err(AppError::validation("Dry run - no request sent"));
+            let body = NewTimeEntryRequest { time_entry: entry };
+            return DryRunRequest::new("POST", "/time_entries.json", Some(&body))
+                .map(WriteOutcome::Planned);
         }
 
+        let for_other_user = entry.user_id.is_some();
         let request = self
             .request(Method::POST, "/time_entries.json")
             .json(&NewTimeEntryRequest { time_entry: entry });
         let response = self.execute(request).await?;
-        let wrapper: TimeEntryResponse = Self::parse_json(response).await?;
-        Ok(wrapper.time_entry)
+
+        if for_other_user && response.status() == StatusCode::FORBIDDEN {
+            return Err(AppError::auth_with_hint(
+                "Logging time for other users requires admin rights or the \"Log spent time for other users\" permission",
+                "Drop --user (or use `--user me`) to log the time for yourself.",
+            ));
+        }
+
+        let wrapper: TimeEntryResponse = self.parse_json(response).await?;
+        Ok(WriteOutcome::Sent(wrapper.time_entry))
     }
 
     /// Update a time entry.
-    pub async fn update_time_entry(&self, id: u32, update: UpdateTimeEntry) -> Result<TimeEntry> {
+    pub async fn update_time_entry(
+        &self,
+        id: u32,
+        update: UpdateTimeEntry,
+    ) -> Result<WriteOutcome<TimeEntry>> {
         if self.dry_run {
-            let body = serde_json::to_string_pretty(&UpdateTimeEntryRequest { time_entry: update })
-                .map_err(|e| AppError::validation(format!("Failed to serialize: {}", e)))?;
-            println!("DRY RUN: PUT /time_entries/{}.json", id);
-            println!("{}", body);
-            return Err(AppError::validation("Dry run - no request sent"));
+            let body = UpdateTimeEntryRequest { time_entry: update };
+            let path = format!("/time_entries/{}.json", id);
+            return DryRunRequest::new("PUT", path, Some(&body)).map(WriteOutcome::Planned);
         }
 
         let path = format!("/time_entries/{}.json", id);
@@ -676,14 +1656,14 @@ impl RedmineClient {
         }
 
         // Fetch the updated entry to return it
-        self.get_time_entry(id).await
+        self.get_time_entry(id).await.map(WriteOutcome::Sent)
     }
 
     /// Delete a time entry.
-    pub async fn delete_time_entry(&self, id: u32) -> Result<()> {
+    pub async fn delete_time_entry(&self, id: u32) -> Result<WriteOutcome<()>> {
         if self.dry_run {
-            println!("DRY RUN: DELETE /time_entries/{}.json", id);
-            return Err(AppError::validation("Dry run - no request sent"));
+            let path = format!("/time_entries/{}.json", id);
+            return DryRunRequest::new("DELETE", path, None::<&()>).map(WriteOutcome::Planned);
         }
 
         let path = format!("/time_entries/{}.json", id);
@@ -706,8 +1686,41 @@ impl RedmineClient {
             ));
         }
 
-        Ok(())
+        Ok(WriteOutcome::Sent(()))
+    }
+}
+
+/// Render an ignored-field path as `issues[].tracker.color`, one entry per field
+/// rather than per array element.
+fn field_path(path: &serde_ignored::Path) -> String {
+    use serde_ignored::Path;
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, .. } => format!("{}[]", field_path(parent)),
+        Path::Map { parent, key } => match field_path(parent) {
+            prefix if prefix.is_empty() => key.clone(),
+            prefix => format!("{}.{}", prefix, key),
+        },
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => field_path(parent),
+    }
+}
+
+/// Delay requested by a `Retry-After` header, in seconds or as an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
     }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
 }
 
 /// Ping response.
@@ -715,14 +1728,50 @@ impl RedmineClient {
 pub struct PingResponse {
     pub status: String,
     pub url: String,
+    /// Round-trip time of the ping request in milliseconds.
+    pub latency_ms: u64,
+    /// Redmine version declared with `--api-version`; the API does not report it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_version: Option<String>,
+    /// Server clock from the `Date` response header, used by `rdm doctor`.
+    #[serde(skip)]
+    pub server_date: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl crate::output::MarkdownOutput for PingResponse {
     fn to_markdown(&self, _meta: &crate::output::Meta) -> String {
-        format!(
-            "## Connection Status\n\n- **Status**: {}\n- **URL**: {}\n",
-            self.status, self.url
-        )
+        let mut output = format!(
+            "## Connection Status\n\n- **Status**: {}\n- **URL**: {}\n- **Latency**: {} ms\n",
+            self.status, self.url, self.latency_ms
+        );
+        if let Some(version) = &self.server_version {
+            output.push_str(&format!("- **Server Version**: Redmine {}\n", version));
+        }
+        output
+    }
+}
+
+/// Pager over all issues matching a filter (see [`RedmineClient::issue_pages`]).
+pub struct IssuePages<'a> {
+    client: &'a RedmineClient,
+    filters: IssueFilters,
+    /// Server-reported total of the last fetched page.
+    total: u32,
+    done: bool,
+}
+
+impl IssuePages<'_> {
+    /// Fetch the next page, or `None` once every issue has been returned.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<Issue>>> {
+        if self.done {
+            return Ok(None);
+        }
+        let page = self.client.list_issues(self.filters.clone()).await?;
+        let fetched = page.issues.len() as u32;
+        self.total = page.total_count.unwrap_or(0);
+        self.filters.offset += fetched;
+        self.done = fetched == 0 || self.filters.offset >= self.total;
+        Ok(Some(page.issues))
     }
 }
 
@@ -732,9 +1781,23 @@ pub struct IssueFilters {
     pub project: Option<String>,
     pub status: Option<String>,
     pub assigned_to: Option<String>,
+    /// Only issues with no assignee (`assigned_to_id=!*`).
+    pub unassigned: bool,
+    /// Exclude issues assigned to this user ID or "me" (`assigned_to_id=!<id>`).
+    pub not_assigned_to: Option<String>,
     pub author: Option<String>,
+    /// Watcher user ID or "me".
+    pub watched_by: Option<String>,
     pub tracker: Option<String>,
     pub subject: Option<String>,
+    /// Closed-on filter value, e.g. ">=2024-01-01".
+    pub closed_on: Option<String>,
+    /// Updated-on filter value, e.g. ">=2024-01-01".
+    pub updated_on: Option<String>,
+    /// Due-date filter value, e.g. "><2024-01-01|2024-01-04".
+    pub due_date: Option<String>,
+    /// Sort order, e.g. "due_date" or "updated_on:desc".
+    pub sort: Option<String>,
     pub custom_fields: Vec<(u32, String)>,
     pub limit: u32,
     pub offset: u32,
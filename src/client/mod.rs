@@ -1,5 +1,7 @@
 //! Redmine API client module.
 
+pub mod concurrent;
+pub mod dry_run;
 pub mod endpoints;
 
 pub use endpoints::RedmineClient;
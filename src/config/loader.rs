@@ -1,9 +1,11 @@
 //! Configuration loader with precedence: CLI > Env > Config File.
 
 use directories::ProjectDirs;
+use std::io::{BufRead, IsTerminal, Write};
 use std::path::PathBuf;
 
-use super::profile::ProfileStore;
+use super::profile::{Profile, ProfileStore};
+use super::settings::Settings;
 use crate::error::{AppError, Result};
 
 /// Cross-platform configuration paths.
@@ -19,13 +21,37 @@ pub struct ConfigPaths {
 
 impl ConfigPaths {
     /// Get the configuration paths for this application.
+    ///
+    /// `REDMINE_CONFIG` overrides the config file path and `REDMINE_CACHE_DIR`
+    /// the cache directory; otherwise OS-specific defaults are used.
     pub fn new() -> Result<Self> {
-        let proj_dirs = ProjectDirs::from("", "", "redmine-agent-cli")
-            .ok_or_else(|| AppError::config("Could not determine config directory"))?;
-
-        let config_dir = proj_dirs.config_dir().to_path_buf();
-        let config_file = config_dir.join("config.toml");
-        let cache_dir = proj_dirs.cache_dir().to_path_buf();
+        let config_override = env_path("REDMINE_CONFIG");
+        let cache_override = env_path("REDMINE_CACHE_DIR");
+
+        let proj_dirs = || {
+            ProjectDirs::from("", "", "redmine-agent-cli")
+                .ok_or_else(|| AppError::config("Could not determine config directory"))
+        };
+
+        let (config_dir, config_file) = match config_override {
+            Some(file) => {
+                let dir = file
+                    .parent()
+                    .map(|p| p.to_path_buf())
+                    .unwrap_or_else(|| PathBuf::from("."));
+                (dir, file)
+            }
+            None => {
+                let dir = proj_dirs()?.config_dir().to_path_buf();
+                let file = dir.join("config.toml");
+                (dir, file)
+            }
+        };
+
+        let cache_dir = match cache_override {
+            Some(dir) => dir,
+            None => proj_dirs()?.cache_dir().to_path_buf(),
+        };
 
         Ok(Self {
             config_dir,
@@ -35,6 +61,15 @@ impl ConfigPaths {
     }
 }
 
+/// Read a non-empty path from an environment variable, made absolute so a
+/// bare file name still has a parent directory.
+fn env_path(name: &str) -> Option<PathBuf> {
+    std::env::var_os(name)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .map(|path| std::path::absolute(&path).unwrap_or(path))
+}
+
 impl Default for ConfigPaths {
     fn default() -> Self {
         Self::new().unwrap_or_else(|_| Self {
@@ -54,9 +89,30 @@ pub struct Config {
     pub api_key: String,
     /// Profile name if loaded from config.
     pub profile_name: Option<String>,
+    /// Default project of the profile, if any.
+    pub default_project: Option<String>,
+    /// Request timeout in seconds; `None` uses the client default.
+    pub timeout_secs: Option<u64>,
+    /// Retries of a transient failure; `None` retries until the retry time runs out.
+    pub max_retries: Option<u32>,
+    /// Seconds to keep retrying transient failures; `None` uses the client default.
+    pub retry_max_elapsed_secs: Option<u64>,
 }
 
 impl Config {
+    /// Build the config of a saved profile.
+    pub fn from_profile(profile: &Profile) -> Self {
+        Self {
+            url: profile.url.clone(),
+            api_key: profile.api_key.clone(),
+            profile_name: Some(profile.name.clone()),
+            default_project: profile.default_project.clone(),
+            timeout_secs: profile.timeout_secs,
+            max_retries: None,
+            retry_max_elapsed_secs: None,
+        }
+    }
+
     /// Redact the API key for display.
     pub fn redacted_api_key(&self) -> String {
         if self.api_key.len() <= 8 {
@@ -83,6 +139,10 @@ pub fn load_config(
             url: url.to_string(),
             api_key: api_key.to_string(),
             profile_name: None,
+            default_project: None,
+            timeout_secs: None,
+            max_retries: None,
+            retry_max_elapsed_secs: None,
         });
     }
 
@@ -99,6 +159,10 @@ pub fn load_config(
             url,
             api_key,
             profile_name: None,
+            default_project: None,
+            timeout_secs: None,
+            max_retries: None,
+            retry_max_elapsed_secs: None,
         });
     }
 
@@ -106,13 +170,10 @@ pub fn load_config(
     let store = ProfileStore::load(&paths.config_file)?;
     if let Some(profile) = store.get_active() {
         // Allow CLI/env to override individual values from profile
-        let url = url.unwrap_or_else(|| profile.url.clone());
-        let api_key = api_key.unwrap_or_else(|| profile.api_key.clone());
-        return Ok(Config {
-            url,
-            api_key,
-            profile_name: Some(profile.name.clone()),
-        });
+        let mut config = Config::from_profile(profile);
+        config.url = url.unwrap_or(config.url);
+        config.api_key = api_key.unwrap_or(config.api_key);
+        return Ok(config);
     }
 
     // 4. Error - no credentials found
@@ -122,6 +183,118 @@ pub fn load_config(
     ))
 }
 
+/// Load the `[settings]` table from the config file.
+///
+/// When the credentials came from flags or the environment rather than a
+/// profile, a missing or unreadable config file just means default settings.
+pub fn load_settings(paths: &ConfigPaths, config: &Config) -> Result<Settings> {
+    match ProfileStore::load(&paths.config_file) {
+        Ok(store) => Ok(store.settings),
+        Err(_) if config.profile_name.is_none() => Ok(Settings::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Profile created by the first-run prompt.
+const PROMPT_PROFILE_NAME: &str = "default";
+
+/// Check if a person can answer prompts (stdin and stderr are terminals).
+pub fn can_prompt() -> bool {
+    std::io::stdin().is_terminal() && std::io::stderr().is_terminal()
+}
+
+/// Check if no profile has been saved yet, so the first-run prompt applies.
+///
+/// An unreadable config file is not a first run: it is reported instead.
+pub fn is_first_run(paths: &ConfigPaths) -> bool {
+    ProfileStore::load(&paths.config_file).is_ok_and(|store| store.list().is_empty())
+}
+
+/// Ask for the URL and API key on the terminal and save them as a profile.
+///
+/// Values already given via `--url`/`--api-key` (or the environment) are
+/// used as-is instead of being asked for. Returns `None` when the user
+/// declines.
+pub fn prompt_for_profile(
+    cli_url: Option<&str>,
+    cli_api_key: Option<&str>,
+    paths: &ConfigPaths,
+) -> Result<Option<Config>> {
+    let answer = prompt_line("No Redmine credentials configured. Set up a profile now? [Y/n] ")?;
+    if answer.eq_ignore_ascii_case("n") || answer.eq_ignore_ascii_case("no") {
+        return Ok(None);
+    }
+
+    let url = match cli_url
+        .map(str::to_string)
+        .or_else(|| std::env::var("REDMINE_URL").ok())
+    {
+        Some(url) => url,
+        None => prompt_line("Redmine URL: ")?,
+    };
+    let api_key = match cli_api_key
+        .map(str::to_string)
+        .or_else(|| std::env::var("REDMINE_API_KEY").ok())
+    {
+        Some(api_key) => api_key,
+        None => rpassword::prompt_password("API key (My account > API access key): ")?
+            .trim()
+            .to_string(),
+    };
+
+    let config = save_prompted_profile(&url, &api_key, paths)?;
+    eprintln!(
+        "Saved profile '{}' to {}",
+        PROMPT_PROFILE_NAME,
+        paths.config_file.display()
+    );
+    Ok(Some(config))
+}
+
+/// Validate the prompted values and save them as the `default` profile,
+/// refusing to replace a profile that already exists.
+fn save_prompted_profile(url: &str, api_key: &str, paths: &ConfigPaths) -> Result<Config> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(AppError::config_with_hint(
+            format!("Invalid Redmine URL: '{}'", url),
+            "Use the full server address, e.g. https://redmine.example.com",
+        ));
+    }
+    if api_key.is_empty() {
+        return Err(AppError::config("API key is empty"));
+    }
+
+    let mut store = ProfileStore::load(&paths.config_file)?;
+    if store.get(PROMPT_PROFILE_NAME).is_some() {
+        return Err(AppError::config_with_hint(
+            format!("Profile '{}' already exists", PROMPT_PROFILE_NAME),
+            "Use `rdm profile add` with another name, or `rdm profile use` to activate it.",
+        ));
+    }
+    store.add(Profile::new(PROMPT_PROFILE_NAME, url, api_key));
+    store.set_active(PROMPT_PROFILE_NAME)?;
+    store.save(&paths.config_file)?;
+
+    Ok(Config {
+        url: url.to_string(),
+        api_key: api_key.to_string(),
+        profile_name: Some(PROMPT_PROFILE_NAME.to_string()),
+        default_project: None,
+        timeout_secs: None,
+        max_retries: None,
+        retry_max_elapsed_secs: None,
+    })
+}
+
+/// Print `prompt` to stderr and read one trimmed line from stdin.
+fn prompt_line(prompt: &str) -> Result<String> {
+    eprint!("{}", prompt);
+    std::io::stderr().flush()?;
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,6 +398,57 @@ mod tests {
         assert_eq!(config.profile_name, Some("test".to_string()));
     }
 
+    #[test]
+    fn test_config_paths_env_overrides() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        let dir = tempdir().unwrap();
+        let config_file = dir.path().join("tenant").join("rdm.toml");
+        let cache_dir = dir.path().join("rdm-cache");
+
+        std::env::set_var("REDMINE_CONFIG", &config_file);
+        std::env::set_var("REDMINE_CACHE_DIR", &cache_dir);
+
+        let paths = ConfigPaths::new().unwrap();
+
+        std::env::remove_var("REDMINE_CONFIG");
+        std::env::remove_var("REDMINE_CACHE_DIR");
+
+        assert_eq!(paths.config_file, config_file);
+        assert_eq!(paths.config_dir, dir.path().join("tenant"));
+        assert_eq!(paths.cache_dir, cache_dir);
+    }
+
+    #[test]
+    fn test_config_paths_empty_env_uses_defaults() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        std::env::set_var("REDMINE_CONFIG", "");
+        std::env::remove_var("REDMINE_CACHE_DIR");
+
+        let paths = ConfigPaths::new();
+
+        std::env::remove_var("REDMINE_CONFIG");
+
+        let paths = paths.unwrap();
+        assert!(paths.config_file.ends_with("config.toml"));
+        assert!(paths.config_file.is_absolute());
+    }
+
+    #[test]
+    fn test_config_paths_relative_env_override() {
+        let _lock = ENV_MUTEX.lock().unwrap();
+        std::env::set_var("REDMINE_CONFIG", "rdm.toml");
+        std::env::remove_var("REDMINE_CACHE_DIR");
+
+        let paths = ConfigPaths::new();
+
+        std::env::remove_var("REDMINE_CONFIG");
+
+        let cwd = std::env::current_dir().unwrap();
+        let paths = paths.unwrap();
+        assert_eq!(paths.config_file, cwd.join("rdm.toml"));
+        assert_eq!(paths.config_dir, cwd);
+    }
+
     #[test]
     fn test_no_config_error() {
         let _lock = ENV_MUTEX.lock().unwrap();
@@ -238,4 +462,47 @@ mod tests {
         let result = load_config(None, None, &paths);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_first_run_only_without_profiles() {
+        let dir = tempdir().unwrap();
+        let paths = test_paths(dir.path());
+        assert!(is_first_run(&paths));
+
+        let mut store = ProfileStore::default();
+        store.add(Profile::new("work", "https://work.example.com", "work-key"));
+        store.save(&paths.config_file).unwrap();
+        assert!(!is_first_run(&paths));
+
+        std::fs::write(&paths.config_file, "not = [valid").unwrap();
+        assert!(!is_first_run(&paths));
+    }
+
+    #[test]
+    fn test_prompted_profile_never_overwrites() {
+        let dir = tempdir().unwrap();
+        let paths = test_paths(dir.path());
+
+        let config =
+            save_prompted_profile("https://redmine.example.com", "first-key", &paths).unwrap();
+        assert_eq!(config.profile_name.as_deref(), Some("default"));
+
+        let result = save_prompted_profile("https://other.example.com", "second-key", &paths);
+        assert!(matches!(result, Err(AppError::Config { .. })));
+
+        let store = ProfileStore::load(&paths.config_file).unwrap();
+        let profile = store.get("default").unwrap();
+        assert_eq!(profile.url, "https://redmine.example.com");
+        assert_eq!(profile.api_key, "first-key");
+    }
+
+    #[test]
+    fn test_prompted_profile_rejects_bad_url() {
+        let dir = tempdir().unwrap();
+        let paths = test_paths(dir.path());
+
+        let result = save_prompted_profile("redmine.example.com", "key", &paths);
+        assert!(result.is_err());
+        assert!(!paths.config_file.exists());
+    }
 }
@@ -2,6 +2,11 @@
 
 mod loader;
 mod profile;
+mod settings;
 
-pub use loader::{load_config, Config, ConfigPaths};
-pub use profile::{Profile, ProfileStore};
+pub use loader::{
+    can_prompt, is_first_run, load_config, load_settings, prompt_for_profile, Config, ConfigPaths,
+};
+pub use profile::{MergeSummary, Profile, ProfileStore};
+#[allow(unused_imports)]
+pub use settings::{Settings, SETTING_KEYS};
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 
+use super::settings::Settings;
 use crate::error::{AppError, Result};
 
 /// A single Redmine profile with connection details.
@@ -15,6 +16,12 @@ pub struct Profile {
     pub url: String,
     /// API key for authentication.
     pub api_key: String,
+    /// Project (ID or identifier) used when a command omits `--project`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_project: Option<String>,
+    /// Request timeout in seconds (defaults to 30).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
 }
 
 impl Profile {
@@ -28,11 +35,12 @@ impl Profile {
             name: name.into(),
             url: url.into(),
             api_key: api_key.into(),
+            default_project: None,
+            timeout_secs: None,
         }
     }
 
     /// Redact the API key for display.
-    #[allow(dead_code)]
     pub fn redacted_api_key(&self) -> String {
         if self.api_key.len() <= 8 {
             "****".to_string()
@@ -52,6 +60,9 @@ pub struct ProfileStore {
     /// The currently active profile name.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active: Option<String>,
+    /// Scalar settings (`[settings]` table).
+    #[serde(default, skip_serializing_if = "Settings::is_empty")]
+    pub settings: Settings,
     /// Map of profile name to profile.
     #[serde(default)]
     pub profiles: HashMap<String, Profile>,
@@ -120,6 +131,31 @@ impl ProfileStore {
         Ok(())
     }
 
+    /// Replace the API key of a profile, returning the previous key.
+    pub fn update_key(&mut self, name: &str, api_key: &str) -> Result<String> {
+        let profile = self.profiles.get_mut(name).ok_or_else(|| {
+            AppError::not_found_with_hint(
+                "Profile",
+                name,
+                "Use `rdm profile list` to see available profiles.",
+            )
+        })?;
+        Ok(std::mem::replace(&mut profile.api_key, api_key.to_string()))
+    }
+
+    /// Set or clear (`None`) the default project of a profile.
+    pub fn set_default_project(&mut self, name: &str, project: Option<&str>) -> Result<()> {
+        let profile = self.profiles.get_mut(name).ok_or_else(|| {
+            AppError::not_found_with_hint(
+                "Profile",
+                name,
+                "Use `rdm profile list` to see available profiles.",
+            )
+        })?;
+        profile.default_project = project.map(str::to_string);
+        Ok(())
+    }
+
     /// Get the active profile.
     pub fn get_active(&self) -> Option<&Profile> {
         self.active
@@ -138,6 +174,87 @@ impl ProfileStore {
     pub fn list(&self) -> Vec<&str> {
         self.profiles.keys().map(|s| s.as_str()).collect()
     }
+
+    /// Copy of the store with every API key blanked out, for sharing.
+    pub fn without_keys(&self) -> Self {
+        let mut store = self.clone();
+        for profile in store.profiles.values_mut() {
+            profile.api_key.clear();
+        }
+        store
+    }
+
+    /// Merge profiles from another store.
+    ///
+    /// Existing profiles are only replaced when `overwrite` is set. A blank
+    /// API key in an imported profile keeps the key already stored locally.
+    pub fn merge(&mut self, other: ProfileStore, overwrite: bool) -> MergeSummary {
+        let mut summary = MergeSummary::default();
+
+        let mut incoming: Vec<Profile> = other.profiles.into_values().collect();
+        incoming.sort_by(|a, b| a.name.cmp(&b.name));
+
+        for mut profile in incoming {
+            match self.profiles.get(&profile.name) {
+                Some(_) if !overwrite => summary.skipped.push(profile.name),
+                Some(existing) => {
+                    if profile.api_key.is_empty() {
+                        profile.api_key = existing.api_key.clone();
+                    }
+                    summary.updated.push(profile.name.clone());
+                    self.profiles.insert(profile.name.clone(), profile);
+                }
+                None => {
+                    summary.added.push(profile.name.clone());
+                    self.profiles.insert(profile.name.clone(), profile);
+                }
+            }
+        }
+
+        if self.settings.branch_issue_pattern.is_none() || overwrite {
+            if let Some(pattern) = other.settings.branch_issue_pattern {
+                self.settings.branch_issue_pattern = Some(pattern);
+            }
+        }
+        if self.settings.time_rounding.is_none() || overwrite {
+            if let Some(rounding) = other.settings.time_rounding {
+                self.settings.time_rounding = Some(rounding);
+            }
+        }
+        for (project, activity) in other.settings.default_activity {
+            if overwrite || !self.settings.default_activity.contains_key(&project) {
+                self.settings.default_activity.insert(project, activity);
+            }
+        }
+
+        let active_valid = self
+            .active
+            .as_ref()
+            .is_some_and(|name| self.profiles.contains_key(name));
+        if !active_valid {
+            self.active = other
+                .active
+                .filter(|name| self.profiles.contains_key(name))
+                .or_else(|| {
+                    let mut names: Vec<&String> = self.profiles.keys().collect();
+                    names.sort();
+                    names.first().map(|name| name.to_string())
+                });
+        }
+
+        summary
+    }
+}
+
+/// Outcome of merging one profile store into another.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MergeSummary {
+    /// Profiles that did not exist before.
+    pub added: Vec<String>,
+    /// Existing profiles replaced by the imported ones.
+    pub updated: Vec<String>,
+    /// Existing profiles left untouched (no `--overwrite`).
+    pub skipped: Vec<String>,
 }
 
 #[cfg(test)]
@@ -179,6 +296,69 @@ mod tests {
         assert!(loaded.profiles.contains_key("test"));
     }
 
+    #[test]
+    fn test_profile_store_merge() {
+        let mut store = ProfileStore::default();
+        store.add(Profile::new("work", "https://work.example.com", "key1"));
+
+        let mut other = ProfileStore::default();
+        other.add(Profile::new("work", "https://new.example.com", ""));
+        other.add(Profile::new("home", "https://home.example.com", "key2"));
+
+        let summary = store.clone().merge(other.clone(), false);
+        assert_eq!(summary.added, vec!["home"]);
+        assert_eq!(summary.skipped, vec!["work"]);
+
+        let summary = store.merge(other, true);
+        assert_eq!(summary.updated, vec!["work"]);
+        let work = store.get("work").unwrap();
+        assert_eq!(work.url, "https://new.example.com");
+        // Blank imported key keeps the local one
+        assert_eq!(work.api_key, "key1");
+        assert_eq!(store.active, Some("work".to_string()));
+    }
+
+    #[test]
+    fn test_profile_store_without_keys() {
+        let mut store = ProfileStore::default();
+        store.add(Profile::new("work", "https://work.example.com", "key1"));
+        assert!(store.without_keys().get("work").unwrap().api_key.is_empty());
+    }
+
+    #[test]
+    fn test_profile_store_update_key() {
+        let mut store = ProfileStore::default();
+        store.add(Profile::new("work", "https://work.example.com", "old-key"));
+
+        let previous = store.update_key("work", "new-key").unwrap();
+        assert_eq!(previous, "old-key");
+        let profile = store.get("work").unwrap();
+        assert_eq!(profile.api_key, "new-key");
+        assert_eq!(profile.url, "https://work.example.com");
+
+        assert!(store.update_key("missing", "key").is_err());
+    }
+
+    #[test]
+    fn test_profile_store_default_project() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let mut store = ProfileStore::default();
+        store.add(Profile::new("work", "https://work.example.com", "key"));
+
+        store.set_default_project("work", Some("backend")).unwrap();
+        store.save(&path).unwrap();
+        let mut loaded = ProfileStore::load(&path).unwrap();
+        assert_eq!(
+            loaded.get("work").unwrap().default_project.as_deref(),
+            Some("backend")
+        );
+
+        loaded.set_default_project("work", None).unwrap();
+        assert_eq!(loaded.get("work").unwrap().default_project, None);
+        assert!(loaded.set_default_project("missing", Some("x")).is_err());
+    }
+
     #[test]
     fn test_profile_store_delete() {
         let mut store = ProfileStore::default();
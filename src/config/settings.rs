@@ -0,0 +1,160 @@
+//! Scalar settings stored in the `[settings]` table of the config file.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::error::{AppError, Result};
+
+/// Keys accepted by `rdm config get/set`.
+pub const SETTING_KEYS: &[&str] = &[
+    "time_rounding",
+    "branch_issue_pattern",
+    "default_activity.<project_id>",
+];
+
+/// Prefix of the per-project default activity keys.
+const DEFAULT_ACTIVITY_PREFIX: &str = "default_activity.";
+
+/// General settings that are not tied to a profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Settings {
+    /// Default `time create --round` increment in minutes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_rounding: Option<u32>,
+    /// Regex used to extract an issue ID from a git branch name
+    /// (first capture group or a group named `issue`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch_issue_pattern: Option<String>,
+    /// Default `time create` activity (name or ID) keyed by project ID.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub default_activity: BTreeMap<String, String>,
+}
+
+impl Settings {
+    /// Check if no setting has a value.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Get a setting as a string, `None` when unset.
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        match key {
+            "time_rounding" => Ok(self.time_rounding.map(|v| v.to_string())),
+            "branch_issue_pattern" => Ok(self.branch_issue_pattern.clone()),
+            _ => {
+                let project = project_key(key)?;
+                Ok(self.default_activity.get(&project).cloned())
+            }
+        }
+    }
+
+    /// Parse, validate and set a setting.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "time_rounding" => {
+                let minutes = value
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|m| *m > 0 && 60 % m == 0)
+                    .ok_or_else(|| {
+                        AppError::validation_with_hint(
+                            format!("Invalid time_rounding: '{}'", value),
+                            "Use minutes that divide 60, such as 5, 6, 10, 15, 20 or 30",
+                        )
+                    })?;
+                self.time_rounding = Some(minutes);
+            }
+            "branch_issue_pattern" => {
+                Regex::new(value).map_err(|e| {
+                    AppError::validation(format!("Invalid branch_issue_pattern: {}", e))
+                })?;
+                self.branch_issue_pattern = Some(value.to_string());
+            }
+            _ => {
+                // An empty value removes the project's default.
+                let project = project_key(key)?;
+                if value.is_empty() {
+                    self.default_activity.remove(&project);
+                } else {
+                    self.default_activity.insert(project, value.to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Default activity configured for a project, if any.
+    pub fn project_activity(&self, project_id: u32) -> Option<&str> {
+        self.default_activity
+            .get(&project_id.to_string())
+            .map(String::as_str)
+    }
+}
+
+/// Extract the project ID from a `default_activity.<project_id>` key.
+fn project_key(key: &str) -> Result<String> {
+    let project = key
+        .strip_prefix(DEFAULT_ACTIVITY_PREFIX)
+        .ok_or_else(|| unknown_key(key))?;
+    project
+        .parse::<u32>()
+        .map(|id| id.to_string())
+        .map_err(|_| {
+            AppError::validation_with_hint(
+                format!("Invalid project ID in '{}'", key),
+                "Use a numeric project ID, e.g. `default_activity.5`",
+            )
+        })
+}
+
+fn unknown_key(key: &str) -> AppError {
+    AppError::validation_with_hint(
+        format!("Unknown setting: '{}'", key),
+        format!("Known settings: {}", SETTING_KEYS.join(", ")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_get() {
+        let mut settings = Settings::default();
+        settings.set("time_rounding", "15").unwrap();
+        assert_eq!(settings.get("time_rounding").unwrap(), Some("15".into()));
+        assert_eq!(settings.get("branch_issue_pattern").unwrap(), None);
+    }
+
+    #[test]
+    fn test_set_validates_values() {
+        let mut settings = Settings::default();
+        assert!(settings.set("time_rounding", "7").is_err());
+        assert!(settings.set("time_rounding", "soon").is_err());
+        assert!(settings.set("branch_issue_pattern", "(").is_err());
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn test_project_default_activity() {
+        let mut settings = Settings::default();
+        settings.set("default_activity.5", "Design").unwrap();
+        assert_eq!(settings.project_activity(5), Some("Design"));
+        assert_eq!(
+            settings.get("default_activity.5").unwrap(),
+            Some("Design".into())
+        );
+        assert!(settings.set("default_activity.backend", "Design").is_err());
+
+        settings.set("default_activity.5", "").unwrap();
+        assert!(settings.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_key() {
+        let mut settings = Settings::default();
+        assert!(settings.get("timezone").is_err());
+        assert!(settings.set("timezone", "UTC").is_err());
+    }
+}
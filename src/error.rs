@@ -16,6 +16,10 @@ pub enum AppExitCode {
     NotFound = 4,
     /// API/server/network errors
     ApiError = 5,
+    /// Resource changed since it was last read
+    Conflict = 6,
+    /// Some items of a bulk command failed
+    PartialFailure = 7,
 }
 
 impl From<AppExitCode> for ExitCode {
@@ -45,7 +49,7 @@ pub enum AppError {
         hint: Option<String>,
     },
 
-    #[error("Not found: {resource} #{id}")]
+    #[error("Not found: {resource}{}", id_suffix(.id))]
     NotFound {
         resource: String,
         id: String,
@@ -65,6 +69,12 @@ pub enum AppError {
         hint: Option<String>,
     },
 
+    #[error("Conflict: {message}")]
+    Conflict {
+        message: String,
+        hint: Option<String>,
+    },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -73,6 +83,11 @@ pub enum AppError {
 
     #[error("TOML error: {0}")]
     Toml(#[from] toml::de::Error),
+
+    /// Some items of a bulk command failed; `output` is the rendered
+    /// per-item result, printed to stdout before the error.
+    #[error("Partial failure: {message}")]
+    PartialFailure { message: String, output: String },
 }
 
 impl AppError {
@@ -85,9 +100,11 @@ impl AppError {
             AppError::NotFound { .. } => AppExitCode::NotFound,
             AppError::Api { .. } => AppExitCode::ApiError,
             AppError::Network { .. } => AppExitCode::ApiError,
+            AppError::Conflict { .. } => AppExitCode::Conflict,
             AppError::Io(_) => AppExitCode::ApiError,
             AppError::Json(_) => AppExitCode::ApiError,
             AppError::Toml(_) => AppExitCode::Auth,
+            AppError::PartialFailure { .. } => AppExitCode::PartialFailure,
         }
     }
 
@@ -100,9 +117,11 @@ impl AppError {
             AppError::NotFound { .. } => "NOT_FOUND",
             AppError::Api { .. } => "API_ERROR",
             AppError::Network { .. } => "NETWORK_ERROR",
+            AppError::Conflict { .. } => "CONFLICT",
             AppError::Io(_) => "IO_ERROR",
             AppError::Json(_) => "JSON_ERROR",
             AppError::Toml(_) => "CONFIG_ERROR",
+            AppError::PartialFailure { .. } => "PARTIAL_FAILURE",
         }
     }
 
@@ -115,10 +134,19 @@ impl AppError {
             AppError::NotFound { hint, .. } => hint.as_deref(),
             AppError::Api { hint, .. } => hint.as_deref(),
             AppError::Network { hint, .. } => hint.as_deref(),
+            AppError::Conflict { hint, .. } => hint.as_deref(),
             _ => None,
         }
     }
 
+    /// Create a partial failure that still prints the per-item `output`.
+    pub fn partial_failure(message: impl Into<String>, output: impl Into<String>) -> Self {
+        AppError::PartialFailure {
+            message: message.into(),
+            output: output.into(),
+        }
+    }
+
     /// Create a validation error.
     pub fn validation(message: impl Into<String>) -> Self {
         AppError::Validation {
@@ -177,6 +205,15 @@ impl AppError {
         }
     }
 
+    /// Create a not found error for a list that matched nothing.
+    pub fn no_results(resource: impl Into<String>, hint: impl Into<String>) -> Self {
+        AppError::NotFound {
+            resource: resource.into(),
+            id: String::new(),
+            hint: Some(hint.into()),
+        }
+    }
+
     /// Create a not found error with hint.
     pub fn not_found_with_hint(
         resource: impl Into<String>,
@@ -200,7 +237,6 @@ impl AppError {
     }
 
     /// Create an API error with hint.
-    #[allow(dead_code)]
     pub fn api_with_hint(
         message: impl Into<String>,
         status: Option<u16>,
@@ -220,10 +256,27 @@ impl AppError {
             hint: None,
         }
     }
+
+    /// Create a conflict error with hint.
+    pub fn conflict_with_hint(message: impl Into<String>, hint: impl Into<String>) -> Self {
+        AppError::Conflict {
+            message: message.into(),
+            hint: Some(hint.into()),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, AppError>;
 
+/// ` #<id>` after the resource of a not found error; empty for list results.
+fn id_suffix(id: &str) -> String {
+    if id.is_empty() {
+        String::new()
+    } else {
+        format!(" #{}", id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -256,6 +309,17 @@ mod tests {
         assert_eq!(err.exit_code() as u8, 4);
     }
 
+    #[test]
+    fn test_no_results_message() {
+        let err = AppError::no_results("issues matching the filters", "Relax the filters");
+        assert_eq!(err.exit_code(), AppExitCode::NotFound);
+        assert_eq!(err.to_string(), "Not found: issues matching the filters");
+        assert_eq!(
+            AppError::not_found("Issue", "123").to_string(),
+            "Not found: Issue #123"
+        );
+    }
+
     #[test]
     fn test_exit_code_api() {
         let err = AppError::api("test", Some(500));
@@ -270,6 +334,14 @@ mod tests {
         assert_eq!(err.exit_code() as u8, 5);
     }
 
+    #[test]
+    fn test_exit_code_conflict() {
+        let err = AppError::conflict_with_hint("test", "hint");
+        assert_eq!(err.exit_code(), AppExitCode::Conflict);
+        assert_eq!(err.exit_code() as u8, 6);
+        assert_eq!(err.code(), "CONFLICT");
+    }
+
     #[test]
     fn test_error_code_strings() {
         assert_eq!(AppError::validation("test").code(), "VALIDATION_ERROR");
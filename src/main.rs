@@ -40,43 +40,213 @@ async fn main() -> ExitCode {
     }
 }
 
-async fn run(cli: Cli) -> Result<ExitCode, AppError> {
+async fn run(mut cli: Cli) -> Result<ExitCode, AppError> {
     let paths = ConfigPaths::new()?;
-    let format = cli.format;
+    let format = if cli.bare {
+        OutputFormat::JsonArray
+    } else {
+        cli.format
+    };
 
     // Handle commands that don't need config first
     if let Command::Profile(cmd) = &cli.command {
-        return handle_profile_command(cmd, &paths, format).await;
+        return handle_profile_command(cmd, &paths, format, cli.heading_offset).await;
+    }
+
+    // Doctor reports a broken config instead of failing on it
+    if let Command::Doctor = &cli.command {
+        let report = cli::doctor::execute(
+            cli.url.as_deref(),
+            cli.api_key.as_deref(),
+            cli.timeout,
+            cli.lang.clone(),
+            &paths,
+        )
+        .await;
+        let exit_code = report.exit_code();
+        let output = format.format_success(report, Meta::default());
+        print_output(&output, format, cli.heading_offset);
+        return Ok(exit_code.into());
+    }
+
+    if let Command::Completions(args) = &cli.command {
+        cli::write_completions(args.shell, &mut std::io::stdout());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Command::Man(args) = &cli.command {
+        let result = match &args.output_dir {
+            Some(dir) => {
+                cli::man::write_pages(dir).map(|r| format.format_success(r, Meta::default()))
+            }
+            None => cli::man::write_root_page(&mut std::io::stdout()).map(|_| String::new()),
+        };
+        return match result {
+            Ok(output) => {
+                print_output(&output, format, cli.heading_offset);
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(e) => {
+                print_error(&e, format);
+                Ok(e.exit_code().into())
+            }
+        };
+    }
+
+    // The cache lives on disk and needs no server
+    if let Command::Cache(cmd) = &cli.command {
+        use cli::cache::CacheCommand;
+        let result = match cmd {
+            CacheCommand::Status => {
+                cli::cache::status(&paths).map(|r| format.format_success(r, Meta::default()))
+            }
+            CacheCommand::Clear => {
+                cli::cache::clear(&paths).map(|r| format.format_success(r, Meta::default()))
+            }
+        };
+        return match result {
+            Ok(output) => {
+                print_output(&output, format, cli.heading_offset);
+                Ok(ExitCode::SUCCESS)
+            }
+            Err(e) => {
+                print_error(&e, format);
+                Ok(e.exit_code().into())
+            }
+        };
+    }
+
+    // Config export/import/get/set work on the config file, not the loaded config
+    if let Command::Config(args) = &cli.command {
+        use cli::profile::ConfigCommand;
+        let result = match &args.command {
+            Some(ConfigCommand::Export(args)) => Some(
+                cli::profile::export_config(args, &paths)
+                    .map(|r| format.format_success(r, Meta::default())),
+            ),
+            Some(ConfigCommand::Import(args)) => Some(
+                cli::profile::import_config(args, &paths)
+                    .map(|r| format.format_success(r, Meta::default())),
+            ),
+            Some(ConfigCommand::Get(args)) => Some(
+                cli::profile::get_setting(args, &paths)
+                    .map(|r| format.format_success(r, Meta::default())),
+            ),
+            Some(ConfigCommand::Set(args)) => Some(
+                cli::profile::set_setting(args, &paths)
+                    .map(|r| format.format_success(r, Meta::default())),
+            ),
+            Some(ConfigCommand::Show) | None => None,
+        };
+        if let Some(result) = result {
+            return match result {
+                Ok(output) => {
+                    print_output(&output, format, cli.heading_offset);
+                    Ok(ExitCode::SUCCESS)
+                }
+                Err(e) => {
+                    print_error(&e, format);
+                    Ok(e.exit_code().into())
+                }
+            };
+        }
     }
 
     // Load config for commands that need it
-    let config = match load_config(cli.url.as_deref(), cli.api_key.as_deref(), &paths) {
+    let mut config = match load_config(cli.url.as_deref(), cli.api_key.as_deref(), &paths) {
         Ok(c) => c,
+        // First run in a terminal: offer to create a profile instead of failing
+        Err(e @ AppError::Config { .. })
+            if config::can_prompt() && config::is_first_run(&paths) =>
+        {
+            match config::prompt_for_profile(cli.url.as_deref(), cli.api_key.as_deref(), &paths) {
+                Ok(Some(c)) => c,
+                Ok(None) => {
+                    print_error(&e, format);
+                    return Ok(e.exit_code().into());
+                }
+                Err(e) => {
+                    print_error(&e, format);
+                    return Ok(e.exit_code().into());
+                }
+            }
+        }
         Err(e) => {
             print_error(&e, format);
             return Ok(e.exit_code().into());
         }
     };
 
+    config.timeout_secs = cli.timeout.or(config.timeout_secs);
+    config.max_retries = cli.max_retries;
+    config.retry_max_elapsed_secs = cli.retry_max_elapsed;
+
+    // Commands without an explicit project fall back to the profile's default
+    cli.command
+        .set_default_project(config.default_project.as_deref());
+
     // Handle config show (needs config but not client)
     if let Command::Config(_) = &cli.command {
         let info = cli::profile::show_config(&config);
-        println!("{}", format.format_success(info, Meta::default()));
+        let output = format.format_success(info, Meta::default());
+        print_output(&output, format, cli.heading_offset);
         return Ok(ExitCode::SUCCESS);
     }
 
+    // Version-gated features: a declared version is remembered for later runs
+    let capabilities = match cli.api_version.as_deref() {
+        Some(version) => {
+            match cache::ServerCapabilities::declare(&paths.cache_dir, &config.url, version) {
+                Ok(capabilities) => capabilities,
+                Err(e) => {
+                    print_error(&e, format);
+                    return Ok(e.exit_code().into());
+                }
+            }
+        }
+        None => cache::ServerCapabilities::load(&paths.cache_dir, &config.url),
+    };
+
+    let settings = match config::load_settings(&paths, &config) {
+        Ok(settings) => settings,
+        Err(e) => {
+            print_error(&e, format);
+            return Ok(e.exit_code().into());
+        }
+    };
+
     // Create client
-    let client = client::RedmineClient::new(&config, cli.dry_run)?;
+    let response_cache =
+        (!cli.no_cache).then(|| cache::ResponseCache::new(paths.cache_dir.join("responses")));
+    let client = client::RedmineClient::new(&config, cli.dry_run)?
+        .with_language(cli.lang.clone())
+        .with_response_cache(response_cache)
+        .with_capabilities(capabilities)
+        .with_strict(cli.strict);
 
     // Execute command
-    let result = execute_command(&cli.command, &client, &paths, format).await;
+    let link_base = cli.links.then_some(config.url.as_str());
+    let color = cli.color.enabled(format);
+    let result = execute_command(
+        &cli.command,
+        &client,
+        &paths,
+        &settings,
+        format,
+        link_base,
+        color,
+    )
+    .await;
 
     match result {
         Ok(output) => {
-            println!("{}", output);
+            print_output(&output, format, cli.heading_offset);
             Ok(ExitCode::SUCCESS)
         }
         Err(e) => {
+            if let AppError::PartialFailure { output, .. } = &e {
+                print_output(output, format, cli.heading_offset);
+            }
             print_error(&e, format);
             Ok(e.exit_code().into())
         }
@@ -87,6 +257,7 @@ async fn handle_profile_command(
     cmd: &cli::profile::ProfileCommand,
     paths: &ConfigPaths,
     format: OutputFormat,
+    heading_offset: u8,
 ) -> Result<ExitCode, AppError> {
     use cli::profile::ProfileCommand;
 
@@ -100,11 +271,19 @@ async fn handle_profile_command(
         }
         ProfileCommand::Delete(args) => cli::profile::delete_profile(args, paths)
             .map(|r| format.format_success(r, Meta::default())),
+        ProfileCommand::Check(args) => cli::profile::check_profiles(args, paths)
+            .await
+            .map(|r| format.format_success(r, Meta::default())),
+        ProfileCommand::SetKey(args) => cli::profile::set_key(args, paths)
+            .await
+            .map(|r| format.format_success(r, Meta::default())),
+        ProfileCommand::SetDefaultProject(args) => cli::profile::set_default_project(args, paths)
+            .map(|r| format.format_success(r, Meta::default())),
     };
 
     match result {
         Ok(output) => {
-            println!("{}", output);
+            print_output(&output, format, heading_offset);
             Ok(ExitCode::SUCCESS)
         }
         Err(e) => {
@@ -118,7 +297,10 @@ async fn execute_command(
     command: &Command,
     client: &client::RedmineClient,
     paths: &ConfigPaths,
+    settings: &config::Settings,
     format: OutputFormat,
+    link_base: Option<&str>,
+    color: bool,
 ) -> Result<String, AppError> {
     match command {
         Command::Ping => {
@@ -126,57 +308,157 @@ async fn execute_command(
             Ok(format.format_success(result, Meta::default()))
         }
 
-        Command::Me => {
-            let user = client.me().await?;
+        Command::Me(args) => {
+            let user = cli::user::me(client, args).await?;
             Ok(format.format_success(user, Meta::default()))
         }
 
-        Command::Profile(_) | Command::Config(_) => {
+        Command::Profile(_)
+        | Command::Config(_)
+        | Command::Doctor
+        | Command::Completions(_)
+        | Command::Man(_)
+        | Command::Cache(_) => {
             // Already handled
             unreachable!()
         }
 
         Command::Project(cmd) => {
-            use cli::project::ProjectCommand;
+            use cli::project::{ProjectCommand, VersionCommand};
+            use models::ProjectStateAction;
             match cmd {
                 ProjectCommand::List(args) => {
-                    let result = cli::project::list(client, args).await?;
+                    let result = cli::project::list(client, paths, args).await?;
                     let meta = Meta::paginated(
                         result.total_count.unwrap_or(0),
                         result.limit.unwrap_or(25),
                         result.offset.unwrap_or(0),
-                    );
-                    Ok(format.format_success(result, meta))
+                    )
+                    .with_links(link_base);
+                    Ok(format.format_list(result, meta))
                 }
                 ProjectCommand::Get(args) => {
                     let result = cli::project::get(client, args).await?;
                     Ok(format.format_success(result, Meta::default()))
                 }
+                ProjectCommand::Archive(args) => {
+                    let result =
+                        cli::project::set_state(client, args, ProjectStateAction::Archive).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                ProjectCommand::Unarchive(args) => {
+                    let result =
+                        cli::project::set_state(client, args, ProjectStateAction::Unarchive)
+                            .await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                ProjectCommand::Close(args) => {
+                    let result =
+                        cli::project::set_state(client, args, ProjectStateAction::Close).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                ProjectCommand::Reopen(args) => {
+                    let result =
+                        cli::project::set_state(client, args, ProjectStateAction::Reopen).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                ProjectCommand::Versions(VersionCommand::Create(args)) => {
+                    let result = cli::project::create_version(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
             }
         }
 
         Command::Issue(cmd) => {
-            use cli::issue::{AttachmentCommand, IssueCommand};
-            match cmd {
+            use cli::issue::{
+                AttachmentCommand, IssueCommand, IssueListArgs, RelationCommand, WatcherCommand,
+            };
+            match cmd.as_ref() {
+                IssueCommand::List(args) if args.list_presets => {
+                    Ok(format.format_success(cli::issue::presets(), Meta::default()))
+                }
+                IssueCommand::List(
+                    args @ IssueListArgs {
+                        stream_to: Some(path),
+                        ..
+                    },
+                ) => {
+                    let result = cli::issue::stream_to_file(client, paths, args, path).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::List(args) if args.all && format == OutputFormat::Ndjson => {
+                    cli::issue::stream_all(client, paths, args, |issue| {
+                        println!("{}", serde_json::to_string(issue)?);
+                        Ok(())
+                    })
+                    .await?;
+                    Ok(String::new())
+                }
                 IssueCommand::List(args) => {
-                    let result = cli::issue::list(client, args).await?;
-                    let meta = Meta::paginated(
-                        result.total_count.unwrap_or(0),
-                        result.limit.unwrap_or(25),
-                        result.offset.unwrap_or(0),
-                    );
-                    Ok(format.format_success(result, meta))
+                    let result = cli::issue::list(client, paths, args).await?;
+                    let meta = result.meta().with_links(link_base).with_color(color);
+                    Ok(format.format_list(result, meta))
                 }
                 IssueCommand::Get(args) => {
                     let result = cli::issue::get(client, args).await?;
-                    Ok(format.format_success(result, Meta::default()))
+                    let meta = result
+                        .meta()
+                        .with_links(link_base)
+                        .with_plain_description(args.plain_description)
+                        .with_history(args.include_journals);
+                    Ok(format.format_success(result, meta))
                 }
                 IssueCommand::Create(args) => {
                     let result = cli::issue::create(client, args).await?;
                     Ok(format.format_success(result, Meta::default()))
                 }
                 IssueCommand::Update(args) => {
-                    let result = cli::issue::update(client, args).await?;
+                    let result = cli::issue::update(client, paths, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Comment(args) => {
+                    let result = cli::issue::comment(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::WatchProject(args) => {
+                    let result = cli::issue::watch_project(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Watchers(cmd) => {
+                    let (args, watching) = match cmd {
+                        WatcherCommand::Add(args) => (args, true),
+                        WatcherCommand::Remove(args) => (args, false),
+                    };
+                    let result = cli::issue::change_watcher(client, args, watching).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Statuses(args) => {
+                    let result = cli::issue::list_statuses(client, paths, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Trackers(args) => {
+                    let result = cli::issue::list_trackers(client, paths, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Priorities(args) => {
+                    let result = cli::issue::list_priorities(client, paths, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Relations(RelationCommand::List(args)) => {
+                    let result = cli::issue::relation_list(client, args).await?;
+                    let meta = Meta::default().with_links(link_base);
+                    Ok(format.format_success(result, meta))
+                }
+                IssueCommand::Relations(RelationCommand::Add(args)) => {
+                    let result = cli::issue::relation_add(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Relations(RelationCommand::Delete(args)) => {
+                    let result = cli::issue::relation_delete(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                IssueCommand::Relations(RelationCommand::Graph(args)) => {
+                    let result = cli::issue::relation_graph(client, args).await?;
                     Ok(format.format_success(result, Meta::default()))
                 }
                 IssueCommand::Attachment(cmd) => match cmd {
@@ -209,17 +491,23 @@ async fn execute_command(
                     }
                 }
                 TimeCommand::Create(args) => {
-                    let result = cli::time::create(client, paths, args).await?;
+                    let result = cli::time::create(client, paths, settings, args).await?;
                     Ok(format.format_success(result, Meta::default()))
                 }
                 TimeCommand::List(args) => {
-                    let result = cli::time::list(client, args).await?;
-                    let meta = result.meta();
+                    let result = cli::time::list(client, paths, args).await?;
+                    let meta = result.meta().with_links(link_base);
+                    Ok(format.format_list(result, meta))
+                }
+                TimeCommand::Summary(args) => {
+                    let result = cli::time::summary(client, paths, args).await?;
+                    let meta = Meta::default().with_total_hours(result.total_hours);
                     Ok(format.format_success(result, meta))
                 }
                 TimeCommand::Get(args) => {
-                    let result = cli::time::get(client, args).await?;
-                    Ok(format.format_success(result, Meta::default()))
+                    let result = cli::time::get(client, args, client.base_url()).await?;
+                    let meta = Meta::default().with_links(link_base);
+                    Ok(format.format_success(result, meta))
                 }
                 TimeCommand::Update(args) => {
                     let result = cli::time::update(client, paths, args).await?;
@@ -229,9 +517,43 @@ async fn execute_command(
                     let result = cli::time::delete(client, args).await?;
                     Ok(format.format_success(result, Meta::default()))
                 }
+                TimeCommand::Import(args) => {
+                    let result = cli::time::import(client, paths, settings, args).await?;
+                    let failure = result.failure_summary();
+                    let output = format.format_success(result, Meta::default());
+                    match failure {
+                        // Every row is still reported, but the exit code signals the failures
+                        Some(message) => Err(AppError::partial_failure(message, output)),
+                        None => Ok(output),
+                    }
+                }
             }
         }
 
+        Command::Wiki(cmd) => {
+            use cli::wiki::WikiCommand;
+            match cmd {
+                WikiCommand::List(args) => {
+                    let result = cli::wiki::list(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+                WikiCommand::Update(args) => {
+                    let result = cli::wiki::update(client, args).await?;
+                    Ok(format.format_success(result, Meta::default()))
+                }
+            }
+        }
+
+        Command::Search(args) => {
+            let result = cli::search::search(client, args).await?;
+            let meta = Meta::paginated(
+                result.total_count.unwrap_or(0),
+                result.limit.unwrap_or(args.limit),
+                result.offset.unwrap_or(0),
+            );
+            Ok(format.format_success(result, meta))
+        }
+
         Command::User(cmd) => {
             use cli::user::UserCommand;
             match cmd {
@@ -242,10 +564,10 @@ async fn execute_command(
                         result.limit.unwrap_or(25),
                         result.offset.unwrap_or(0),
                     );
-                    Ok(format.format_success(result, meta))
+                    Ok(format.format_list(result, meta))
                 }
-                UserCommand::Me => {
-                    let user = client.me().await?;
+                UserCommand::Me(args) => {
+                    let user = cli::user::me(client, args).await?;
                     Ok(format.format_success(user, Meta::default()))
                 }
             }
@@ -253,6 +575,24 @@ async fn execute_command(
     }
 }
 
+/// Print command output, skipping empty (already streamed) output.
+fn print_output(output: &str, format: OutputFormat, heading_offset: u8) {
+    if output.is_empty() {
+        return;
+    }
+    if format == OutputFormat::Markdown {
+        println!(
+            "{}",
+            output::markdown::shift_headings(output, heading_offset)
+        );
+    } else if format == OutputFormat::Csv {
+        // CSV rows already end with CRLF
+        print!("{}", output);
+    } else {
+        println!("{}", output);
+    }
+}
+
 fn print_error(error: &AppError, format: OutputFormat) {
     let output = format.format_error(error);
     eprintln!("{}", output);
@@ -59,6 +59,9 @@ pub struct AttachmentRef {
 pub struct AttachmentList {
     pub issue_id: u32,
     pub attachments: Vec<Attachment>,
+    /// Files written by `--download`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub downloaded: Vec<AttachmentDownloaded>,
 }
 
 impl MarkdownOutput for AttachmentList {
@@ -75,7 +78,7 @@ impl MarkdownOutput for AttachmentList {
             return output;
         }
 
-        let headers = ["ID", "Filename", "Size", "Type", "Author"];
+        let headers = ["ID", "Filename", "Size", "Type", "Author", "URL"];
         let rows: Vec<Vec<String>> = self
             .attachments
             .iter()
@@ -89,12 +92,27 @@ impl MarkdownOutput for AttachmentList {
                         .as_ref()
                         .map(|u| u.name.clone())
                         .unwrap_or_else(|| "-".into()),
+                    a.content_url.clone(),
                 ]
             })
             .collect();
 
         output.push_str(&markdown_table(&headers, rows));
-        output.push_str("\n*Use `rdm issue attachment download --id <ID>` to download*\n");
+        if self.downloaded.is_empty() {
+            output.push_str("\n*Use `rdm issue attachment download --id <ID>` to download*\n");
+            return output;
+        }
+
+        let total: u64 = self.downloaded.iter().map(|d| d.bytes).sum();
+        output.push_str(&format!("\n### Downloaded ({})\n\n", format_bytes(total)));
+        for file in &self.downloaded {
+            output.push_str(&format!(
+                "- **{}** ({}) saved to `{}`\n",
+                file.filename,
+                format_bytes(file.bytes),
+                file.saved_to.display()
+            ));
+        }
         output
     }
 }
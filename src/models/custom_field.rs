@@ -16,32 +16,84 @@ pub struct CustomField {
 }
 
 /// Custom field value for API requests (write format).
-/// Redmine expects: `{ "id": 5, "value": "some value" }`
-///
-/// Note: This currently only supports string values. Multi-value custom fields
-/// (arrays) are not yet supported via the CLI.
+/// Redmine expects: `{ "id": 5, "value": "some value" }`, or an array of
+/// strings for multi-value fields: `{ "id": 5, "value": ["a", "b"] }`.
 #[derive(Debug, Clone, Serialize)]
 pub struct CustomFieldValue {
     pub id: u32,
-    pub value: String,
+    pub value: CustomFieldWriteValue,
+}
+
+/// Value sent for a custom field on write.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum CustomFieldWriteValue {
+    Single(String),
+    Multiple(Vec<String>),
 }
 
 impl CustomFieldValue {
     /// Create a new custom field value from parsed (id, value) tuple.
     pub fn new(id: u32, value: String) -> Self {
-        Self { id, value }
+        Self {
+            id,
+            value: CustomFieldWriteValue::Single(value),
+        }
+    }
+
+    /// Create a multi-value custom field value.
+    pub fn multiple(id: u32, values: Vec<String>) -> Self {
+        Self {
+            id,
+            value: CustomFieldWriteValue::Multiple(values),
+        }
     }
 
     /// Convert a list of (id, value) tuples to CustomFieldValue vec.
+    ///
+    /// Values sharing an ID are collected into one multi-value field; an ID
+    /// given once stays a single value.
     pub fn from_tuples(tuples: Vec<(u32, String)>) -> Vec<Self> {
-        tuples
+        let mut grouped: Vec<(u32, Vec<String>)> = Vec::new();
+        for (id, value) in tuples {
+            match grouped.iter_mut().find(|(i, _)| *i == id) {
+                Some((_, values)) => values.push(value),
+                None => grouped.push((id, vec![value])),
+            }
+        }
+        grouped
             .into_iter()
-            .map(|(id, value)| Self::new(id, value))
+            .map(|(id, mut values)| {
+                if values.len() == 1 {
+                    Self::new(id, values.remove(0))
+                } else {
+                    Self::multiple(id, values)
+                }
+            })
             .collect()
     }
 }
 
 impl CustomField {
+    /// Whether this field holds multiple values.
+    pub fn is_multiple(&self) -> bool {
+        self.multiple.unwrap_or(false) || self.value.is_array()
+    }
+
+    /// Get the current value(s) as strings, skipping empty entries.
+    pub fn values(&self) -> Vec<String> {
+        let as_string = |v: &serde_json::Value| match v {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) if s.is_empty() => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        };
+        match &self.value {
+            serde_json::Value::Array(arr) => arr.iter().filter_map(as_string).collect(),
+            other => as_string(other).into_iter().collect(),
+        }
+    }
+
     /// Get the value as a display string.
     pub fn display_value(&self) -> String {
         match &self.value {
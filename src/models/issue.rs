@@ -2,13 +2,18 @@
 
 use super::attachment::{format_bytes, Attachment, AttachmentRef};
 use super::custom_field::{CustomField, CustomFieldValue};
-use super::project::ProjectRef;
+use super::project::{ProjectRef, ProjectWriteId};
 use super::user::User;
 use crate::output::{
-    markdown::{markdown_kv_table, markdown_table, pagination_hint},
-    MarkdownOutput, Meta,
+    csv::{csv_columns, csv_opt},
+    markdown::{
+        markdown_code_block, markdown_kv_table, markdown_table, pagination_hint,
+        partial_grouping_note, ANSI_DIM, ANSI_RED,
+    },
+    CsvOutput, MarkdownOutput, Meta,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Tracker (Bug, Feature, etc).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +22,38 @@ pub struct Tracker {
     pub name: String,
 }
 
+/// List of trackers from API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackerList {
+    pub trackers: Vec<Tracker>,
+}
+
+impl MarkdownOutput for TrackerList {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Trackers\n\n");
+
+        if self.trackers.is_empty() {
+            output.push_str("*No trackers found*\n");
+            return output;
+        }
+
+        let headers = &["ID", "Name"];
+        let rows: Vec<Vec<String>> = self
+            .trackers
+            .iter()
+            .map(|t| vec![t.id.to_string(), t.name.clone()])
+            .collect();
+
+        output.push_str(&markdown_table(headers, rows));
+        output.push_str(
+            "\n*Use with `rdm issue update --tracker <name|id>` or `rdm issue create --tracker <id>`*\n",
+        );
+
+        output
+    }
+}
+
 /// Issue status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Status {
@@ -26,11 +63,96 @@ pub struct Status {
     pub is_closed: Option<bool>,
 }
 
+/// List of issue statuses from API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusList {
+    pub issue_statuses: Vec<Status>,
+}
+
+impl MarkdownOutput for StatusList {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Issue Statuses\n\n");
+
+        if self.issue_statuses.is_empty() {
+            output.push_str("*No statuses found*\n");
+            return output;
+        }
+
+        let headers = &["ID", "Name", "Closed"];
+        let rows: Vec<Vec<String>> = self
+            .issue_statuses
+            .iter()
+            .map(|s| {
+                vec![
+                    s.id.to_string(),
+                    s.name.clone(),
+                    if s.is_closed.unwrap_or(false) {
+                        "Yes"
+                    } else {
+                        "-"
+                    }
+                    .to_string(),
+                ]
+            })
+            .collect();
+
+        output.push_str(&markdown_table(headers, rows));
+        output.push_str("\n*Use with `rdm issue update --status <name|id>`*\n");
+
+        output
+    }
+}
+
 /// Issue priority.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Priority {
     pub id: u32,
     pub name: String,
+    /// Set in the priority enumeration, not on issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+}
+
+/// List of issue priorities from API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriorityList {
+    pub issue_priorities: Vec<Priority>,
+}
+
+impl MarkdownOutput for PriorityList {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str("## Issue Priorities\n\n");
+
+        if self.issue_priorities.is_empty() {
+            output.push_str("*No priorities found*\n");
+            return output;
+        }
+
+        let headers = &["ID", "Name", "Default"];
+        let rows: Vec<Vec<String>> = self
+            .issue_priorities
+            .iter()
+            .map(|p| {
+                vec![
+                    p.id.to_string(),
+                    p.name.clone(),
+                    if p.is_default.unwrap_or(false) {
+                        "Yes"
+                    } else {
+                        "-"
+                    }
+                    .to_string(),
+                ]
+            })
+            .collect();
+
+        output.push_str(&markdown_table(headers, rows));
+        output.push_str("\n*Use with `rdm issue update --priority <name|id>` or `rdm issue create --priority <id>`*\n");
+
+        output
+    }
 }
 
 /// A single field change within a journal entry.
@@ -72,6 +194,9 @@ pub struct Issue {
     pub author: Option<User>,
     #[serde(default)]
     pub assigned_to: Option<User>,
+    /// Parent issue, for subtasks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<IssueRef>,
     #[serde(default)]
     pub start_date: Option<String>,
     #[serde(default)]
@@ -86,6 +211,11 @@ pub struct Issue {
     pub created_on: Option<String>,
     #[serde(default)]
     pub updated_on: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_on: Option<String>,
+    /// Optimistic locking counter, when the server reports it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_version: Option<u32>,
     #[serde(default)]
     pub custom_fields: Option<Vec<CustomField>>,
     #[serde(default)]
@@ -94,6 +224,61 @@ pub struct Issue {
     pub attachments: Option<Vec<Attachment>>,
 }
 
+/// Issue field names accepted by `rdm issue get --fields`.
+pub const ISSUE_FIELDS: &[&str] = &[
+    "id",
+    "subject",
+    "description",
+    "project",
+    "tracker",
+    "status",
+    "priority",
+    "author",
+    "assigned_to",
+    "parent",
+    "start_date",
+    "due_date",
+    "done_ratio",
+    "estimated_hours",
+    "spent_hours",
+    "created_on",
+    "updated_on",
+    "closed_on",
+    "custom_fields",
+    "journals",
+    "attachments",
+];
+
+/// Reference to another issue by ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRef {
+    pub id: u32,
+}
+
+/// A single issue limited to a subset of its fields.
+#[derive(Debug, Clone)]
+pub struct IssueProjection {
+    pub issue: Issue,
+    /// Field names from [`ISSUE_FIELDS`] to keep.
+    pub fields: Vec<String>,
+}
+
+impl Serialize for IssueProjection {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut value = serde_json::to_value(&self.issue).map_err(serde::ser::Error::custom)?;
+        if let serde_json::Value::Object(map) = &mut value {
+            map.retain(|key, _| self.fields.iter().any(|f| f == key));
+        }
+        value.serialize(serializer)
+    }
+}
+
+impl MarkdownOutput for IssueProjection {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        self.issue.render_markdown(meta, Some(&self.fields))
+    }
+}
+
 /// List of issues from API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueList {
@@ -104,6 +289,12 @@ pub struct IssueList {
     pub offset: Option<u32>,
     #[serde(default)]
     pub limit: Option<u32>,
+    /// Custom field IDs to render as extra Markdown table columns.
+    #[serde(skip)]
+    pub cf_columns: Vec<u32>,
+    /// Append a tracker/status/priority ID legend to Markdown output.
+    #[serde(skip)]
+    pub legend: bool,
 }
 
 /// Wrapper for single issue response.
@@ -115,7 +306,7 @@ pub struct IssueResponse {
 /// New issue creation request.
 #[derive(Debug, Clone, Serialize)]
 pub struct NewIssue {
-    pub project_id: u32,
+    pub project_id: ProjectWriteId,
     pub subject: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -127,15 +318,29 @@ pub struct NewIssue {
     pub priority_id: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assigned_to_id: Option<u32>,
+    /// Target version (milestone).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_version_id: Option<u32>,
+    /// Parent issue, making this one a subtask.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_issue_id: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub estimated_hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_ratio: Option<u32>,
     /// Custom field values for the issue.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomFieldValue>>,
+    /// Files to attach (upload tokens).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploads: Option<Vec<AttachmentRef>>,
+    /// Users added as watchers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watcher_user_ids: Option<Vec<u32>>,
 }
 
 /// Wrapper for issue creation request.
@@ -159,22 +364,32 @@ pub struct UpdateIssue {
     pub priority_id: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assigned_to_id: Option<u32>,
+    /// `Some(None)` is sent as `null`, which removes the parent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_issue_id: Option<Option<u32>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub due_date: Option<String>,
+    /// `Some(None)` is sent as `null`, which clears the estimate.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub estimated_hours: Option<f64>,
+    pub estimated_hours: Option<Option<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub done_ratio: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+    /// Mark `notes` as private.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_notes: Option<bool>,
     /// Custom field values to update.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<CustomFieldValue>>,
     /// Attachments to add (upload tokens).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uploads: Option<Vec<AttachmentRef>>,
+    /// Reject the update if the issue's lock_version has moved on.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_version: Option<u32>,
 }
 
 /// Wrapper for issue update request.
@@ -184,63 +399,93 @@ pub struct UpdateIssueRequest {
 }
 
 impl MarkdownOutput for Issue {
-    fn to_markdown(&self, _meta: &Meta) -> String {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        self.render_markdown(meta, None)
+    }
+}
+
+impl Issue {
+    /// Render the issue as Markdown, limited to `fields` when given.
+    fn render_markdown(&self, meta: &Meta, fields: Option<&[String]>) -> String {
+        let shown = |field: &str| match fields {
+            Some(fields) => fields.iter().any(|f| f == field),
+            None => true,
+        };
+
         let mut output = String::new();
         output.push_str(&format!("## Issue #{}: {}\n\n", self.id, self.subject));
 
         let mut pairs = vec![
-            ("ID", self.id.to_string()),
-            ("Subject", self.subject.clone()),
-            ("Project", self.project.name.clone()),
-            ("Status", self.status.name.clone()),
-            ("Priority", self.priority.name.clone()),
+            ("id", "ID", self.id.to_string()),
+            ("subject", "Subject", self.subject.clone()),
+            ("project", "Project", self.project.name.clone()),
+            ("status", "Status", self.status.name.clone()),
+            ("priority", "Priority", self.priority.name.clone()),
         ];
 
         if let Some(tracker) = &self.tracker {
-            pairs.push(("Tracker", tracker.name.clone()));
+            pairs.push(("tracker", "Tracker", tracker.name.clone()));
         }
 
         if let Some(assignee) = &self.assigned_to {
-            pairs.push(("Assignee", assignee.name.clone()));
+            pairs.push(("assigned_to", "Assignee", assignee.name.clone()));
         }
 
         if let Some(author) = &self.author {
-            pairs.push(("Author", author.name.clone()));
+            pairs.push(("author", "Author", author.name.clone()));
+        }
+
+        if let Some(parent) = &self.parent {
+            pairs.push(("parent", "Parent", format!("#{}", parent.id)));
         }
 
         if let Some(start) = &self.start_date {
-            pairs.push(("Start Date", start.clone()));
+            pairs.push(("start_date", "Start Date", start.clone()));
         }
 
         if let Some(due) = &self.due_date {
-            pairs.push(("Due Date", due.clone()));
+            pairs.push(("due_date", "Due Date", due.clone()));
         }
 
         if let Some(done) = self.done_ratio {
-            pairs.push(("Done", format!("{}%", done)));
+            pairs.push(("done_ratio", "Done", format!("{}%", done)));
         }
 
         if let Some(estimated) = self.estimated_hours {
-            pairs.push(("Estimated", format!("{:.2}h", estimated)));
+            pairs.push(("estimated_hours", "Estimated", format!("{:.2}h", estimated)));
         }
 
         if let Some(spent) = self.spent_hours {
-            pairs.push(("Spent", format!("{:.2}h", spent)));
+            pairs.push(("spent_hours", "Spent", format!("{:.2}h", spent)));
         }
 
         if let Some(created) = &self.created_on {
-            pairs.push(("Created", created.clone()));
+            pairs.push(("created_on", "Created", created.clone()));
         }
 
         if let Some(updated) = &self.updated_on {
-            pairs.push(("Updated", updated.clone()));
+            pairs.push(("updated_on", "Updated", updated.clone()));
         }
 
-        let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
-        output.push_str(&markdown_kv_table(&pairs_ref));
+        if let Some(closed) = &self.closed_on {
+            pairs.push(("closed_on", "Closed", closed.clone()));
+        }
+
+        let pairs_ref: Vec<(&str, String)> = pairs
+            .iter()
+            .filter(|(field, _, _)| shown(field))
+            .map(|(_, k, v)| (*k, v.clone()))
+            .collect();
+        if !pairs_ref.is_empty() {
+            output.push_str(&markdown_kv_table(&pairs_ref));
+        }
 
         // Display custom fields if present
-        if let Some(custom_fields) = &self.custom_fields {
+        if let Some(custom_fields) = self
+            .custom_fields
+            .as_ref()
+            .filter(|_| shown("custom_fields"))
+        {
             if !custom_fields.is_empty() {
                 output.push_str("\n### Custom Fields\n\n");
                 let cf_pairs: Vec<(&str, String)> = custom_fields
@@ -251,34 +496,42 @@ impl MarkdownOutput for Issue {
             }
         }
 
-        if let Some(desc) = &self.description {
+        if let Some(desc) = self.description.as_ref().filter(|_| shown("description")) {
             if !desc.is_empty() {
                 output.push_str("\n### Description\n\n");
-                output.push_str(desc);
-                output.push('\n');
+                if meta.plain_description {
+                    output.push_str(&markdown_code_block(desc));
+                } else {
+                    output.push_str(desc);
+                    output.push('\n');
+                }
             }
         }
 
-        if let Some(journals) = &self.journals {
-            let notes: Vec<&Journal> = journals
-                .iter()
-                .filter(|j| j.notes.as_deref().map(|n| !n.is_empty()).unwrap_or(false))
-                .collect();
-            if !notes.is_empty() {
-                output.push_str("\n### Comments\n\n");
-                for j in notes {
-                    output.push_str(&format!(
-                        "**#{} — {} ({})**\n\n{}\n\n---\n\n",
-                        j.id,
-                        j.user.name,
-                        j.created_on,
-                        j.notes.as_deref().unwrap_or("")
-                    ));
+        if let Some(journals) = self.journals.as_ref().filter(|_| shown("journals")) {
+            if meta.show_history {
+                output.push_str(&journal_history(journals));
+            } else {
+                let notes: Vec<&Journal> = journals
+                    .iter()
+                    .filter(|j| j.notes.as_deref().map(|n| !n.is_empty()).unwrap_or(false))
+                    .collect();
+                if !notes.is_empty() {
+                    output.push_str("\n### Comments\n\n");
+                    for j in notes {
+                        output.push_str(&format!(
+                            "**#{} — {} ({})**\n\n{}\n\n---\n\n",
+                            j.id,
+                            j.user.name,
+                            j.created_on,
+                            j.notes.as_deref().unwrap_or("")
+                        ));
+                    }
                 }
             }
         }
 
-        if let Some(attachments) = &self.attachments {
+        if let Some(attachments) = self.attachments.as_ref().filter(|_| shown("attachments")) {
             if !attachments.is_empty() {
                 output.push_str("\n### Attachments\n\n");
                 for a in attachments {
@@ -298,6 +551,99 @@ impl MarkdownOutput for Issue {
 
         output
     }
+
+    /// Display value of a custom field, or "-" when absent.
+    pub fn custom_field_display(&self, id: u32) -> String {
+        self.custom_fields
+            .as_ref()
+            .and_then(|cfs| cfs.iter().find(|cf| cf.id == id))
+            .map(|cf| cf.display_value())
+            .unwrap_or_else(|| "-".to_string())
+    }
+}
+
+impl IssueList {
+    /// Column header for a custom field, using its name when any issue carries it.
+    fn cf_column_name(&self, id: u32) -> String {
+        self.issues
+            .iter()
+            .filter_map(|i| i.custom_fields.as_ref())
+            .flatten()
+            .find(|cf| cf.id == id)
+            .map(|cf| cf.name.clone())
+            .unwrap_or_else(|| format!("CF {}", id))
+    }
+}
+
+/// CSV columns of issue lists, before any `--cf-column` columns.
+const ISSUE_CSV_COLUMNS: &[&str] = &[
+    "id",
+    "project",
+    "tracker",
+    "status",
+    "priority",
+    "subject",
+    "author",
+    "assigned_to",
+    "parent",
+    "start_date",
+    "due_date",
+    "done_ratio",
+    "estimated_hours",
+    "spent_hours",
+    "created_on",
+    "updated_on",
+    "closed_on",
+];
+
+/// Cells of an issue in `ISSUE_CSV_COLUMNS` order.
+fn issue_csv_row(i: &Issue) -> Vec<String> {
+    vec![
+        i.id.to_string(),
+        i.project.name.clone(),
+        csv_opt(i.tracker.as_ref().map(|t| &t.name)),
+        i.status.name.clone(),
+        i.priority.name.clone(),
+        i.subject.clone(),
+        csv_opt(i.author.as_ref().map(|u| &u.name)),
+        csv_opt(i.assigned_to.as_ref().map(|u| &u.name)),
+        csv_opt(i.parent.as_ref().map(|p| p.id)),
+        csv_opt(i.start_date.as_ref()),
+        csv_opt(i.due_date.as_ref()),
+        csv_opt(i.done_ratio),
+        csv_opt(i.estimated_hours),
+        csv_opt(i.spent_hours),
+        csv_opt(i.created_on.as_ref()),
+        csv_opt(i.updated_on.as_ref()),
+        csv_opt(i.closed_on.as_ref()),
+    ]
+}
+
+impl CsvOutput for IssueList {
+    fn csv_header(&self) -> Vec<String> {
+        let mut header = csv_columns(ISSUE_CSV_COLUMNS);
+        header.extend(self.cf_columns.iter().map(|id| format!("cf_{}", id)));
+        header
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.issues
+            .iter()
+            .map(|i| {
+                let mut row = issue_csv_row(i);
+                row.extend(self.cf_columns.iter().map(|id| {
+                    csv_opt(
+                        i.custom_fields
+                            .iter()
+                            .flatten()
+                            .find(|cf| cf.id == *id)
+                            .map(|cf| cf.display_value()),
+                    )
+                }));
+                row
+            })
+            .collect()
+    }
 }
 
 impl MarkdownOutput for IssueList {
@@ -320,26 +666,29 @@ impl MarkdownOutput for IssueList {
             return output;
         }
 
-        let headers = &["ID", "Subject", "Status", "Priority", "Assignee", "Updated"];
+        let mut headers: Vec<String> = ISSUE_TABLE_HEADERS.iter().map(|h| h.to_string()).collect();
+        headers.extend(self.cf_columns.iter().map(|id| self.cf_column_name(*id)));
+        let headers_ref: Vec<&str> = headers.iter().map(|h| h.as_str()).collect();
+
         let rows: Vec<Vec<String>> = self
             .issues
             .iter()
             .map(|i| {
-                vec![
-                    i.id.to_string(),
-                    truncate(&i.subject, 40),
-                    i.status.name.clone(),
-                    i.priority.name.clone(),
-                    i.assigned_to
-                        .as_ref()
-                        .map(|u| u.name.clone())
-                        .unwrap_or_else(|| "-".to_string()),
-                    i.updated_on.clone().unwrap_or_else(|| "-".to_string()),
-                ]
+                let mut row = issue_row(i, meta);
+                row.extend(self.cf_columns.iter().map(|id| i.custom_field_display(*id)));
+                row
             })
             .collect();
 
-        output.push_str(&markdown_table(headers, rows));
+        output.push_str(&markdown_table(&headers_ref, rows));
+
+        if self.legend {
+            output.push_str("\n### Legend\n\n");
+            output.push_str(&markdown_table(
+                &["Field", "ID", "Name"],
+                self.legend_rows(),
+            ));
+        }
 
         if let Some(hint) = pagination_hint("rdm issue list ", meta) {
             output.push('\n');
@@ -351,6 +700,105 @@ impl MarkdownOutput for IssueList {
     }
 }
 
+impl IssueList {
+    /// Distinct tracker, status and priority (id, name) pairs seen in the listed issues.
+    fn legend_rows(&self) -> Vec<Vec<String>> {
+        let mut trackers = BTreeMap::new();
+        let mut statuses = BTreeMap::new();
+        let mut priorities = BTreeMap::new();
+        for issue in &self.issues {
+            if let Some(tracker) = &issue.tracker {
+                trackers.insert(tracker.id, &tracker.name);
+            }
+            statuses.insert(issue.status.id, &issue.status.name);
+            priorities.insert(issue.priority.id, &issue.priority.name);
+        }
+
+        [
+            ("Tracker", trackers),
+            ("Status", statuses),
+            ("Priority", priorities),
+        ]
+        .into_iter()
+        .flat_map(|(field, entries)| {
+            entries
+                .into_iter()
+                .map(move |(id, name)| vec![field.to_string(), id.to_string(), name.clone()])
+        })
+        .collect()
+    }
+}
+
+const ISSUE_TABLE_HEADERS: &[&str] =
+    &["ID", "Subject", "Status", "Priority", "Assignee", "Updated"];
+
+/// Priority names highlighted in colored issue tables (Redmine's defaults).
+const HIGH_PRIORITIES: &[&str] = &["High", "Urgent", "Immediate"];
+
+/// Build a table row for an issue in list views.
+fn issue_row(i: &Issue, meta: &Meta) -> Vec<String> {
+    let id = match meta.link_base {
+        Some(_) => meta.link(&format!("#{}", i.id), &format!("/issues/{}", i.id)),
+        None => i.id.to_string(),
+    };
+    vec![
+        id,
+        truncate(&i.subject, 40),
+        if i.status.is_closed.unwrap_or(false) {
+            meta.paint(&i.status.name, ANSI_DIM)
+        } else {
+            i.status.name.clone()
+        },
+        if HIGH_PRIORITIES
+            .iter()
+            .any(|p| i.priority.name.eq_ignore_ascii_case(p))
+        {
+            meta.paint(&i.priority.name, ANSI_RED)
+        } else {
+            i.priority.name.clone()
+        },
+        i.assigned_to
+            .as_ref()
+            .map(|u| u.name.clone())
+            .unwrap_or_else(|| "-".to_string()),
+        i.updated_on.clone().unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+/// Render all journals oldest first, with notes and field changes.
+fn journal_history(journals: &[Journal]) -> String {
+    if journals.is_empty() {
+        return String::new();
+    }
+    let mut journals: Vec<&Journal> = journals.iter().collect();
+    journals.sort_by(|a, b| a.created_on.cmp(&b.created_on));
+
+    let mut output = String::from("\n### History\n\n");
+    for j in journals {
+        output.push_str(&format!(
+            "**#{} — {} ({})**\n\n",
+            j.id, j.user.name, j.created_on
+        ));
+        for d in &j.details {
+            output.push_str(&format!(
+                "- {}: {} → {}\n",
+                d.name,
+                d.old_value.as_deref().unwrap_or("-"),
+                d.new_value.as_deref().unwrap_or("-")
+            ));
+        }
+        if !j.details.is_empty() {
+            output.push('\n');
+        }
+        if let Some(notes) = j.notes.as_deref().filter(|n| !n.is_empty()) {
+            output.push_str(notes);
+            output.push_str("\n\n");
+        }
+        output.push_str("---\n\n");
+    }
+    output
+}
+
 fn truncate(s: &str, max_len: usize) -> String {
     if s.len() <= max_len {
         s.to_string()
@@ -359,28 +807,145 @@ fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
-/// Search result from Redmine search API.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SearchResult {
-    pub id: u32,
-    pub title: String,
-    #[serde(rename = "type")]
-    pub result_type: String,
-    pub url: String,
-    #[serde(default)]
-    pub description: Option<String>,
-    #[serde(default)]
-    pub datetime: Option<String>,
+/// Field to group issues by.
+#[derive(Debug, Clone)]
+pub enum IssueGroupByField {
+    Status,
+    Priority,
+    Assignee,
+    Tracker,
+    Project,
 }
 
-/// Search results response from API.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SearchResults {
-    pub results: Vec<SearchResult>,
-    #[serde(default)]
-    pub total_count: Option<u32>,
-    #[serde(default)]
-    pub offset: Option<u32>,
-    #[serde(default)]
-    pub limit: Option<u32>,
+impl IssueGroupByField {
+    /// Parse a group-by field from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "status" => Some(Self::Status),
+            "priority" => Some(Self::Priority),
+            "assignee" | "assigned_to" => Some(Self::Assignee),
+            "tracker" => Some(Self::Tracker),
+            "project" => Some(Self::Project),
+            _ => None,
+        }
+    }
+
+    /// Get the display name for this field.
+    pub fn display_name(&self) -> String {
+        match self {
+            Self::Status => "Status".to_string(),
+            Self::Priority => "Priority".to_string(),
+            Self::Assignee => "Assignee".to_string(),
+            Self::Tracker => "Tracker".to_string(),
+            Self::Project => "Project".to_string(),
+        }
+    }
+}
+
+/// A group of issues with a name and count.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueGroup {
+    pub name: String,
+    pub count: u32,
+    pub issues: Vec<Issue>,
+}
+
+/// Grouped issues for display.
+#[derive(Debug, Clone, Serialize)]
+pub struct GroupedIssues {
+    pub group_by: String,
+    pub groups: Vec<IssueGroup>,
+    pub total_count: u32,
+    /// Issues matching on the server; more than `total_count` when only a page was grouped.
+    pub matched_count: u32,
+}
+
+impl CsvOutput for GroupedIssues {
+    fn csv_header(&self) -> Vec<String> {
+        csv_columns(ISSUE_CSV_COLUMNS)
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.groups
+            .iter()
+            .flat_map(|g| g.issues.iter().map(issue_csv_row))
+            .collect()
+    }
+}
+
+impl GroupedIssues {
+    /// Create grouped issues from a list.
+    pub fn from_entries(issues: Vec<Issue>, field: &IssueGroupByField) -> Self {
+        let mut groups_map: BTreeMap<String, Vec<Issue>> = BTreeMap::new();
+
+        for issue in issues {
+            let key = match field {
+                IssueGroupByField::Status => issue.status.name.clone(),
+                IssueGroupByField::Priority => issue.priority.name.clone(),
+                IssueGroupByField::Assignee => issue
+                    .assigned_to
+                    .as_ref()
+                    .map(|u| u.name.clone())
+                    .unwrap_or_else(|| "Unassigned".to_string()),
+                IssueGroupByField::Tracker => issue
+                    .tracker
+                    .as_ref()
+                    .map(|t| t.name.clone())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+                IssueGroupByField::Project => issue.project.name.clone(),
+            };
+
+            groups_map.entry(key).or_default().push(issue);
+        }
+
+        let mut total_count = 0u32;
+        let groups: Vec<IssueGroup> = groups_map
+            .into_iter()
+            .map(|(name, issues)| {
+                let count = issues.len() as u32;
+                total_count += count;
+                IssueGroup {
+                    name,
+                    count,
+                    issues,
+                }
+            })
+            .collect();
+
+        Self {
+            group_by: field.display_name(),
+            groups,
+            total_count,
+            matched_count: total_count,
+        }
+    }
+}
+
+impl MarkdownOutput for GroupedIssues {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        let mut output = String::new();
+        output.push_str(&format!(
+            "## Issues by {} ({} issues)\n\n",
+            self.group_by, self.total_count
+        ));
+        if let Some(note) =
+            partial_grouping_note(self.total_count, meta, "use --all to group every match")
+        {
+            output.push_str(&note);
+        }
+
+        if self.groups.is_empty() {
+            output.push_str("*No issues found*\n");
+            return output;
+        }
+
+        for group in &self.groups {
+            output.push_str(&format!("### {} ({})\n\n", group.name, group.count));
+            let rows: Vec<Vec<String>> = group.issues.iter().map(|i| issue_row(i, meta)).collect();
+            output.push_str(&markdown_table(ISSUE_TABLE_HEADERS, rows));
+            output.push('\n');
+        }
+
+        output
+    }
 }
@@ -4,8 +4,12 @@ pub mod attachment;
 mod custom_field;
 mod issue;
 mod project;
+mod relation;
+mod search;
 mod time_entry;
 mod user;
+mod version;
+mod wiki;
 
 // Re-export for public API (may not be used internally but available for consumers)
 #[allow(unused_imports)]
@@ -14,19 +18,39 @@ pub use attachment::{
     AttachmentUploaded, UploadResponse,
 };
 #[allow(unused_imports)]
-pub use custom_field::{CustomField, CustomFieldValue};
+pub use custom_field::{CustomField, CustomFieldValue, CustomFieldWriteValue};
 pub use issue::{
-    Issue, IssueList, IssueResponse, NewIssue, NewIssueRequest, UpdateIssue, UpdateIssueRequest,
+    GroupedIssues, Issue, IssueGroupByField, IssueList, IssueProjection, IssueResponse, NewIssue,
+    NewIssueRequest, Priority, PriorityList, Status, StatusList, Tracker, TrackerList, UpdateIssue,
+    UpdateIssueRequest, ISSUE_FIELDS,
 };
-// Re-export for internal use by client/endpoints.rs
-pub(crate) use issue::SearchResults;
-pub use project::{Project, ProjectList, ProjectResponse};
+pub use project::{
+    project_status_label, LastActivity, Project, ProjectList, ProjectNameFilter, ProjectResponse,
+    ProjectStateAction, ProjectStateChanged, ProjectWriteId,
+};
+pub use relation::{
+    IssueRelation, IssueRelationCreated, IssueRelationDeleted, IssueRelationList,
+    IssueRelationResponse, IssueRelations, NewIssueRelation, NewIssueRelationRequest,
+    RELATION_TYPES,
+};
+#[allow(unused_imports)]
+pub use search::SearchResult;
+pub use search::SearchResults;
 pub use time_entry::{
     Activity, ActivityList, GroupByField, GroupedTimeEntries, NewTimeEntry, NewTimeEntryRequest,
     TimeEntry, TimeEntryCreated, TimeEntryDeleted, TimeEntryList, TimeEntryResponse,
-    TimeEntryUpdated, UpdateTimeEntry, UpdateTimeEntryRequest,
+    TimeEntryUpdated, TimeSummary, UpdateTimeEntry, UpdateTimeEntryRequest, WeeklyGrid,
 };
-pub use user::{CurrentUser, CurrentUserResponse};
+pub use user::{Capabilities, CurrentUser, CurrentUserResponse};
 // Re-export for public API
 #[allow(unused_imports)]
 pub use user::User;
+pub use version::{
+    NewVersion, NewVersionRequest, Version, VersionCreated, VersionList, VersionResponse,
+    VersionSharing,
+};
+#[allow(unused_imports)]
+pub use wiki::{
+    UpdateWikiPage, UpdateWikiPageRequest, WikiPage, WikiPageList, WikiPageRef, WikiPageResponse,
+    WikiPageSummary, WikiPageUpdated,
+};
@@ -1,8 +1,9 @@
 //! Project model.
 
 use crate::output::{
+    csv::{csv_columns, csv_opt},
     markdown::{markdown_kv_table, markdown_table, pagination_hint},
-    MarkdownOutput, Meta,
+    CsvOutput, MarkdownOutput, Meta,
 };
 use serde::{Deserialize, Serialize};
 
@@ -13,6 +14,36 @@ pub struct ProjectRef {
     pub name: String,
 }
 
+/// Project sent in a write request.
+///
+/// Redmine accepts the identifier in place of the numeric ID; it is only sent
+/// as given when it was not looked up (`--dry-run`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ProjectWriteId {
+    Id(u32),
+    Identifier(String),
+}
+
+impl ProjectWriteId {
+    /// The numeric ID, if known.
+    pub fn id(&self) -> Option<u32> {
+        match self {
+            ProjectWriteId::Id(id) => Some(*id),
+            ProjectWriteId::Identifier(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectWriteId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectWriteId::Id(id) => write!(f, "{}", id),
+            ProjectWriteId::Identifier(identifier) => f.write_str(identifier),
+        }
+    }
+}
+
 /// Project from Redmine API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
@@ -31,6 +62,35 @@ pub struct Project {
     pub created_on: Option<String>,
     #[serde(default)]
     pub updated_on: Option<String>,
+    /// Most recent issue activity, filled in by `project get --with-activity`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_activity: Option<LastActivity>,
+}
+
+/// Most recent issue activity in a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum LastActivity {
+    /// The most recently updated issue.
+    Issue { issue_id: u32, updated_on: String },
+    /// The project has no issues.
+    NoIssues,
+    /// Issues are not visible to the current user.
+    Forbidden,
+}
+
+impl LastActivity {
+    /// One-line summary for Markdown output.
+    pub fn summary(&self) -> String {
+        match self {
+            LastActivity::Issue {
+                issue_id,
+                updated_on,
+            } => format!("{} on #{}", updated_on, issue_id),
+            LastActivity::NoIssues => "No issues yet".to_string(),
+            LastActivity::Forbidden => "Unavailable (no permission to view issues)".to_string(),
+        }
+    }
 }
 
 /// List of projects from API.
@@ -43,6 +103,20 @@ pub struct ProjectList {
     pub offset: Option<u32>,
     #[serde(default)]
     pub limit: Option<u32>,
+    /// Set when the list was narrowed with `--name-contains`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_filter: Option<ProjectNameFilter>,
+}
+
+/// Summary of a client-side project name filter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectNameFilter {
+    /// Text matched against names and identifiers.
+    pub query: String,
+    /// Projects fetched and searched.
+    pub searched: u32,
+    /// Projects available on the server.
+    pub available: u32,
 }
 
 /// Wrapper for single project response.
@@ -51,6 +125,90 @@ pub struct ProjectResponse {
     pub project: Project,
 }
 
+/// Project status value for active projects.
+pub const PROJECT_STATUS_ACTIVE: u32 = 1;
+/// Project status value for closed projects.
+pub const PROJECT_STATUS_CLOSED: u32 = 5;
+/// Project status value for archived projects.
+pub const PROJECT_STATUS_ARCHIVED: u32 = 9;
+
+/// Human-readable label for a project status value.
+pub fn project_status_label(status: u32) -> &'static str {
+    match status {
+        PROJECT_STATUS_ACTIVE => "Active",
+        PROJECT_STATUS_CLOSED => "Closed",
+        PROJECT_STATUS_ARCHIVED => "Archived",
+        _ => "Unknown",
+    }
+}
+
+/// Lifecycle action on a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectStateAction {
+    Archive,
+    Unarchive,
+    Close,
+    Reopen,
+}
+
+impl ProjectStateAction {
+    /// API path segment for this action (`/projects/{id}/<segment>.json`).
+    pub fn path_segment(&self) -> &'static str {
+        match self {
+            Self::Archive => "archive",
+            Self::Unarchive => "unarchive",
+            Self::Close => "close",
+            Self::Reopen => "reopen",
+        }
+    }
+
+    /// Past-tense label used in output.
+    pub fn past_tense(&self) -> &'static str {
+        match self {
+            Self::Archive => "Archived",
+            Self::Unarchive => "Unarchived",
+            Self::Close => "Closed",
+            Self::Reopen => "Reopened",
+        }
+    }
+
+    /// Project status expected after the action succeeds.
+    pub fn resulting_status(&self) -> u32 {
+        match self {
+            Self::Archive => PROJECT_STATUS_ARCHIVED,
+            Self::Close => PROJECT_STATUS_CLOSED,
+            Self::Unarchive | Self::Reopen => PROJECT_STATUS_ACTIVE,
+        }
+    }
+}
+
+/// Result of a project lifecycle action.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectStateChanged {
+    pub project: String,
+    pub action: String,
+    pub status: u32,
+    pub status_name: String,
+    /// Whether `status` was read back from the server rather than assumed.
+    pub confirmed: bool,
+}
+
+impl MarkdownOutput for ProjectStateChanged {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        if self.confirmed {
+            format!(
+                "## Project {}\n\nProject **{}** is now **{}**.\n",
+                self.action, self.project, self.status_name
+            )
+        } else {
+            format!(
+                "## Project {}\n\nProject **{}** should now be **{}** (it could not be read back to confirm).\n",
+                self.action, self.project, self.status_name
+            )
+        }
+    }
+}
+
 impl MarkdownOutput for Project {
     fn to_markdown(&self, _meta: &Meta) -> String {
         let mut output = String::new();
@@ -73,13 +231,7 @@ impl MarkdownOutput for Project {
         pairs.push(("Parent", parent_str));
 
         if let Some(status) = self.status {
-            let status_str = match status {
-                1 => "Active",
-                5 => "Closed",
-                9 => "Archived",
-                _ => "Unknown",
-            };
-            pairs.push(("Status", status_str.to_string()));
+            pairs.push(("Status", project_status_label(status).to_string()));
         }
 
         if let Some(is_public) = self.is_public {
@@ -94,6 +246,10 @@ impl MarkdownOutput for Project {
             pairs.push(("Updated", updated.clone()));
         }
 
+        if let Some(activity) = &self.last_activity {
+            pairs.push(("Last activity", activity.summary()));
+        }
+
         let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
         output.push_str(&markdown_kv_table(&pairs_ref));
 
@@ -114,20 +270,68 @@ impl MarkdownOutput for Project {
     }
 }
 
+impl CsvOutput for ProjectList {
+    fn csv_header(&self) -> Vec<String> {
+        csv_columns(&[
+            "id",
+            "identifier",
+            "name",
+            "parent",
+            "status",
+            "is_public",
+            "created_on",
+            "updated_on",
+        ])
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.projects
+            .iter()
+            .map(|p| {
+                vec![
+                    p.id.to_string(),
+                    p.identifier.clone(),
+                    p.name.clone(),
+                    csv_opt(p.parent.as_ref().map(|parent| &parent.name)),
+                    csv_opt(p.status.map(project_status_label)),
+                    csv_opt(p.is_public),
+                    csv_opt(p.created_on.as_ref()),
+                    csv_opt(p.updated_on.as_ref()),
+                ]
+            })
+            .collect()
+    }
+}
+
 impl MarkdownOutput for ProjectList {
     fn to_markdown(&self, meta: &Meta) -> String {
         let mut output = String::new();
 
-        let total = meta.total_count.unwrap_or(self.projects.len() as u32);
-        let offset = meta.offset.unwrap_or(0);
-        let showing_end = offset + self.projects.len() as u32;
+        if let Some(filter) = &self.name_filter {
+            output.push_str(&format!(
+                "## Projects matching \"{}\" ({} of {})\n\n",
+                filter.query,
+                self.projects.len(),
+                filter.searched
+            ));
+            if filter.searched < filter.available {
+                output.push_str(&format!(
+                    "*Searched only {} of {} projects; add `--all` to search every project.*\n\n",
+                    filter.searched, filter.available
+                ));
+            }
+        } else {
+            let total = meta.total_count.unwrap_or(self.projects.len() as u32);
+            let offset = meta.offset.unwrap_or(0);
+            let showing_end = offset + self.projects.len() as u32;
 
-        output.push_str(&format!(
-            "## Projects (showing {}-{} of {})\n\n",
-            offset + 1,
-            showing_end,
-            total
-        ));
+            output.push_str(&format!(
+                "## Projects (showing {}-{} of {})\n\n",
+                offset + 1,
+                showing_end,
+                total
+            ));
+        }
 
         if self.projects.is_empty() {
             output.push_str("*No projects found*\n");
@@ -139,15 +343,7 @@ impl MarkdownOutput for ProjectList {
             .projects
             .iter()
             .map(|p| {
-                let status = p
-                    .status
-                    .map(|s| match s {
-                        1 => "Active",
-                        5 => "Closed",
-                        9 => "Archived",
-                        _ => "Unknown",
-                    })
-                    .unwrap_or("-");
+                let status = p.status.map(project_status_label).unwrap_or("-");
                 let parent = p
                     .parent
                     .as_ref()
@@ -155,7 +351,7 @@ impl MarkdownOutput for ProjectList {
                     .unwrap_or_else(|| "-".to_string());
                 vec![
                     p.id.to_string(),
-                    p.identifier.clone(),
+                    meta.link(&p.identifier, &format!("/projects/{}", p.identifier)),
                     p.name.clone(),
                     parent,
                     status.to_string(),
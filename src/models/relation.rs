@@ -0,0 +1,166 @@
+//! Issue relation models.
+
+use serde::{Deserialize, Serialize};
+
+use crate::output::{
+    markdown::{markdown_kv_table, markdown_table},
+    MarkdownOutput, Meta,
+};
+
+/// Relation types accepted by the API; `duplicated`, `blocked`, `follows` and
+/// `copied_from` are stored as their forward form with the issues swapped.
+pub const RELATION_TYPES: &[&str] = &[
+    "relates",
+    "duplicates",
+    "duplicated",
+    "blocks",
+    "blocked",
+    "precedes",
+    "follows",
+    "copied_to",
+    "copied_from",
+];
+
+/// A relation between two issues.
+///
+/// Redmine stores reversed types in their forward form, so a `blocks`
+/// relation always means `issue_id` blocks `issue_to_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRelation {
+    pub id: u32,
+    pub issue_id: u32,
+    pub issue_to_id: u32,
+    pub relation_type: String,
+    #[serde(default)]
+    pub delay: Option<i32>,
+}
+
+impl IssueRelation {
+    /// The other issue and the relation as seen from `issue_id`, e.g. `blocked by`.
+    pub fn seen_from(&self, issue_id: u32) -> (u32, String) {
+        if self.issue_id == issue_id {
+            return (self.issue_to_id, self.relation_type.replace('_', " "));
+        }
+        let reversed = match self.relation_type.as_str() {
+            "duplicates" => "duplicated by",
+            "blocks" => "blocked by",
+            "precedes" => "follows",
+            "copied_to" => "copied from",
+            other => other,
+        };
+        (self.issue_id, reversed.to_string())
+    }
+}
+
+/// List of issue relations from API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IssueRelationList {
+    pub relations: Vec<IssueRelation>,
+}
+
+/// Wrapper for single relation response.
+#[derive(Debug, Deserialize)]
+pub struct IssueRelationResponse {
+    pub relation: IssueRelation,
+}
+
+/// New issue relation creation request.
+#[derive(Debug, Clone, Serialize)]
+pub struct NewIssueRelation {
+    pub issue_to_id: u32,
+    pub relation_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<i32>,
+}
+
+/// Wrapper for relation creation request.
+#[derive(Debug, Serialize)]
+pub struct NewIssueRelationRequest {
+    pub relation: NewIssueRelation,
+}
+
+/// Relations of one issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueRelations {
+    pub issue_id: u32,
+    pub relations: Vec<IssueRelation>,
+}
+
+impl MarkdownOutput for IssueRelations {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        let mut output = format!("## Relations of #{}\n\n", self.issue_id);
+
+        if self.relations.is_empty() {
+            output.push_str("*No relations found*\n");
+            return output;
+        }
+
+        let rows: Vec<Vec<String>> = self
+            .relations
+            .iter()
+            .map(|r| {
+                let (other, relation) = r.seen_from(self.issue_id);
+                vec![
+                    r.id.to_string(),
+                    relation,
+                    meta.link(&format!("#{}", other), &format!("/issues/{}", other)),
+                    r.delay
+                        .map(|d| format!("{} days", d))
+                        .unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+        output.push_str(&markdown_table(&["ID", "Relation", "Issue", "Delay"], rows));
+        output.push_str(
+            "\n*Use `rdm issue relations delete --id <relation_id>` to remove a relation*\n",
+        );
+        output
+    }
+}
+
+/// Result of relation creation.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueRelationCreated {
+    pub relation: IssueRelation,
+}
+
+impl MarkdownOutput for IssueRelationCreated {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let r = &self.relation;
+        let mut output = String::from("## Relation Added\n\n");
+
+        let mut pairs = vec![
+            ("ID", r.id.to_string()),
+            (
+                "Relation",
+                format!(
+                    "#{} {} #{}",
+                    r.issue_id,
+                    r.relation_type.replace('_', " "),
+                    r.issue_to_id
+                ),
+            ),
+        ];
+        if let Some(delay) = r.delay {
+            pairs.push(("Delay", format!("{} days", delay)));
+        }
+        output.push_str(&markdown_kv_table(&pairs));
+
+        output
+    }
+}
+
+/// Message for successful relation deletion.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueRelationDeleted {
+    pub id: u32,
+}
+
+impl MarkdownOutput for IssueRelationDeleted {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        format!(
+            "## Relation Deleted\n\nRelation #{} has been deleted.\n",
+            self.id
+        )
+    }
+}
@@ -0,0 +1,79 @@
+//! Search result models.
+
+use crate::output::{
+    markdown::{markdown_table, pagination_hint},
+    MarkdownOutput, Meta,
+};
+use serde::{Deserialize, Serialize};
+
+/// Search result from Redmine search API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub id: u32,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub result_type: String,
+    pub url: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub datetime: Option<String>,
+}
+
+/// Search results response from API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub results: Vec<SearchResult>,
+    #[serde(default)]
+    pub total_count: Option<u32>,
+    #[serde(default)]
+    pub offset: Option<u32>,
+    #[serde(default)]
+    pub limit: Option<u32>,
+}
+
+impl MarkdownOutput for SearchResults {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        let mut output = String::new();
+
+        let total = meta.total_count.unwrap_or(self.results.len() as u32);
+        let offset = meta.offset.unwrap_or(0);
+        let showing_end = offset + self.results.len() as u32;
+
+        output.push_str(&format!(
+            "## Search Results (showing {}-{} of {})\n\n",
+            offset + 1,
+            showing_end,
+            total
+        ));
+
+        if self.results.is_empty() {
+            output.push_str("*No results found*\n");
+            return output;
+        }
+
+        let headers = &["Type", "Title", "Date", "URL"];
+        let rows: Vec<Vec<String>> = self
+            .results
+            .iter()
+            .map(|r| {
+                vec![
+                    r.result_type.clone(),
+                    r.title.clone(),
+                    r.datetime.clone().unwrap_or_else(|| "-".to_string()),
+                    r.url.clone(),
+                ]
+            })
+            .collect();
+
+        output.push_str(&markdown_table(headers, rows));
+
+        if let Some(hint) = pagination_hint("rdm search ", meta) {
+            output.push('\n');
+            output.push_str(&hint);
+            output.push('\n');
+        }
+
+        output
+    }
+}
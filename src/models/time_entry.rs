@@ -1,12 +1,14 @@
 //! Time entry model with activity caching.
 
 use super::custom_field::CustomField;
-use super::project::ProjectRef;
+use super::project::{ProjectRef, ProjectWriteId};
 use super::user::User;
 use crate::output::{
-    markdown::{markdown_kv_table, markdown_table, pagination_hint},
-    MarkdownOutput, Meta,
+    csv::{csv_columns, csv_opt},
+    markdown::{markdown_kv_table, markdown_table, pagination_hint, partial_grouping_note},
+    CsvOutput, MarkdownOutput, Meta,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
 /// Activity type for time entries.
@@ -45,12 +47,18 @@ pub struct TimeEntry {
     pub updated_on: Option<String>,
     #[serde(default)]
     pub custom_fields: Option<Vec<CustomField>>,
+    /// Web page of the entry, filled in by `rdm time get`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
 /// Simplified issue reference in time entries.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeEntryIssue {
     pub id: u32,
+    /// Issue subject, filled in by `rdm time list --enrich`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
 }
 
 /// List of time entries from API.
@@ -65,6 +73,55 @@ pub struct TimeEntryList {
     pub limit: Option<u32>,
 }
 
+/// CSV columns of time entry lists.
+const TIME_ENTRY_CSV_COLUMNS: &[&str] = &[
+    "id",
+    "spent_on",
+    "hours",
+    "activity",
+    "project",
+    "issue",
+    "issue_subject",
+    "user",
+    "comments",
+    "created_on",
+    "updated_on",
+];
+
+/// Cells of a time entry in `TIME_ENTRY_CSV_COLUMNS` order.
+fn time_entry_csv_row(e: &TimeEntry) -> Vec<String> {
+    vec![
+        e.id.to_string(),
+        e.spent_on.clone(),
+        e.hours.to_string(),
+        e.activity.name.clone(),
+        csv_opt(e.project.as_ref().map(|p| &p.name)),
+        csv_opt(e.issue.as_ref().map(|i| i.id)),
+        csv_opt(e.issue.as_ref().and_then(|i| i.subject.as_ref())),
+        csv_opt(e.user.as_ref().map(|u| &u.name)),
+        csv_opt(e.comments.as_ref()),
+        csv_opt(e.created_on.as_ref()),
+        csv_opt(e.updated_on.as_ref()),
+    ]
+}
+
+impl CsvOutput for TimeEntryList {
+    fn csv_header(&self) -> Vec<String> {
+        csv_columns(TIME_ENTRY_CSV_COLUMNS)
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.time_entries.iter().map(time_entry_csv_row).collect()
+    }
+}
+
+impl TimeEntryList {
+    /// Sum of the hours of the listed entries.
+    pub fn total_hours(&self) -> f64 {
+        self.time_entries.iter().map(|t| t.hours).sum()
+    }
+}
+
 /// Wrapper for single time entry response.
 #[derive(Debug, Deserialize)]
 pub struct TimeEntryResponse {
@@ -77,7 +134,7 @@ pub struct NewTimeEntry {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issue_id: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project_id: Option<u32>,
+    pub project_id: Option<ProjectWriteId>,
     pub hours: f64,
     pub activity_id: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -161,7 +218,7 @@ impl MarkdownOutput for ActivityList {
 }
 
 impl MarkdownOutput for TimeEntry {
-    fn to_markdown(&self, _meta: &Meta) -> String {
+    fn to_markdown(&self, meta: &Meta) -> String {
         let mut output = String::new();
         output.push_str(&format!("## Time Entry #{}\n\n", self.id));
 
@@ -173,7 +230,12 @@ impl MarkdownOutput for TimeEntry {
         ];
 
         if let Some(issue) = &self.issue {
-            pairs.push(("Issue", format!("#{}", issue.id)));
+            let id = issue_link(issue.id, meta);
+            let value = match &issue.subject {
+                Some(subject) => format!("{} {}", id, subject),
+                None => id,
+            };
+            pairs.push(("Issue", value));
         }
 
         if let Some(project) = &self.project {
@@ -198,6 +260,10 @@ impl MarkdownOutput for TimeEntry {
             pairs.push(("Updated", updated.clone()));
         }
 
+        if let Some(url) = &self.url {
+            pairs.push(("URL", url.clone()));
+        }
+
         let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
         output.push_str(&markdown_kv_table(&pairs_ref));
 
@@ -242,8 +308,7 @@ impl MarkdownOutput for TimeEntryList {
             return output;
         }
 
-        // Calculate total hours
-        let total_hours: f64 = self.time_entries.iter().map(|t| t.hours).sum();
+        let total_hours = self.total_hours();
 
         let headers = &[
             "ID", "Date", "Hours", "User", "Activity", "Issue", "Comment",
@@ -263,7 +328,13 @@ impl MarkdownOutput for TimeEntryList {
                     t.activity.name.clone(),
                     t.issue
                         .as_ref()
-                        .map(|i| format!("#{}", i.id))
+                        .map(|i| {
+                            let id = issue_link(i.id, meta);
+                            match &i.subject {
+                                Some(subject) => format!("{} {}", id, truncate_comment(subject)),
+                                None => id,
+                            }
+                        })
                         .unwrap_or_else(|| "-".to_string()),
                     truncate_comment(t.comments.as_deref().unwrap_or("-")),
                 ]
@@ -283,6 +354,11 @@ impl MarkdownOutput for TimeEntryList {
     }
 }
 
+/// `#id` issue reference, linked to the issue with `--links`.
+fn issue_link(id: u32, meta: &Meta) -> String {
+    meta.link(&format!("#{}", id), &format!("/issues/{}", id))
+}
+
 fn truncate_comment(s: &str) -> String {
     let s = s.replace('\n', " ");
     if s.chars().count() <= 30 {
@@ -306,6 +382,9 @@ fn truncate_name(s: &str, max_len: usize) -> String {
 #[derive(Debug, Clone, Serialize)]
 pub struct TimeEntryCreated {
     pub time_entry: TimeEntry,
+    /// Hours as given on the command line, when rounding changed them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_hours: Option<f64>,
 }
 
 impl MarkdownOutput for TimeEntryCreated {
@@ -316,7 +395,7 @@ impl MarkdownOutput for TimeEntryCreated {
 
         let mut pairs = vec![
             ("ID", t.id.to_string()),
-            ("Hours", format!("{:.2}", t.hours)),
+            ("Hours", hours_with_rounding(t.hours, self.requested_hours)),
             ("Activity", t.activity.name.clone()),
             ("Date", t.spent_on.clone()),
         ];
@@ -347,6 +426,14 @@ impl MarkdownOutput for TimeEntryCreated {
     }
 }
 
+/// Format hours, noting the original value when rounding changed it.
+fn hours_with_rounding(hours: f64, requested: Option<f64>) -> String {
+    match requested {
+        Some(requested) => format!("{:.2} (rounded from {:.2})", hours, requested),
+        None => format!("{:.2}", hours),
+    }
+}
+
 /// Message for successful time entry update.
 #[derive(Debug, Clone, Serialize)]
 pub struct TimeEntryUpdated {
@@ -441,6 +528,21 @@ pub struct GroupedTimeEntries {
     pub groups: Vec<TimeEntryGroup>,
     pub total_hours: f64,
     pub total_count: u32,
+    /// Entries matching on the server; more than `total_count` when only a page was grouped.
+    pub matched_count: u32,
+}
+
+impl CsvOutput for GroupedTimeEntries {
+    fn csv_header(&self) -> Vec<String> {
+        csv_columns(TIME_ENTRY_CSV_COLUMNS)
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.groups
+            .iter()
+            .flat_map(|g| g.entries.iter().map(time_entry_csv_row))
+            .collect()
+    }
 }
 
 impl GroupedTimeEntries {
@@ -501,17 +603,23 @@ impl GroupedTimeEntries {
             groups,
             total_hours,
             total_count,
+            matched_count: total_count,
         }
     }
 }
 
 impl MarkdownOutput for GroupedTimeEntries {
-    fn to_markdown(&self, _meta: &Meta) -> String {
+    fn to_markdown(&self, meta: &Meta) -> String {
         let mut output = String::new();
         output.push_str(&format!(
             "## Time Entries by {} ({} entries)\n\n",
             self.group_by, self.total_count
         ));
+        if let Some(note) =
+            partial_grouping_note(self.total_count, meta, "raise --limit to group every match")
+        {
+            output.push_str(&note);
+        }
 
         if self.groups.is_empty() {
             output.push_str("*No time entries found*\n");
@@ -542,7 +650,7 @@ impl MarkdownOutput for GroupedTimeEntries {
                         t.activity.name.clone(),
                         t.issue
                             .as_ref()
-                            .map(|i| format!("#{}", i.id))
+                            .map(|i| issue_link(i.id, meta))
                             .unwrap_or_else(|| "-".to_string()),
                         truncate_comment(t.comments.as_deref().unwrap_or("-")),
                     ]
@@ -558,3 +666,287 @@ impl MarkdownOutput for GroupedTimeEntries {
         output
     }
 }
+
+/// One row of a weekly timesheet grid: an issue, or project-level time.
+#[derive(Debug, Clone, Serialize)]
+pub struct WeeklyGridRow {
+    /// Issue ID; `None` for time logged on the project itself.
+    pub issue: Option<u32>,
+    /// Issue subject (with `--enrich`) or project name.
+    pub label: String,
+    /// Hours per day, aligned with `WeeklyGrid::days`.
+    pub hours: Vec<f64>,
+    pub total: f64,
+}
+
+impl WeeklyGridRow {
+    fn new(issue: Option<u32>, label: String, days: usize) -> Self {
+        Self {
+            issue,
+            label,
+            hours: vec![0.0; days],
+            total: 0.0,
+        }
+    }
+}
+
+/// Time entries of one week as an issue-by-day grid.
+#[derive(Debug, Clone, Serialize)]
+pub struct WeeklyGrid {
+    /// Dates of the grid columns (YYYY-MM-DD).
+    pub days: Vec<String>,
+    pub rows: Vec<WeeklyGridRow>,
+    /// Hours per day over all rows.
+    pub day_totals: Vec<f64>,
+    pub total_hours: f64,
+}
+
+impl CsvOutput for WeeklyGrid {
+    fn csv_header(&self) -> Vec<String> {
+        let mut header = csv_columns(&["issue", "label"]);
+        header.extend(self.days.iter().cloned());
+        header.push("total".to_string());
+        header
+    }
+
+    fn csv_rows(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|r| {
+                let mut row = vec![csv_opt(r.issue), r.label.clone()];
+                row.extend(r.hours.iter().map(|h| h.to_string()));
+                row.push(r.total.to_string());
+                row
+            })
+            .collect()
+    }
+}
+
+impl WeeklyGrid {
+    /// Build the grid for the days `from..=to`; entries outside the range are ignored.
+    pub fn from_entries(entries: &[TimeEntry], from: NaiveDate, to: NaiveDate) -> Self {
+        use std::collections::BTreeMap;
+
+        let days: Vec<String> = from
+            .iter_days()
+            .take_while(|d| *d <= to)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect();
+
+        let mut issues: BTreeMap<u32, WeeklyGridRow> = BTreeMap::new();
+        let mut projects: BTreeMap<String, WeeklyGridRow> = BTreeMap::new();
+        let mut day_totals = vec![0.0; days.len()];
+        for entry in entries {
+            let Some(day) = days.iter().position(|d| *d == entry.spent_on) else {
+                continue;
+            };
+            let row = match &entry.issue {
+                Some(issue) => issues.entry(issue.id).or_insert_with(|| {
+                    let subject = issue.subject.clone().unwrap_or_default();
+                    WeeklyGridRow::new(Some(issue.id), subject, days.len())
+                }),
+                None => {
+                    let name = entry
+                        .project
+                        .as_ref()
+                        .map(|p| p.name.clone())
+                        .unwrap_or_else(|| "No Issue".to_string());
+                    projects
+                        .entry(name.clone())
+                        .or_insert_with(|| WeeklyGridRow::new(None, name, days.len()))
+                }
+            };
+            row.hours[day] += entry.hours;
+            row.total += entry.hours;
+            day_totals[day] += entry.hours;
+        }
+
+        Self {
+            total_hours: day_totals.iter().sum(),
+            days,
+            rows: issues.into_values().chain(projects.into_values()).collect(),
+            day_totals,
+        }
+    }
+}
+
+/// Grid cell: hours, or `-` for an empty day.
+fn grid_hours(hours: f64) -> String {
+    if hours == 0.0 {
+        "-".to_string()
+    } else {
+        format!("{:.2}", hours)
+    }
+}
+
+impl MarkdownOutput for WeeklyGrid {
+    fn to_markdown(&self, meta: &Meta) -> String {
+        let mut output = format!(
+            "## Weekly Timesheet ({} to {})\n\n",
+            self.days.first().map(String::as_str).unwrap_or("-"),
+            self.days.last().map(String::as_str).unwrap_or("-")
+        );
+
+        if self.rows.is_empty() {
+            output.push_str("*No time entries found*\n");
+            return output;
+        }
+
+        let mut headers = vec!["Issue".to_string()];
+        headers.extend(self.days.iter().map(|d| {
+            NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                .map(|date| date.format("%a %m-%d").to_string())
+                .unwrap_or_else(|_| d.clone())
+        }));
+        headers.push("Total".to_string());
+        let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+
+        let mut rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let label = match row.issue {
+                    Some(id) if row.label.is_empty() => issue_link(id, meta),
+                    Some(id) => {
+                        format!("{} {}", issue_link(id, meta), truncate_comment(&row.label))
+                    }
+                    None => format!("*{}*", row.label),
+                };
+                let mut cells = vec![label];
+                cells.extend(row.hours.iter().map(|h| grid_hours(*h)));
+                cells.push(format!("{:.2}", row.total));
+                cells
+            })
+            .collect();
+
+        let mut totals = vec!["**Total**".to_string()];
+        totals.extend(self.day_totals.iter().map(|h| grid_hours(*h)));
+        totals.push(format!("**{:.2}**", self.total_hours));
+        rows.push(totals);
+
+        output.push_str(&markdown_table(&headers, rows));
+        output
+    }
+}
+
+/// Hours logged on one day.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeSummaryDay {
+    /// Date (YYYY-MM-DD).
+    pub date: String,
+    pub hours: f64,
+    /// Number of time entries on the day.
+    pub entries: u32,
+}
+
+/// Hours logged in one ISO week.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeSummaryWeek {
+    /// ISO week, e.g. `2024-W03`.
+    pub week: String,
+    /// Monday of the week (YYYY-MM-DD).
+    pub start: String,
+    /// Hours per weekday, Monday first.
+    pub hours: Vec<f64>,
+    pub total: f64,
+}
+
+/// Per-day and per-week totals of a set of time entries.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimeSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    pub days: Vec<TimeSummaryDay>,
+    pub weeks: Vec<TimeSummaryWeek>,
+    pub total_hours: f64,
+    pub total_count: u32,
+}
+
+impl TimeSummary {
+    /// Roll entries up by day, then by week.
+    pub fn from_entries(entries: Vec<TimeEntry>, from: Option<String>, to: Option<String>) -> Self {
+        use chrono::Datelike;
+        use std::collections::BTreeMap;
+
+        let by_day = GroupedTimeEntries::from_entries(entries, &GroupByField::SpentOn);
+        let days: Vec<TimeSummaryDay> = by_day
+            .groups
+            .iter()
+            .map(|group| TimeSummaryDay {
+                date: group.name.clone(),
+                hours: group.subtotal,
+                entries: group.entries.len() as u32,
+            })
+            .collect();
+
+        let mut weeks: BTreeMap<NaiveDate, TimeSummaryWeek> = BTreeMap::new();
+        for day in &days {
+            let Ok(date) = NaiveDate::parse_from_str(&day.date, "%Y-%m-%d") else {
+                continue;
+            };
+            let weekday = date.weekday().num_days_from_monday() as usize;
+            let start = date - chrono::Duration::days(weekday as i64);
+            let week = weeks.entry(start).or_insert_with(|| {
+                let iso = date.iso_week();
+                TimeSummaryWeek {
+                    week: format!("{}-W{:02}", iso.year(), iso.week()),
+                    start: start.format("%Y-%m-%d").to_string(),
+                    hours: vec![0.0; 7],
+                    total: 0.0,
+                }
+            });
+            week.hours[weekday] += day.hours;
+            week.total += day.hours;
+        }
+
+        Self {
+            from,
+            to,
+            days,
+            weeks: weeks.into_values().collect(),
+            total_hours: by_day.total_hours,
+            total_count: by_day.total_count,
+        }
+    }
+}
+
+impl MarkdownOutput for TimeSummary {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = match (&self.from, &self.to) {
+            (Some(from), Some(to)) => format!("## Time Summary ({} to {})\n\n", from, to),
+            (Some(from), None) => format!("## Time Summary (from {})\n\n", from),
+            (None, Some(to)) => format!("## Time Summary (until {})\n\n", to),
+            (None, None) => "## Time Summary\n\n".to_string(),
+        };
+
+        if self.days.is_empty() {
+            output.push_str("*No time entries found*\n");
+            return output;
+        }
+
+        let headers = &[
+            "Week", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun", "Total",
+        ];
+        let rows: Vec<Vec<String>> = self
+            .weeks
+            .iter()
+            .map(|week| {
+                let mut cells = vec![format!("{} ({})", week.week, week.start)];
+                cells.extend(week.hours.iter().map(|h| grid_hours(*h)));
+                cells.push(format!("**{:.2}**", week.total));
+                cells
+            })
+            .collect();
+        output.push_str(&markdown_table(headers, rows));
+
+        output.push_str(&format!(
+            "\n**Grand Total: {:.2} hours ({} entries on {} days)**\n",
+            self.total_hours,
+            self.total_count,
+            self.days.len()
+        ));
+        output
+    }
+}
@@ -27,6 +27,16 @@ pub struct CurrentUser {
     pub created_on: Option<String>,
     #[serde(default)]
     pub last_login_on: Option<String>,
+    /// What the API key may do, filled in by `rdm me --probe`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Capabilities>,
+}
+
+/// Permissions detected for the API key.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Capabilities {
+    pub is_admin: bool,
+    pub can_list_users: bool,
 }
 
 impl CurrentUser {
@@ -57,7 +67,9 @@ impl MarkdownOutput for CurrentUser {
             pairs.push(("Email", mail.clone()));
         }
 
-        if let Some(admin) = self.admin {
+        // `--probe` infers admin rights when the server omits `admin`
+        let admin = self.admin.or(self.capabilities.map(|caps| caps.is_admin));
+        if let Some(admin) = admin {
             pairs.push(("Admin", if admin { "Yes" } else { "No" }.to_string()));
         }
 
@@ -69,6 +81,13 @@ impl MarkdownOutput for CurrentUser {
             pairs.push(("Last Login", last_login.clone()));
         }
 
+        if let Some(caps) = self.capabilities {
+            pairs.push((
+                "Can List Users",
+                if caps.can_list_users { "Yes" } else { "No" }.to_string(),
+            ));
+        }
+
         let pairs_ref: Vec<(&str, String)> = pairs.iter().map(|(k, v)| (*k, v.clone())).collect();
         output.push_str(&markdown_kv_table(&pairs_ref));
 
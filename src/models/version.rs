@@ -0,0 +1,122 @@
+//! Project version (milestone) models.
+
+use super::project::ProjectRef;
+use crate::output::{markdown::markdown_kv_table, MarkdownOutput, Meta};
+use serde::{Deserialize, Serialize};
+
+/// Which projects can use a version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionSharing {
+    /// Only the version's project.
+    None,
+    /// The project and its subprojects.
+    Descendants,
+    /// The project, its ancestors and descendants.
+    Hierarchy,
+    /// Every project in the project tree.
+    Tree,
+    /// All projects (administrators only).
+    System,
+}
+
+impl VersionSharing {
+    /// Accepted values, as sent to the API.
+    pub const VALUES: &'static [&'static str] =
+        &["none", "descendants", "hierarchy", "tree", "system"];
+
+    /// Parse a sharing mode from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "descendants" => Some(Self::Descendants),
+            "hierarchy" => Some(Self::Hierarchy),
+            "tree" => Some(Self::Tree),
+            "system" => Some(Self::System),
+            _ => None,
+        }
+    }
+}
+
+/// Version from Redmine API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub id: u32,
+    pub project: ProjectRef,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub sharing: Option<String>,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub updated_on: Option<String>,
+}
+
+/// Versions available to a project (its own and shared ones).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionList {
+    pub versions: Vec<Version>,
+    #[serde(default)]
+    pub total_count: Option<u32>,
+}
+
+/// Single version response wrapper.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VersionResponse {
+    pub version: Version,
+}
+
+/// New version for creation.
+#[derive(Debug, Serialize)]
+pub struct NewVersion {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing: Option<VersionSharing>,
+}
+
+/// Wrapper for version creation request.
+#[derive(Debug, Serialize)]
+pub struct NewVersionRequest {
+    pub version: NewVersion,
+}
+
+/// Result of version creation.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionCreated {
+    pub version: Version,
+}
+
+impl MarkdownOutput for VersionCreated {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let v = &self.version;
+        let mut output = String::from("## Version Created\n\n");
+
+        let mut pairs = vec![
+            ("ID", v.id.to_string()),
+            ("Name", v.name.clone()),
+            ("Project", v.project.name.clone()),
+        ];
+        if let Some(status) = &v.status {
+            pairs.push(("Status", status.clone()));
+        }
+        if let Some(due) = &v.due_date {
+            pairs.push(("Due Date", due.clone()));
+        }
+        if let Some(sharing) = &v.sharing {
+            pairs.push(("Sharing", sharing.clone()));
+        }
+        output.push_str(&markdown_kv_table(&pairs));
+
+        output
+    }
+}
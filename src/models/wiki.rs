@@ -0,0 +1,179 @@
+//! Wiki page models.
+
+use std::collections::BTreeMap;
+
+use super::user::User;
+use crate::output::{
+    markdown::{markdown_kv_table, markdown_table},
+    MarkdownOutput, Meta,
+};
+use serde::{Deserialize, Serialize};
+
+/// Reference to a wiki page by title.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPageRef {
+    pub title: String,
+}
+
+/// Wiki page entry from a project's wiki index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPageSummary {
+    pub title: String,
+    #[serde(default)]
+    pub parent: Option<WikiPageRef>,
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub updated_on: Option<String>,
+}
+
+/// Wiki page with its content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPage {
+    pub title: String,
+    #[serde(default)]
+    pub parent: Option<WikiPageRef>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub author: Option<User>,
+    #[serde(default)]
+    pub comments: Option<String>,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub updated_on: Option<String>,
+}
+
+/// Single wiki page response wrapper.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WikiPageResponse {
+    pub wiki_page: WikiPage,
+}
+
+/// New content for a wiki page.
+#[derive(Debug, Serialize)]
+pub struct UpdateWikiPage {
+    pub text: String,
+    /// Version comment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+}
+
+/// Wrapper for wiki page update request.
+#[derive(Debug, Serialize)]
+pub struct UpdateWikiPageRequest {
+    pub wiki_page: UpdateWikiPage,
+}
+
+/// Result of wiki page update.
+#[derive(Debug, Clone, Serialize)]
+pub struct WikiPageUpdated {
+    pub project: String,
+    pub title: String,
+    /// Whether the page did not exist before.
+    pub created: bool,
+    /// Version number after the update.
+    pub version: Option<u32>,
+}
+
+impl MarkdownOutput for WikiPageUpdated {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = format!(
+            "## Wiki Page {}\n\n",
+            if self.created { "Created" } else { "Updated" }
+        );
+        let pairs = [
+            ("Project", self.project.clone()),
+            ("Page", self.title.clone()),
+            (
+                "Version",
+                self.version
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+        ];
+        output.push_str(&markdown_kv_table(&pairs));
+        output
+    }
+}
+
+/// Wiki index response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WikiPageList {
+    pub wiki_pages: Vec<WikiPageSummary>,
+}
+
+impl WikiPageList {
+    /// Pages in tree order (children under their parent, siblings by title) with their depth.
+    fn tree(&self) -> Vec<(usize, &WikiPageSummary)> {
+        let titles: Vec<&str> = self.wiki_pages.iter().map(|p| p.title.as_str()).collect();
+        let mut children: BTreeMap<Option<&str>, Vec<&WikiPageSummary>> = BTreeMap::new();
+        for page in &self.wiki_pages {
+            // Pages whose parent is not listed are shown at the top level.
+            let parent = page
+                .parent
+                .as_ref()
+                .map(|p| p.title.as_str())
+                .filter(|t| titles.contains(t));
+            children.entry(parent).or_default().push(page);
+        }
+        for pages in children.values_mut() {
+            pages.sort_by(|a, b| a.title.cmp(&b.title));
+        }
+
+        let mut ordered = Vec::with_capacity(self.wiki_pages.len());
+        let mut stack: Vec<(usize, &WikiPageSummary)> = children
+            .get(&None)
+            .map(|roots| roots.iter().rev().map(|p| (0, *p)).collect())
+            .unwrap_or_default();
+        while let Some((depth, page)) = stack.pop() {
+            // Guard against parent cycles in malformed data.
+            if ordered.len() >= self.wiki_pages.len() {
+                break;
+            }
+            ordered.push((depth, page));
+            if let Some(kids) = children.get(&Some(page.title.as_str())) {
+                stack.extend(kids.iter().rev().map(|p| (depth + 1, *p)));
+            }
+        }
+        ordered
+    }
+}
+
+impl MarkdownOutput for WikiPageList {
+    fn to_markdown(&self, _meta: &Meta) -> String {
+        let mut output = format!("## Wiki Pages ({})\n\n", self.wiki_pages.len());
+
+        if self.wiki_pages.is_empty() {
+            output.push_str("*No wiki pages found*\n");
+            return output;
+        }
+
+        let headers = &["Title", "Parent", "Version", "Updated"];
+        let rows: Vec<Vec<String>> = self
+            .tree()
+            .into_iter()
+            .map(|(depth, page)| {
+                vec![
+                    format!("{}{}", "↳ ".repeat(depth), page.title),
+                    page.parent
+                        .as_ref()
+                        .map(|p| p.title.clone())
+                        .unwrap_or_else(|| "-".to_string()),
+                    page.version
+                        .map(|v| v.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    page.updated_on.clone().unwrap_or_else(|| "-".to_string()),
+                ]
+            })
+            .collect();
+        output.push_str(&markdown_table(headers, rows));
+
+        output
+    }
+}
@@ -0,0 +1,86 @@
+//! CSV rendering for list results (`--format csv`).
+
+/// Trait for list results with a fixed CSV layout.
+pub trait CsvOutput {
+    /// Column names, in output order.
+    fn csv_header(&self) -> Vec<String>;
+
+    /// One row per item, with cells in header order.
+    fn csv_rows(&self) -> Vec<Vec<String>>;
+
+    /// Render the header and rows as RFC 4180 CSV.
+    ///
+    /// The header is printed even when there are no rows, and every line
+    /// ends with CRLF.
+    fn to_csv(&self) -> String {
+        let mut output = csv_line(&self.csv_header());
+        for row in self.csv_rows() {
+            output.push_str(&csv_line(&row));
+        }
+        output
+    }
+}
+
+/// Build a header from fixed column names.
+pub fn csv_columns(columns: &[&str]) -> Vec<String> {
+    columns.iter().map(|c| c.to_string()).collect()
+}
+
+/// An optional value as a cell, empty when missing.
+pub fn csv_opt<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Join cells into one CSV record, terminated by CRLF.
+pub fn csv_line<S: AsRef<str>>(cells: &[S]) -> String {
+    let mut line = cells
+        .iter()
+        .map(|c| csv_field(c.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// Quote a CSV field when it contains a delimiter, quote or line break.
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rows(Vec<(u32, &'static str)>);
+
+    impl CsvOutput for Rows {
+        fn csv_header(&self) -> Vec<String> {
+            csv_columns(&["id", "name"])
+        }
+
+        fn csv_rows(&self) -> Vec<Vec<String>> {
+            self.0
+                .iter()
+                .map(|(id, name)| vec![id.to_string(), name.to_string()])
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_csv_quotes_and_crlf() {
+        let rows = Rows(vec![(1, "plain"), (2, "a, \"quoted\"\nname")]);
+        assert_eq!(
+            rows.to_csv(),
+            "id,name\r\n1,plain\r\n2,\"a, \"\"quoted\"\"\nname\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_empty_still_has_header() {
+        assert_eq!(Rows(vec![]).to_csv(), "id,name\r\n");
+    }
+}
@@ -66,12 +66,30 @@ pub struct Meta {
     /// Next offset for pagination (if more results exist).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_offset: Option<u32>,
+    /// Sum of the hours listed (time entry lists).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_hours: Option<f64>,
+    /// Server URL that Markdown ids link to (`--links`); never serialized.
+    #[serde(skip)]
+    pub link_base: Option<String>,
+    /// Color Markdown table cells with ANSI codes (`--color`); never serialized.
+    #[serde(skip)]
+    pub color: bool,
+    /// Show an issue description verbatim in a code block
+    /// (`issue get --plain-description`); never serialized.
+    #[serde(skip)]
+    pub plain_description: bool,
+    /// Show every issue journal with its field changes
+    /// (`issue get --include-journals`); never serialized.
+    #[serde(skip)]
+    pub show_history: bool,
 }
 
 impl Meta {
     /// Create metadata for a paginated response.
     pub fn paginated(total_count: u32, limit: u32, offset: u32) -> Self {
-        let next_offset = if offset + limit < total_count {
+        // A zero limit (server default) gives no usable next page
+        let next_offset = if limit > 0 && offset + limit < total_count {
             Some(offset + limit)
         } else {
             None
@@ -81,6 +99,59 @@ impl Meta {
             limit: Some(limit),
             offset: Some(offset),
             next_offset,
+            total_hours: None,
+            link_base: None,
+            color: false,
+            plain_description: false,
+            show_history: false,
+        }
+    }
+
+    /// Report the total hours of a time entry listing.
+    pub fn with_total_hours(mut self, hours: f64) -> Self {
+        self.total_hours = Some(hours);
+        self
+    }
+
+    /// Link ids in Markdown output to pages under `base`.
+    pub fn with_links(mut self, base: Option<&str>) -> Self {
+        self.link_base = base.map(|b| b.trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Color table cells for a terminal.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Show issue descriptions verbatim instead of as Markdown.
+    pub fn with_plain_description(mut self, plain: bool) -> Self {
+        self.plain_description = plain;
+        self
+    }
+
+    /// Show the full issue history instead of only the comments.
+    pub fn with_history(mut self, history: bool) -> Self {
+        self.show_history = history;
+        self
+    }
+
+    /// Wrap `text` in the ANSI `style` (e.g. [`ANSI_RED`](super::markdown::ANSI_RED)),
+    /// or plain `text` without color.
+    pub fn paint(&self, text: &str, style: &str) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", style, text)
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Markdown link from `text` to `path` on the server, or plain `text` without `--links`.
+    pub fn link(&self, text: &str, path: &str) -> String {
+        match &self.link_base {
+            Some(base) => format!("[{}]({}{})", text, base, path),
+            None => text.to_string(),
         }
     }
 }
@@ -168,6 +239,25 @@ mod tests {
         assert_eq!(meta.next_offset, None);
     }
 
+    #[test]
+    fn test_meta_pagination_zero_limit() {
+        let meta = Meta::paginated(100, 0, 0);
+        assert_eq!(meta.next_offset, None);
+    }
+
+    #[test]
+    fn test_meta_links() {
+        let meta = Meta::default();
+        assert_eq!(meta.link("#1", "/issues/1"), "#1");
+
+        let meta = meta.with_links(Some("https://redmine.example.com/"));
+        assert_eq!(
+            meta.link("#1", "/issues/1"),
+            "[#1](https://redmine.example.com/issues/1)"
+        );
+        assert!(!serde_json::to_string(&meta).unwrap().contains("example"));
+    }
+
     #[test]
     fn test_envelope_json_serialization() {
         let envelope =
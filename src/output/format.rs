@@ -2,7 +2,10 @@
 
 use clap::ValueEnum;
 use serde::Serialize;
+use serde_json::{Map, Value};
+use std::io::IsTerminal;
 
+use super::csv::{csv_line, CsvOutput};
 use super::markdown::format_error_markdown;
 use super::{Envelope, ErrorInfo, Meta};
 use crate::error::AppError;
@@ -15,13 +18,59 @@ pub enum OutputFormat {
     Markdown,
     /// JSON output (envelope format for programmatic pipelines).
     Json,
+    /// Bare JSON: the item array of list results (or the data object), no envelope.
+    JsonArray,
+    /// Newline-delimited JSON: one object per line, no envelope (for streaming).
+    Ndjson,
+    /// Comma-separated values: one row per item, nested fields as dotted columns.
+    Csv,
+    /// YAML output (same envelope as JSON).
+    Yaml,
 }
 
+/// When to color Markdown output.
+#[derive(Debug, Clone, Copy, Default, ValueEnum, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal and `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always color Markdown output.
+    Always,
+    /// Never color.
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to emit ANSI codes; only Markdown output is ever colored.
+    pub fn enabled(self, format: OutputFormat) -> bool {
+        if format != OutputFormat::Markdown {
+            return false;
+        }
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+        }
+    }
+}
+
+/// Fields that may accompany the item array of a list response.
+const LIST_META_FIELDS: &[&str] = &["total_count", "offset", "limit", "next_offset"];
+
 /// Trait for outputting results in the selected format.
 pub trait Format {
     /// Format success output.
     fn format_success<T: Serialize + super::MarkdownOutput>(&self, data: T, meta: Meta) -> String;
 
+    /// Format a list result, using its fixed CSV layout for `--format csv`.
+    fn format_list<T: Serialize + super::MarkdownOutput + CsvOutput>(
+        &self,
+        data: T,
+        meta: Meta,
+    ) -> String;
+
     /// Format error output.
     fn format_error(&self, error: &AppError) -> String;
 }
@@ -39,13 +88,54 @@ impl Format for OutputFormat {
                     )
                 })
             }
+            OutputFormat::JsonArray => match serde_json::to_value(&data) {
+                Ok(value) => {
+                    let bare = match value {
+                        Value::Object(map) if is_list_response(&map) => {
+                            Value::Array(list_items(Value::Object(map)))
+                        }
+                        other => other,
+                    };
+                    serde_json::to_string_pretty(&bare).unwrap_or_default()
+                }
+                Err(e) => format!(
+                    "{{\"ok\":false,\"error\":{{\"code\":\"JSON_ERROR\",\"message\":\"{}\"}}}}",
+                    e
+                ),
+            },
+            OutputFormat::Ndjson => match serde_json::to_value(&data) {
+                Ok(value) => ndjson_lines(value),
+                Err(e) => format!(
+                    "{{\"ok\":false,\"error\":{{\"code\":\"JSON_ERROR\",\"message\":\"{}\"}}}}",
+                    e
+                ),
+            },
+            OutputFormat::Csv => match serde_json::to_value(&data) {
+                Ok(value) => csv_rows(value),
+                Err(e) => format!(
+                    "{{\"ok\":false,\"error\":{{\"code\":\"JSON_ERROR\",\"message\":\"{}\"}}}}",
+                    e
+                ),
+            },
+            OutputFormat::Yaml => to_yaml(&Envelope::success_with_meta(data, meta)),
+        }
+    }
+
+    fn format_list<T: Serialize + super::MarkdownOutput + CsvOutput>(
+        &self,
+        data: T,
+        meta: Meta,
+    ) -> String {
+        match self {
+            OutputFormat::Csv => data.to_csv(),
+            _ => self.format_success(data, meta),
         }
     }
 
     fn format_error(&self, error: &AppError) -> String {
         match self {
             OutputFormat::Markdown => format_error_markdown(error),
-            OutputFormat::Json => {
+            OutputFormat::Json | OutputFormat::JsonArray => {
                 let envelope: Envelope<()> = Envelope::<()>::error(ErrorInfo::from(error));
                 serde_json::to_string_pretty(&envelope).unwrap_or_else(|e| {
                     format!(
@@ -54,10 +144,124 @@ impl Format for OutputFormat {
                     )
                 })
             }
+            OutputFormat::Yaml => to_yaml(&Envelope::<()>::error(ErrorInfo::from(error))),
+            OutputFormat::Ndjson | OutputFormat::Csv => {
+                let envelope: Envelope<()> = Envelope::<()>::error(ErrorInfo::from(error));
+                serde_json::to_string(&envelope).unwrap_or_else(|e| {
+                    format!(
+                        "{{\"ok\":false,\"error\":{{\"code\":\"JSON_ERROR\",\"message\":\"{}\"}}}}",
+                        e
+                    )
+                })
+            }
+        }
+    }
+}
+
+/// Serialize an envelope as YAML, without the trailing newline.
+fn to_yaml<T: Serialize>(envelope: &Envelope<T>) -> String {
+    match serde_norway::to_string(envelope) {
+        Ok(yaml) => yaml.trim_end().to_string(),
+        Err(e) => format!(
+            "ok: false\nerror:\n  code: YAML_ERROR\n  message: {:?}",
+            e.to_string()
+        ),
+    }
+}
+
+/// Render a value as NDJSON.
+///
+/// List responses (a single item array plus pagination fields) become one
+/// line per item; anything else is emitted as a single line.
+fn ndjson_lines(value: Value) -> String {
+    list_items(value)
+        .iter()
+        .map(|item| item.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render a value without a [`CsvOutput`] layout as CSV with a header row.
+///
+/// Rows are the items of a list response (or the value itself). Nested
+/// objects become dotted columns such as `issue.subject`; arrays are written
+/// as JSON.
+fn csv_rows(value: Value) -> String {
+    let rows: Vec<Vec<(String, String)>> = list_items(value)
+        .into_iter()
+        .map(|item| {
+            let mut row = Vec::new();
+            flatten_csv("", item, &mut row);
+            row
+        })
+        .collect();
+
+    // Columns in first-seen order, so rows missing optional fields still line up
+    let mut columns: Vec<&str> = Vec::new();
+    for (key, _) in rows.iter().flatten() {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+
+    let mut output = csv_line(&columns);
+    for row in &rows {
+        let cells: Vec<&str> = columns
+            .iter()
+            .map(|column| {
+                row.iter()
+                    .find(|(key, _)| key == column)
+                    .map(|(_, value)| value.as_str())
+                    .unwrap_or_default()
+            })
+            .collect();
+        output.push_str(&csv_line(&cells));
+    }
+    output
+}
+
+fn flatten_csv(prefix: &str, value: Value, row: &mut Vec<(String, String)>) {
+    let column = if prefix.is_empty() { "value" } else { prefix };
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_csv(&key, value, row);
+            }
         }
+        Value::Null => row.push((column.to_string(), String::new())),
+        Value::String(s) => row.push((column.to_string(), s)),
+        other => row.push((column.to_string(), other.to_string())),
+    }
+}
+
+/// The items of a list response, or the value itself as a single item.
+fn list_items(value: Value) -> Vec<Value> {
+    match value {
+        Value::Array(items) => items,
+        Value::Object(map) if is_list_response(&map) => map
+            .into_iter()
+            .find_map(|(_, v)| match v {
+                Value::Array(items) => Some(items),
+                _ => None,
+            })
+            .unwrap_or_default(),
+        other => vec![other],
     }
 }
 
+/// Whether an object is a list response: exactly one array plus pagination fields.
+fn is_list_response(map: &Map<String, Value>) -> bool {
+    map.values().filter(|v| v.is_array()).count() == 1
+        && map
+            .iter()
+            .all(|(k, v)| v.is_array() || LIST_META_FIELDS.contains(&k.as_str()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +278,18 @@ mod tests {
         }
     }
 
+    #[derive(Serialize)]
+    struct TestList {
+        items: Vec<TestData>,
+        total_count: u32,
+    }
+
+    impl super::super::MarkdownOutput for TestList {
+        fn to_markdown(&self, _meta: &Meta) -> String {
+            format!("## Test list ({})", self.total_count)
+        }
+    }
+
     #[test]
     fn test_markdown_format() {
         let format = OutputFormat::Markdown;
@@ -99,6 +315,78 @@ mod tests {
         assert!(output.contains("\"name\": \"test\""));
     }
 
+    #[test]
+    fn test_ndjson_list_one_line_per_item() {
+        let format = OutputFormat::Ndjson;
+        let data = TestList {
+            items: vec![
+                TestData {
+                    id: 1,
+                    name: "a".to_string(),
+                },
+                TestData {
+                    id: 2,
+                    name: "b".to_string(),
+                },
+            ],
+            total_count: 2,
+        };
+        let output = format.format_success(data, Meta::default());
+        assert_eq!(
+            output,
+            "{\"id\":1,\"name\":\"a\"}\n{\"id\":2,\"name\":\"b\"}"
+        );
+    }
+
+    #[test]
+    fn test_csv_list_flattens_and_quotes() {
+        let format = OutputFormat::Csv;
+        let data = TestList {
+            items: vec![
+                TestData {
+                    id: 1,
+                    name: "plain".to_string(),
+                },
+                TestData {
+                    id: 2,
+                    name: "a, \"quoted\" name".to_string(),
+                },
+            ],
+            total_count: 2,
+        };
+        let output = format.format_success(data, Meta::default());
+        assert_eq!(
+            output,
+            "id,name\r\n1,plain\r\n2,\"a, \"\"quoted\"\" name\"\r\n"
+        );
+    }
+
+    #[test]
+    fn test_json_array_list_is_bare() {
+        let format = OutputFormat::JsonArray;
+        let data = TestList {
+            items: vec![TestData {
+                id: 1,
+                name: "a".to_string(),
+            }],
+            total_count: 1,
+        };
+        let output = format.format_success(data, Meta::default());
+        let value: Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value, serde_json::json!([{"id": 1, "name": "a"}]));
+    }
+
+    #[test]
+    fn test_ndjson_single_object() {
+        let format = OutputFormat::Ndjson;
+        let data = TestData {
+            id: 1,
+            name: "test".to_string(),
+        };
+        let output = format.format_success(data, Meta::default());
+        assert_eq!(output, "{\"id\":1,\"name\":\"test\"}");
+    }
+
     #[test]
     fn test_json_error_format() {
         let format = OutputFormat::Json;
@@ -107,4 +395,12 @@ mod tests {
         assert!(output.contains("\"ok\": false"));
         assert!(output.contains("NOT_FOUND"));
     }
+
+    #[test]
+    fn test_color_only_for_markdown() {
+        assert!(ColorChoice::Always.enabled(OutputFormat::Markdown));
+        assert!(!ColorChoice::Always.enabled(OutputFormat::Json));
+        assert!(!ColorChoice::Always.enabled(OutputFormat::Csv));
+        assert!(!ColorChoice::Never.enabled(OutputFormat::Markdown));
+    }
 }
@@ -35,6 +35,45 @@ pub fn format_error_info_markdown(error: &ErrorInfo) -> String {
     output
 }
 
+/// Demote every heading by `offset` levels (capped at `######`), leaving code fences untouched.
+pub fn shift_headings(markdown: &str, offset: u8) -> String {
+    if offset == 0 {
+        return markdown.to_string();
+    }
+
+    let mut in_fence = false;
+    let lines: Vec<String> = markdown
+        .split('\n')
+        .map(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                return line.to_string();
+            }
+            let level = line.chars().take_while(|c| *c == '#').count();
+            let is_heading = (1..=6).contains(&level) && line[level..].starts_with(' ');
+            if in_fence || !is_heading {
+                return line.to_string();
+            }
+            let new_level = (level + usize::from(offset)).min(6);
+            format!("{}{}", "#".repeat(new_level), &line[level..])
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Note for grouped output built from only part of the matching results.
+///
+/// Returns `None` when every match (`meta.total_count`) was fetched.
+pub fn partial_grouping_note(fetched: u32, meta: &Meta, hint: &str) -> Option<String> {
+    let total = meta.total_count?;
+    (fetched < total).then(|| {
+        format!(
+            "> **Note**: grouping over {} of {} entries; {}.\n\n",
+            fetched, total, hint
+        )
+    })
+}
+
 /// Helper to create a Markdown table from headers and rows.
 pub fn markdown_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
     let mut output = String::new();
@@ -65,6 +104,12 @@ pub fn markdown_table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
     output
 }
 
+/// ANSI style for de-emphasized cells (closed statuses).
+pub const ANSI_DIM: &str = "2";
+
+/// ANSI style for cells that need attention (high priorities).
+pub const ANSI_RED: &str = "31";
+
 /// Helper to create a key-value Markdown table.
 pub fn markdown_kv_table(pairs: &[(&str, String)]) -> String {
     let mut output = String::new();
@@ -76,8 +121,51 @@ pub fn markdown_kv_table(pairs: &[(&str, String)]) -> String {
     output
 }
 
+/// Wrap text in a fenced code block so it is shown verbatim; the fence is
+/// longer than any backtick run inside the text.
+pub fn markdown_code_block(text: &str) -> String {
+    let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat((longest_run + 1).max(3));
+    format!("{}\n{}\n{}\n", fence, text.trim_end_matches('\n'), fence)
+}
+
 /// Helper to add a pagination hint.
 pub fn pagination_hint(command: &str, meta: &Meta) -> Option<String> {
     meta.next_offset
         .map(|next| format!("*Use `{}--offset {}` for next page*", command, next))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_headings() {
+        let markdown = "## Issues\n\n```\n# not a heading\n```\n#### Deep\n#hashtag";
+        assert_eq!(
+            shift_headings(markdown, 1),
+            "### Issues\n\n```\n# not a heading\n```\n##### Deep\n#hashtag"
+        );
+        assert_eq!(shift_headings("##### Deep", 3), "###### Deep");
+    }
+
+    #[test]
+    fn test_markdown_code_block() {
+        assert_eq!(markdown_code_block("h1. Title\n"), "```\nh1. Title\n```\n");
+        assert_eq!(
+            markdown_code_block("see ```code```"),
+            "````\nsee ```code```\n````\n"
+        );
+    }
+
+    #[test]
+    fn test_partial_grouping_note() {
+        let meta = Meta {
+            total_count: Some(40),
+            ..Default::default()
+        };
+        let note = partial_grouping_note(25, &meta, "use --all").unwrap();
+        assert!(note.contains("grouping over 25 of 40 entries"));
+        assert!(partial_grouping_note(40, &meta, "use --all").is_none());
+    }
+}
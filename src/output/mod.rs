@@ -1,9 +1,11 @@
 //! Output formatting module - Markdown default, JSON envelope available.
 
+pub mod csv;
 mod envelope;
 mod format;
 pub mod markdown;
 
+pub use csv::CsvOutput;
 pub use envelope::{Envelope, ErrorInfo, Meta};
-pub use format::{Format, OutputFormat};
+pub use format::{ColorChoice, Format, OutputFormat};
 pub use markdown::MarkdownOutput;
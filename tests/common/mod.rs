@@ -27,6 +27,13 @@ pub fn mock_current_user() -> Mock {
         })))
 }
 
+/// Create a mock that rejects the current user endpoint with 401.
+pub fn mock_current_user_unauthorized() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/users/current.json"))
+        .respond_with(ResponseTemplate::new(401))
+}
+
 /// Create a mock for the activities endpoint.
 pub fn mock_activities() -> Mock {
     Mock::given(method("GET"))
@@ -110,27 +117,6 @@ pub fn mock_issues_list() -> Mock {
         })))
 }
 
-/// Create a mock for getting a single issue.
-pub fn mock_issue_get() -> Mock {
-    Mock::given(method("GET"))
-        .and(path_regex(r"/issues/\d+\.json"))
-        .and(header("X-Redmine-API-Key", "test-api-key"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "issue": {
-                "id": 123,
-                "subject": "Test Issue",
-                "description": "This is a test issue",
-                "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
-                "status": {"id": 1, "name": "New"},
-                "priority": {"id": 2, "name": "Normal"},
-                "tracker": {"id": 1, "name": "Bug"},
-                "author": {"id": 1, "name": "Test User"},
-                "created_on": "2024-01-01T00:00:00Z",
-                "updated_on": "2024-01-15T12:00:00Z"
-            }
-        })))
-}
-
 /// Create a mock for time entries list endpoint.
 pub fn mock_time_entries_list() -> Mock {
     Mock::given(method("GET"))
@@ -155,60 +141,3 @@ pub fn mock_time_entries_list() -> Mock {
             "limit": 25
         })))
 }
-
-/// Create a mock for getting a single time entry.
-pub fn mock_time_entry_get() -> Mock {
-    Mock::given(method("GET"))
-        .and(path_regex(r"/time_entries/\d+\.json"))
-        .and(header("X-Redmine-API-Key", "test-api-key"))
-        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "time_entry": {
-                "id": 456,
-                "hours": 2.5,
-                "comments": "Test comment",
-                "spent_on": "2024-01-15",
-                "activity": {"id": 1, "name": "Development"},
-                "user": {"id": 1, "name": "Test User"},
-                "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
-                "issue": {"id": 123},
-                "created_on": "2024-01-15T12:00:00Z",
-                "updated_on": "2024-01-15T12:00:00Z"
-            }
-        })))
-}
-
-/// Create a mock for creating a time entry.
-pub fn mock_time_entry_create() -> Mock {
-    Mock::given(method("POST"))
-        .and(path("/time_entries.json"))
-        .and(header("X-Redmine-API-Key", "test-api-key"))
-        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
-            "time_entry": {
-                "id": 789,
-                "hours": 1.5,
-                "comments": "New entry",
-                "spent_on": "2024-01-16",
-                "activity": {"id": 1, "name": "Development"},
-                "user": {"id": 1, "name": "Test User"},
-                "issue": {"id": 123},
-                "created_on": "2024-01-16T12:00:00Z",
-                "updated_on": "2024-01-16T12:00:00Z"
-            }
-        })))
-}
-
-/// Create a mock for updating a time entry (PUT returns no body, then we GET).
-pub fn mock_time_entry_update() -> Mock {
-    Mock::given(method("PUT"))
-        .and(path_regex(r"/time_entries/\d+\.json"))
-        .and(header("X-Redmine-API-Key", "test-api-key"))
-        .respond_with(ResponseTemplate::new(200))
-}
-
-/// Create a mock for deleting a time entry.
-pub fn mock_time_entry_delete() -> Mock {
-    Mock::given(method("DELETE"))
-        .and(path_regex(r"/time_entries/\d+\.json"))
-        .and(header("X-Redmine-API-Key", "test-api-key"))
-        .respond_with(ResponseTemplate::new(200))
-}
@@ -1,4 +1,4 @@
-//! Golden JSON output tests.
+//! Golden JSON (and YAML) output tests.
 //!
 //! These tests verify that the JSON output schema remains stable across changes.
 //! They test the envelope structure: `{"ok": true/false, "data": {...}, "meta": {...}, "error": {...}}`
@@ -416,6 +416,46 @@ async fn golden_time_list_json_data_structure() {
     );
 }
 
+#[tokio::test]
+async fn golden_time_list_json_meta_total_hours() {
+    let server = start_mock_server().await;
+    let entry = |id: u32, hours: f64| {
+        serde_json::json!({
+            "id": id,
+            "hours": hours,
+            "spent_on": "2024-01-15",
+            "activity": {"id": 1, "name": "Development"},
+            "user": {"id": 1, "name": "Test User"}
+        })
+    };
+    wiremock::Mock::given(wiremock::matchers::path("/time_entries.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time_entries": [entry(1, 2.5), entry(2, 1.25)],
+                "total_count": 2,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    // Plain and grouped listings report the same sum of hours
+    for extra in [vec![], vec!["--group-by", "activity"]] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["--format", "json"])
+            .args(["time", "list"])
+            .args(extra);
+
+        let (success, json) = run_json_command(&mut cmd);
+        assert!(success, "Command should succeed");
+        assert_eq!(json["meta"]["total_hours"].as_f64().unwrap(), 3.75);
+    }
+}
+
 #[tokio::test]
 async fn golden_time_list_json_item_fields() {
     let server = start_mock_server().await;
@@ -700,4 +740,126 @@ async fn golden_ping_json_data_fields() {
     // Ping should return connection status
     assert!(data["status"].is_string(), "data.status should be a string");
     assert_eq!(data["status"].as_str().unwrap(), "ok");
+    assert!(
+        data["latency_ms"].is_number(),
+        "data.latency_ms should be a number"
+    );
+    assert!(
+        data.get("server_version").is_none(),
+        "server_version is omitted when unknown"
+    );
+}
+
+#[tokio::test]
+async fn golden_ping_json_server_version() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "--api-version", "5.1"])
+        .arg("ping");
+
+    let (_, json) = run_json_command(&mut cmd);
+    assert_eq!(json["data"]["server_version"].as_str().unwrap(), "5.1");
+}
+
+// ============================================================================
+// Golden Tests: Dry Run
+// ============================================================================
+
+#[test]
+fn golden_dry_run_issue_create_json() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--format", "json", "--dry-run"])
+        .args(["issue", "create", "--project", "1", "--subject", "Draft"]);
+
+    let (success, json) = run_json_command(&mut cmd);
+    assert!(success, "Dry run should exit 0");
+    assert_success_envelope(&json);
+
+    let data = &json["data"];
+    assert_eq!(data["method"].as_str().unwrap(), "POST");
+    assert_eq!(data["path"].as_str().unwrap(), "/issues.json");
+    assert_eq!(
+        data["payload"]["issue"]["subject"].as_str().unwrap(),
+        "Draft"
+    );
+}
+
+#[test]
+fn golden_dry_run_time_delete_json() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--format", "json", "--dry-run"])
+        .args(["time", "delete", "--id", "42"]);
+
+    let (success, json) = run_json_command(&mut cmd);
+    assert!(success, "Dry run should exit 0");
+    assert_success_envelope(&json);
+
+    let data = &json["data"];
+    assert_eq!(data["method"].as_str().unwrap(), "DELETE");
+    assert_eq!(data["path"].as_str().unwrap(), "/time_entries/42.json");
+    assert!(data.get("payload").is_none(), "DELETE has no payload");
+}
+
+// ============================================================================
+// Golden Tests: YAML Envelope
+// ============================================================================
+
+/// Helper to run a command and parse YAML output into the JSON value model.
+fn run_yaml_command(cmd: &mut Command, stderr: bool) -> (bool, Value) {
+    let output = cmd.output().expect("Failed to execute command");
+    let text = String::from_utf8_lossy(if stderr {
+        &output.stderr
+    } else {
+        &output.stdout
+    });
+    let yaml: Value = serde_norway::from_str(&text)
+        .unwrap_or_else(|e| panic!("Failed to parse YAML: {}\nOutput: {}", e, text));
+    (output.status.success(), yaml)
+}
+
+#[tokio::test]
+async fn golden_me_yaml_envelope_structure() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "yaml"])
+        .arg("me");
+
+    let (success, yaml) = run_yaml_command(&mut cmd, false);
+    assert!(success, "Command should succeed");
+    assert_success_envelope(&yaml);
+    assert!(yaml["data"]["id"].is_number(), "data.id should be a number");
+}
+
+#[tokio::test]
+async fn golden_yaml_error_envelope() {
+    let server = start_mock_server().await;
+    mock_current_user_unauthorized().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "wrong"])
+        .args(["--format", "yaml"])
+        .arg("me");
+
+    let (success, yaml) = run_yaml_command(&mut cmd, true);
+    assert!(!success, "Command should fail");
+    assert_error_envelope(&yaml);
 }
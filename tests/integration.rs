@@ -1,9 +1,11 @@
 //! Integration tests for the rdm CLI.
 
 mod common;
+mod mocks;
 
 use assert_cmd::Command;
 use common::*;
+use mocks::*;
 use predicates::prelude::*;
 
 fn get_binary() -> Command {
@@ -31,6 +33,120 @@ async fn test_project_list() {
         .stdout(predicate::str::contains("Test Project"));
 }
 
+#[tokio::test]
+async fn test_project_list_name_contains_all() {
+    let server = start_mock_server().await;
+    mock_projects_list_all().expect(1).mount(&server).await;
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", cache_dir.path())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["project", "list", "--all", "--name-contains", "BACK"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("matching \"BACK\" (1 of 2)"))
+        .stdout(predicate::str::contains("Backend Services"))
+        .stdout(predicate::str::contains("Website").not());
+}
+
+#[tokio::test]
+async fn test_project_list_all_is_cached() {
+    let server = start_mock_server().await;
+    // The second run is served from the cache; --refresh fetches again
+    mock_projects_list_all().expect(2).mount(&server).await;
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    for extra in [&[][..], &[][..], &["--refresh"][..]] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", cache_dir.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["project", "list", "--all"])
+            .args(extra);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Backend Services"));
+    }
+    assert!(cache_dir.path().join("projects.json").exists());
+}
+
+#[tokio::test]
+async fn test_project_list_all_no_cache_skips_cache() {
+    let server = start_mock_server().await;
+    mock_projects_list_all().expect(2).mount(&server).await;
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    for _ in 0..2 {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", cache_dir.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["--no-cache", "project", "list", "--all"]);
+
+        cmd.assert().success();
+    }
+    assert!(!cache_dir.path().join("projects.json").exists());
+}
+
+#[test]
+fn test_cache_status_reports_age_and_validity() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let write = |file: &str, updated_at: u64| {
+        let body = serde_json::json!({"updated_at": updated_at, "items": [{"id": 1}, {"id": 2}]});
+        std::fs::write(cache_dir.path().join(file), body.to_string()).unwrap();
+    };
+    write("statuses.json", now - 2 * 3600);
+    write("projects.json", now - 48 * 3600);
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CACHE_DIR", cache_dir.path())
+        .args(["cache", "status"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "| statuses.json | 2 | 2h ago | Yes |",
+        ))
+        .stdout(predicate::str::contains(
+            "| projects.json | 2 | 48h ago | Expired |",
+        ))
+        .stdout(predicate::str::contains("trackers.json").not());
+}
+
+#[test]
+fn test_cache_clear_removes_cached_files() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    std::fs::write(cache_dir.path().join("projects.json"), "{}").unwrap();
+    std::fs::create_dir(cache_dir.path().join("responses")).unwrap();
+    std::fs::write(cache_dir.path().join("responses").join("a.json"), "{}").unwrap();
+    // The cache directory may be shared; files rdm did not write are kept
+    std::fs::write(cache_dir.path().join("notes.txt"), "keep").unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CACHE_DIR", cache_dir.path())
+        .args(["cache", "clear"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 2 items"));
+    let left: Vec<_> = std::fs::read_dir(cache_dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    assert_eq!(left, ["notes.txt"]);
+}
+
 #[tokio::test]
 async fn test_project_list_json() {
     let server = start_mock_server().await;
@@ -68,264 +184,4720 @@ async fn test_project_get() {
         .stdout(predicate::str::contains("Test Project"));
 }
 
-// ============================================================================
-// Issue Commands
-// ============================================================================
-
 #[tokio::test]
-async fn test_issue_list() {
+async fn test_project_get_strict_reports_unknown_fields() {
     let server = start_mock_server().await;
-    mock_issues_list().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/test-project.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project": {
+                    "id": 1,
+                    "name": "Test Project",
+                    "identifier": "test-project",
+                    "inherit_members": false,
+                    "parent": {"id": 42, "name": "Parent Project", "identifier": "parent"}
+                }
+            })),
+        )
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("issue")
-        .arg("list");
+        .args(["--strict", "project", "get", "--identifier", "test-project"]);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("Test Issue"))
-        .stdout(predicate::str::contains("123"));
+        .stdout(predicate::str::contains("Test Project"))
+        .stderr(predicate::str::contains(
+            "unknown field in response: project.inherit_members",
+        ))
+        .stderr(predicate::str::contains(
+            "unknown field in response: project.parent.identifier",
+        ));
 }
 
 #[tokio::test]
-async fn test_issue_list_json() {
+async fn test_project_get_with_activity() {
     let server = start_mock_server().await;
+    mock_project_get().mount(&server).await;
     mock_issues_list().mount(&server).await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .args(["--format", "json"])
-        .arg("issue")
-        .arg("list");
+        .args([
+            "project",
+            "get",
+            "--identifier",
+            "test-project",
+            "--with-activity",
+        ]);
 
-    cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("\"ok\": true"))
-        .stdout(predicate::str::contains("\"subject\": \"Test Issue\""));
+    cmd.assert().success().stdout(predicate::str::contains(
+        "| Last activity | 2024-01-15T12:00:00Z on #123 |",
+    ));
 }
 
 #[tokio::test]
-async fn test_issue_get() {
+async fn test_project_get_with_activity_forbidden() {
     let server = start_mock_server().await;
-    mock_issue_get().mount(&server).await;
+    mock_project_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(wiremock::ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("issue")
-        .arg("get")
-        .args(["--id", "123"]);
+        .args([
+            "project",
+            "get",
+            "--identifier",
+            "test-project",
+            "--with-activity",
+        ]);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("Test Issue"))
-        .stdout(predicate::str::contains("#123"));
+        .stdout(predicate::str::contains("Unavailable (no permission"));
 }
 
-// ============================================================================
-// Time Entry Commands
-// ============================================================================
-
 #[tokio::test]
-async fn test_time_list() {
+async fn test_project_close() {
     let server = start_mock_server().await;
-    mock_time_entries_list().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/close.json",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/test-project.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project": {"id": 1, "name": "Test Project", "identifier": "test-project", "status": 5}
+            })),
+        )
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("time")
-        .arg("list");
+        .args(["project", "close", "--project", "test-project"]);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("456"))
-        .stdout(predicate::str::contains("2.50"))
-        .stdout(predicate::str::contains("Development"));
+        .stdout(predicate::str::contains("## Project Closed"))
+        .stdout(predicate::str::contains("is now **Closed**"));
 }
 
 #[tokio::test]
-async fn test_time_list_json() {
+async fn test_project_archive_unconfirmed_when_hidden() {
     let server = start_mock_server().await;
-    mock_time_entries_list().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/archive.json",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/test-project.json"))
+        .respond_with(wiremock::ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .args(["--format", "json"])
-        .arg("time")
-        .arg("list");
+        .args([
+            "--format",
+            "json",
+            "project",
+            "archive",
+            "--project",
+            "test-project",
+        ]);
 
-    cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("\"ok\": true"))
-        .stdout(predicate::str::contains("\"hours\": 2.5"));
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["status_name"], "Archived");
+    assert_eq!(json["data"]["confirmed"], false);
 }
 
 #[tokio::test]
-async fn test_time_get() {
+async fn test_project_close_confirmation_error_is_reported() {
     let server = start_mock_server().await;
-    mock_time_entry_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/close.json",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/test-project.json"))
+        .respond_with(wiremock::ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("time")
-        .arg("get")
-        .args(["--id", "456"]);
+        .args(["project", "close", "--project", "test-project"]);
+
+    cmd.assert().code(3);
+}
+
+#[tokio::test]
+async fn test_project_archive_unsupported_server() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/archive.json",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    mock_project_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["project", "archive", "--project", "test-project"]);
 
     cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Time Entry #456"))
-        .stdout(predicate::str::contains("2.50"));
+        .code(5)
+        .stderr(predicate::str::contains("does not support `archive`"))
+        .stderr(predicate::str::contains("Redmine 5.1 or later"));
 }
 
 #[tokio::test]
-async fn test_time_delete() {
+async fn test_project_versions_create() {
     let server = start_mock_server().await;
-    mock_time_entry_delete().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/versions.json",
+        ))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "version": {"name": "1.0", "due_date": "2024-06-30", "sharing": "descendants"}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "version": {
+                    "id": 7,
+                    "project": {"id": 1, "name": "Test Project"},
+                    "name": "1.0",
+                    "status": "open",
+                    "due_date": "2024-06-30",
+                    "sharing": "descendants"
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("time")
-        .arg("delete")
-        .args(["--id", "456"]);
+        .args([
+            "project",
+            "versions",
+            "create",
+            "--project",
+            "test-project",
+            "--name",
+            "1.0",
+            "--due-date",
+            "2024-06-30",
+            "--sharing",
+            "descendants",
+        ]);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("Time Entry Deleted"));
+        .stdout(predicate::str::contains("## Version Created"))
+        .stdout(predicate::str::contains("2024-06-30"));
 }
 
 #[tokio::test]
-async fn test_time_activities_list() {
+async fn test_project_versions_create_invalid_sharing() {
     let server = start_mock_server().await;
-    mock_activities().mount(&server).await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("time")
-        .arg("activities")
-        .arg("list");
+        .args([
+            "project",
+            "versions",
+            "create",
+            "--project",
+            "test-project",
+            "--name",
+            "1.0",
+            "--sharing",
+            "everyone",
+        ]);
 
     cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Development"))
-        .stdout(predicate::str::contains("Design"))
-        .stdout(predicate::str::contains("Testing"));
+        .code(2)
+        .stderr(predicate::str::contains("Invalid --sharing value"));
 }
 
 // ============================================================================
-// Me Command
+// Issue Commands
 // ============================================================================
 
 #[tokio::test]
-async fn test_me() {
+async fn test_issue_list() {
     let server = start_mock_server().await;
-    mock_current_user().mount(&server).await;
+    mock_issues_list().mount(&server).await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("me");
+        .arg("issue")
+        .arg("list");
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("testuser"))
-        .stdout(predicate::str::contains("Test User"));
+        .stdout(predicate::str::contains("Test Issue"))
+        .stdout(predicate::str::contains("123"));
 }
 
-// ============================================================================
-// Ping Command
-// ============================================================================
-
 #[tokio::test]
-async fn test_ping() {
+async fn test_issue_list_heading_offset() {
     let server = start_mock_server().await;
-    mock_current_user().mount(&server).await;
+    mock_issues_list().mount(&server).await;
 
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
         .args(["--url", &server.uri(), "--api-key", "test-api-key"])
-        .arg("ping");
+        .args(["--heading-offset", "1", "issue", "list"]);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("Connection Status"))
-        .stdout(predicate::str::contains("ok"));
+        .stdout(predicate::str::starts_with("### Issues"));
 }
 
-// ============================================================================
-// Error Handling
-// ============================================================================
+#[tokio::test]
+async fn test_issue_list_require_results() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("status_id", "closed"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [], "total_count": 0, "offset": 0, "limit": 25}),
+        ))
+        .mount(&server)
+        .await;
+    mock_issues_list().mount(&server).await;
 
-#[test]
-fn test_missing_credentials() {
     let mut cmd = get_binary();
     cmd.env("APPDATA", std::env::temp_dir())
         .env("LOCALAPPDATA", std::env::temp_dir())
-        .env_remove("REDMINE_URL")
-        .env_remove("REDMINE_API_KEY")
-        .arg("ping");
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--require-results"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Test Issue"));
 
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--status", "closed", "--require-results"]);
     cmd.assert()
         .failure()
-        .stderr(predicate::str::contains("No Redmine credentials"));
+        .code(4)
+        .stderr(predicate::str::contains(
+            "Not found: issues matching the filters",
+        ));
 }
 
-#[test]
-fn test_help() {
+#[tokio::test]
+async fn test_issue_list_all_page_size() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("limit", "10"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [], "total_count": 0, "offset": 0, "limit": 10}),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
     let mut cmd = get_binary();
-    cmd.arg("--help");
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--all", "--page-size", "10"]);
+    cmd.assert().success();
 
-    cmd.assert()
-        .success()
-        .stdout(predicate::str::contains("Agent-first Redmine CLI"))
-        .stdout(predicate::str::contains("ping"))
-        .stdout(predicate::str::contains("issue"))
-        .stdout(predicate::str::contains("time"));
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--all", "--page-size", "500"]);
+    cmd.assert().failure().code(2);
 }
 
-#[test]
-fn test_version() {
+#[tokio::test]
+async fn test_issue_list_multiple_projects_merges_and_dedupes() {
+    let server = start_mock_server().await;
+    let issue = |id: u32| {
+        serde_json::json!({
+            "id": id,
+            "subject": format!("Issue {}", id),
+            "project": {"id": 1, "name": "Backend", "identifier": "backend"},
+            "status": {"id": 1, "name": "New"},
+            "priority": {"id": 2, "name": "Normal"}
+        })
+    };
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("project_id", "backend"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [issue(126), issue(123)], "total_count": 2, "offset": 0, "limit": 25}),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("project_id", "web"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [issue(123)], "total_count": 1, "offset": 0, "limit": 25}),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
     let mut cmd = get_binary();
-    cmd.arg("--version");
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "issue",
+            "list",
+            "--project",
+            "backend,web",
+        ]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let ids: Vec<u64> = json["data"]["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["id"].as_u64().unwrap())
+        .collect();
+    assert_eq!(ids, vec![126, 123]);
+    assert_eq!(json["data"]["total_count"], 2);
+}
+
+#[tokio::test]
+async fn test_issue_list_filter_file_with_flag_override() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param(
+            "project_id",
+            "test-project",
+        ))
+        .and(wiremock::matchers::query_param("status_id", "closed"))
+        .and(wiremock::matchers::query_param("cf_5", "high"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [], "total_count": 0, "offset": 0, "limit": 25}),
+        ))
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let filter_file = temp.path().join("filters.toml");
+    std::fs::write(
+        &filter_file,
+        "project = \"test-project\"\nstatus = \"open\"\n\n[cf]\n5 = \"high\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--status", "closed", "--filter-file"])
+        .arg(&filter_file);
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("rdm"));
+        .stdout(predicate::str::contains("No issues found"));
 }
 
-// ============================================================================
-// Profile Commands
-// ============================================================================
-
 #[test]
-fn test_profile_list_empty() {
+fn test_issue_list_filter_file_conflicting_with_flag() {
     let temp = tempfile::tempdir().unwrap();
+    let filter_file = temp.path().join("filters.toml");
+    std::fs::write(&filter_file, "closed_since = \"7d\"\n").unwrap();
+
     let mut cmd = get_binary();
     cmd.env("APPDATA", temp.path())
         .env("LOCALAPPDATA", temp.path())
-        .env_remove("REDMINE_URL")
-        .env_remove("REDMINE_API_KEY")
-        .arg("profile")
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--status", "open", "--filter-file"])
+        .arg(&filter_file);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "--closed-since cannot be used with --status",
+        ));
+}
+
+#[tokio::test]
+async fn test_issue_list_json() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json"])
+        .arg("issue")
         .arg("list");
 
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("No profiles"));
+        .stdout(predicate::str::contains("\"ok\": true"))
+        .stdout(predicate::str::contains("\"subject\": \"Test Issue\""));
+}
+
+#[tokio::test]
+async fn test_issue_list_bare_json_array() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--bare", "issue", "list"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let issues = json.as_array().expect("bare output should be an array");
+    assert_eq!(issues[0]["subject"], "Test Issue");
+}
+
+#[test]
+fn test_bare_conflicts_with_format() {
+    let mut cmd = get_binary();
+    cmd.args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--bare", "--format", "csv", "issue", "list"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[tokio::test]
+async fn test_issue_list_links() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--links", "issue", "list"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "| [#123]({}/issues/123) |",
+            server.uri()
+        )));
+}
+
+#[tokio::test]
+async fn test_issue_list_group_by_status() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--group-by", "status"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issues by Status (1 issues)"))
+        .stdout(predicate::str::contains("### New (1)"))
+        .stdout(predicate::str::contains("Test Issue"));
+}
+
+#[tokio::test]
+async fn test_issue_list_group_by_assigned_to() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--group-by", "assigned_to"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issues by Assignee (1 issues)"));
+}
+
+#[tokio::test]
+async fn test_issue_list_group_by_warns_on_partial_page() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [{
+                    "id": 123,
+                    "subject": "Test Issue",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"}
+                }],
+                "total_count": 40,
+                "offset": 0,
+                "limit": 1
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--group-by", "status", "--limit", "1"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("grouping over 1 of 40 entries"));
+}
+
+#[test]
+fn test_issue_list_group_by_invalid() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://127.0.0.1:9", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--group-by", "color"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid group-by field"));
+}
+
+#[tokio::test]
+async fn test_issue_list_with_custom_fields_enriches_json() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+    mock_issue_get_with_custom_fields().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "issue", "list", "--with-custom-fields"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"Severity\""));
+}
+
+#[tokio::test]
+async fn test_issue_list_cf_column() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+    mock_issue_get_with_custom_fields().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--with-custom-fields", "--cf-column", "6"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("| Updated | Severity |"))
+        .stdout(predicate::str::contains("| high |"));
+}
+
+#[tokio::test]
+async fn test_issue_list_legend() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--legend"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("### Legend"))
+        .stdout(predicate::str::contains("| Status | 1 | New |"))
+        .stdout(predicate::str::contains("| Priority | 2 | Normal |"));
+}
+
+#[tokio::test]
+async fn test_issue_list_closed_since() {
+    let server = start_mock_server().await;
+    mock_issues_list_closed_since().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "issue",
+            "list",
+            "--closed-since",
+            "2024-01-01",
+        ]);
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"closed_on\": \"2024-01-20T12:00:00Z\"",
+    ));
+}
+
+#[test]
+fn test_issue_list_rejects_multibyte_window_unit() {
+    for flag in ["--closed-since", "--due-within"] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+            .args(["issue", "list", flag, "7é"]);
+
+        cmd.assert().failure().code(2);
+    }
+}
+
+#[tokio::test]
+async fn test_issue_list_due_within() {
+    let server = start_mock_server().await;
+    mock_issues_list_due_soon().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--due-within", "3d"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Due Soon Issue"));
+}
+
+#[tokio::test]
+async fn test_issue_list_unassigned() {
+    let server = start_mock_server().await;
+    mock_issues_list_unassigned().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--unassigned", "--status", "open"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Nobody's Issue"));
+}
+
+#[tokio::test]
+async fn test_issue_list_watched_by_me_sends_user_id() {
+    let server = start_mock_server().await;
+    mock_current_user().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("watcher_id", "1"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--watched-by", "me"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_issue_list_sort_multiple_keys() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param(
+            "sort",
+            "updated_on:desc,priority:desc,id",
+        ))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "list",
+            "--sort",
+            "updated_on:desc,priority:desc,id:asc",
+        ]);
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_issue_list_rejects_invalid_sort() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--sort", "updated_on:newest"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid sort direction"));
+}
+
+#[test]
+fn test_issue_list_presets() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--list-presets"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("i-reported"))
+        .stdout(predicate::str::contains("--assigned-to me --status open"));
+}
+
+#[tokio::test]
+async fn test_issue_list_preset_mine_with_override() {
+    let server = start_mock_server().await;
+    mock_current_user().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("assigned_to_id", "1"))
+        .and(wiremock::matchers::query_param("status_id", "closed"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--preset", "mine", "--status", "closed"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_issue_list_color_always_paints_status_and_priority() {
+    let server = start_mock_server().await;
+    mock_issues_list_closed_high_priority().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--color", "always", "issue", "list"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[2mClosed\x1b[0m"))
+        .stdout(predicate::str::contains("\x1b[31mHigh\x1b[0m"));
+}
+
+#[tokio::test]
+async fn test_issue_list_no_color_when_piped_or_json() {
+    let server = start_mock_server().await;
+    mock_issues_list_closed_high_priority().mount(&server).await;
+
+    for args in [
+        &["issue", "list"][..],
+        &["--color", "always", "--format", "json", "issue", "list"],
+    ] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(args);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("High"))
+            .stdout(predicate::str::contains("\x1b").not());
+    }
+}
+
+#[tokio::test]
+async fn test_issue_list_delegated() {
+    let server = start_mock_server().await;
+    mock_current_user().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("author_id", "1"))
+        .and(wiremock::matchers::query_param("assigned_to_id", "!1"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--delegated"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_issue_list_overdue_sorts_by_due_date() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("status_id", "open"))
+        .and(wiremock::matchers::query_param("sort", "due_date"))
+        .and(wiremock::matchers::query_param_contains("due_date", "<="))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--preset", "overdue"]);
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_issue_list_unknown_preset() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--preset", "everything"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Unknown preset"));
+}
+
+#[test]
+fn test_issue_list_unassigned_conflicts_with_assigned_to() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--unassigned", "--assigned-to", "me"]);
+
+    cmd.assert().failure().code(2);
+}
+
+#[test]
+fn test_issue_list_due_within_invalid_window() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "list", "--due-within", "soon"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid window"));
+}
+
+#[tokio::test]
+async fn test_issue_list_all_ndjson_streams_lines() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "ndjson", "issue", "list", "--all"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("{\"id\":123,"))
+        .stdout(predicate::str::contains("\"ok\"").not());
+}
+
+#[tokio::test]
+async fn test_issue_list_stream_to_file() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+    let dir = tempfile::tempdir().unwrap();
+    let export = dir.path().join("issues.ndjson");
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--stream-to"])
+        .arg(&export);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Wrote 1 issue to"));
+
+    let content = std::fs::read_to_string(&export).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let issue: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(issue["id"], 123);
+}
+
+#[tokio::test]
+async fn test_issue_list_stream_to_file_failure_leaves_no_file() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(wiremock::ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+    let dir = tempfile::tempdir().unwrap();
+    let export = dir.path().join("issues.ndjson");
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--stream-to"])
+        .arg(&export);
+
+    cmd.assert().failure();
+    assert!(!export.exists());
+}
+
+#[tokio::test]
+async fn test_issue_get() {
+    let server = start_mock_server().await;
+    mock_issue_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("issue")
+        .arg("get")
+        .args(["--id", "123"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Test Issue"))
+        .stdout(predicate::str::contains("#123"))
+        .stdout(predicate::str::contains("| Parent | #100 |"));
+}
+
+#[tokio::test]
+async fn test_issue_get_fields_projection() {
+    let server = start_mock_server().await;
+    mock_issue_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json"])
+        .arg("issue")
+        .arg("get")
+        .args(["--id", "123", "--fields", "id,subject"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let data = json["data"].as_object().unwrap();
+    assert_eq!(data.len(), 2);
+    assert_eq!(data["id"], 123);
+    assert!(data.contains_key("subject"));
+}
+
+#[tokio::test]
+async fn test_issue_get_plain_description() {
+    let server = start_mock_server().await;
+    mock_issue_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "get", "--id", "123", "--plain-description"]);
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "### Description\n\n```\nThis is a test issue\n```\n",
+    ));
+}
+
+#[tokio::test]
+async fn test_issue_get_include_journals_shows_history() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 123,
+                    "subject": "Test Issue",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 2, "name": "In Progress"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "journals": [
+                        {
+                            "id": 8,
+                            "user": {"id": 1, "name": "Test User"},
+                            "created_on": "2024-01-03T00:00:00Z",
+                            "notes": "Looking into it",
+                            "details": []
+                        },
+                        {
+                            "id": 7,
+                            "user": {"id": 1, "name": "Test User"},
+                            "created_on": "2024-01-02T00:00:00Z",
+                            "notes": "",
+                            "details": [
+                                {"property": "attr", "name": "status_id", "old_value": "1", "new_value": "2"}
+                            ]
+                        }
+                    ]
+                }
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "get", "--id", "123", "--include-journals"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let stdout = String::from_utf8(output).unwrap();
+    assert!(stdout.contains("### History"));
+    assert!(stdout.contains("- status_id: 1 → 2"));
+    let older = stdout.find("**#7").unwrap();
+    let newer = stdout.find("**#8").unwrap();
+    assert!(older < newer);
+}
+
+#[tokio::test]
+async fn test_issue_statuses() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "statuses"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issue Statuses"))
+        .stdout(predicate::str::contains("| 5 | Closed | Yes |"));
+}
+
+#[tokio::test]
+async fn test_issue_update_dry_run_status_name_needs_lookup() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let update = [
+        "--dry-run",
+        "issue",
+        "update",
+        "--id",
+        "1",
+        "--status",
+        "Closed",
+    ];
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(update);
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("pass the status ID"));
+
+    // A valid cache resolves the name without a request
+    let mut output = Vec::new();
+    for args in [&["issue", "statuses"][..], &update[..]] {
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["--format", "json"])
+            .args(args);
+        output = cmd.assert().success().get_output().stdout.clone();
+    }
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["payload"]["issue"]["status_id"], 5);
+}
+
+#[tokio::test]
+async fn test_issue_trackers_are_cached() {
+    let server = start_mock_server().await;
+    mock_trackers().expect(1).mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    for _ in 0..2 {
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["issue", "trackers"]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("## Trackers"))
+            .stdout(predicate::str::contains("| 2 | Feature |"));
+    }
+}
+
+#[tokio::test]
+async fn test_issue_trackers_cache_is_per_server() {
+    let servers = [start_mock_server().await, start_mock_server().await];
+    let temp = tempfile::tempdir().unwrap();
+
+    for server in &servers {
+        mock_trackers().expect(1).mount(server).await;
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["issue", "trackers"]);
+
+        cmd.assert().success();
+    }
+}
+
+#[tokio::test]
+async fn test_issue_priorities() {
+    let server = start_mock_server().await;
+    mock_issue_priorities().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "priorities", "--refresh"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issue Priorities"))
+        .stdout(predicate::str::contains("| 2 | Normal | Yes |"));
+}
+
+#[tokio::test]
+async fn test_issue_get_rejects_unknown_field() {
+    let server = start_mock_server().await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("issue")
+        .arg("get")
+        .args(["--id", "123", "--fields", "id,colour"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Unknown issue field"));
+}
+
+#[tokio::test]
+async fn test_issue_get_serves_cached_body_on_not_modified() {
+    let server = start_mock_server().await;
+    mock_issue_get_not_modified().expect(1).mount(&server).await;
+    mock_issue_get_with_etag().expect(1).mount(&server).await;
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    for _ in 0..2 {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", cache_dir.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["issue", "get", "--id", "123"]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Cached Issue"));
+    }
+}
+
+#[tokio::test]
+async fn test_no_cache_skips_conditional_request() {
+    let server = start_mock_server().await;
+    mock_issue_get_not_modified().expect(0).mount(&server).await;
+    mock_issue_get_with_etag().expect(2).mount(&server).await;
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    for extra in [&[][..], &["--no-cache"][..]] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", cache_dir.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(extra)
+            .args(["issue", "get", "--id", "123"]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("Cached Issue"));
+    }
+}
+
+#[tokio::test]
+async fn test_issue_update_if_unchanged_since_matches() {
+    let server = start_mock_server().await;
+    mock_issue_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--notes", "Done"])
+        .args(["--if-unchanged-since", "2024-01-15T12:00:00Z"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Issue Updated"));
+}
+
+#[test]
+fn test_issue_update_if_unchanged_since_dry_run() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "123",
+            "--notes",
+            "Done",
+        ])
+        .args(["--if-unchanged-since", "2024-01-15T12:00:00Z"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"notes\": \"Done\""));
+}
+
+#[tokio::test]
+async fn test_issue_update_resolves_status_and_priority_names() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().expect(1).mount(&server).await;
+    mock_issue_priorities().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "issue": {"status_id": 5, "priority_id": 2}
+        })))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123"])
+        .args(["--status", "closed", "--priority", "Normal"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Issue Updated"));
+}
+
+#[tokio::test]
+async fn test_issue_update_unknown_status_lists_valid_ones() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--status", "Done"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("Unknown status: 'Done'"))
+        .stderr(predicate::str::contains(
+            "Available statuses: New, In Progress, Closed",
+        ));
+}
+
+#[tokio::test]
+async fn test_issue_list_status_keyword_is_sent_as_is() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().expect(0).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("status_id", "open"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"issues": [], "total_count": 0})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--status", "open"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_issue_list_limit_zero_uses_server_default() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param_is_missing("limit"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [], "total_count": 0, "offset": 0, "limit": 25}),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--limit", "0"]);
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_list_limit_above_server_cap_is_rejected() {
+    for command in [["issue", "list"], ["time", "list"], ["project", "list"]] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+            .args(command)
+            .args(["--limit", "500"]);
+
+        cmd.assert()
+            .code(2)
+            .stderr(predicate::str::contains("500 is not in 0..=100"));
+    }
+}
+
+#[tokio::test]
+async fn test_issue_list_resolves_status_name() {
+    let server = start_mock_server().await;
+    mock_issue_statuses().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param("status_id", "2"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"issues": [], "total_count": 0})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "list", "--status", "In Progress"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_issue_update_if_unchanged_since_conflict() {
+    let server = start_mock_server().await;
+    mock_issue_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--notes", "Done"])
+        .args(["--if-unchanged-since", "2024-01-10T08:00:00Z"]);
+
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains(
+            "was updated at 2024-01-15T12:00:00Z",
+        ));
+}
+
+#[tokio::test]
+async fn test_issue_update_stale_lock_version_is_conflict() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .and(wiremock::matchers::body_partial_json(
+            serde_json::json!({"issue": {"lock_version": 7}}),
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(409))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--notes", "Done"])
+        .args(["--lock-version", "7"]);
+
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains("modified by someone else"));
+}
+
+#[tokio::test]
+async fn test_issue_update_localized_422_with_stale_lock_version_is_conflict() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(wiremock::ResponseTemplate::new(422).set_body_json(
+            serde_json::json!({"errors": ["Die Daten wurden von einem anderen Benutzer geändert."]}),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 123,
+                    "project": {"id": 1, "name": "Test Project"},
+                    "tracker": {"id": 1, "name": "Bug"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "John Doe"},
+                    "subject": "Test issue",
+                    "lock_version": 8
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--notes", "Done"])
+        .args(["--lock-version", "7"]);
+
+    cmd.assert()
+        .failure()
+        .code(6)
+        .stderr(predicate::str::contains("modified by someone else"));
+}
+
+#[tokio::test]
+async fn test_issue_relations_graph_text() {
+    let server = start_mock_server().await;
+    // 110 blocks 120 blocks 123 blocks 130, and 130 blocks 120 (a cycle)
+    mock_issue_relations(123, &[(1, 120, 123), (2, 123, 130)])
+        .mount(&server)
+        .await;
+    mock_issue_relations(120, &[(1, 120, 123), (3, 110, 120), (4, 130, 120)])
+        .mount(&server)
+        .await;
+    mock_issue_relations(110, &[(3, 110, 120)])
+        .mount(&server)
+        .await;
+    mock_issue_relations(130, &[(2, 123, 130), (4, 130, 120)])
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "relations", "graph", "--id", "123"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Dependencies of #123"))
+        .stdout(predicate::str::contains("- #120\n  - #110\n"))
+        .stdout(predicate::str::contains(
+            "- #130\n  - #120\n    - #123 (cycle)",
+        ));
+}
+
+#[tokio::test]
+async fn test_issue_relations_graph_dot() {
+    let server = start_mock_server().await;
+    mock_issue_relations(123, &[(2, 123, 130)])
+        .mount(&server)
+        .await;
+    mock_issue_relations(130, &[(2, 123, 130)])
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "relations",
+            "graph",
+            "--id",
+            "123",
+            "--style",
+            "dot",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("digraph \"issue_123\" {"))
+        .stdout(predicate::str::contains("\"123\" -> \"130\";"));
+}
+
+#[tokio::test]
+async fn test_issue_relations_list_shows_direction() {
+    let server = start_mock_server().await;
+    mock_issue_relations(123, &[(1, 120, 123), (2, 123, 130)])
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "relations", "list", "--id", "123"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Relations of #123"))
+        .stdout(predicate::str::contains("| 1 | blocked by | #120 | - |"))
+        .stdout(predicate::str::contains("| 2 | blocks | #130 | - |"));
+}
+
+#[tokio::test]
+async fn test_issue_relations_add() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/issues/123/relations.json"))
+        .and(wiremock::matchers::body_json(serde_json::json!({
+            "relation": {"issue_to_id": 130, "relation_type": "blocks"}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "relation": {
+                    "id": 7,
+                    "issue_id": 123,
+                    "issue_to_id": 130,
+                    "relation_type": "blocks",
+                    "delay": null
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "relations",
+            "add",
+            "--id",
+            "123",
+            "--relates-to",
+            "130",
+            "--type",
+            "blocks",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Relation Added"))
+        .stdout(predicate::str::contains("#123 blocks #130"));
+}
+
+#[test]
+fn test_issue_relations_add_rejects_unknown_type() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "relations",
+            "add",
+            "--id",
+            "123",
+            "--relates-to",
+            "130",
+            "--type",
+            "depends",
+        ]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid relation type: 'depends'"));
+}
+
+#[tokio::test]
+async fn test_issue_update_add_cf_merges_multi_value() {
+    let server = start_mock_server().await;
+    mock_issue_get_with_custom_fields().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/issues/123.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "issue": {"custom_fields": [{"id": 5, "value": ["backend", "api"]}]}
+        })))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123"])
+        .args(["--add-cf", "5=api", "--remove-cf", "5=frontend"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Issue Updated"));
+}
+
+#[tokio::test]
+async fn test_issue_update_add_cf_rejects_set_single_value() {
+    let server = start_mock_server().await;
+    mock_issue_get_with_custom_fields().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--add-cf", "6=low"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("already has value 'high'"));
+}
+
+#[tokio::test]
+async fn test_issue_update_add_cf_dry_run_names_flag() {
+    let server = start_mock_server().await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "1",
+            "--add-cf",
+            "3=staging",
+        ]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("--add-cf/--remove-cf"))
+        .stderr(predicate::str::contains("--cf <id>=<value>"));
+}
+
+#[tokio::test]
+async fn test_issue_watch_project_rejects_dry_run() {
+    let server = start_mock_server().await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "watch-project",
+            "--project",
+            "1",
+            "--yes",
+        ]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("Cannot use --dry-run"));
+}
+
+#[tokio::test]
+async fn test_issue_watch_project_requires_yes() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "watch-project", "--project", "test-project"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("1 issues"))
+        .stderr(predicate::str::contains("--yes"));
+}
+
+#[tokio::test]
+async fn test_issue_watch_project() {
+    let server = start_mock_server().await;
+    mock_issues_list().mount(&server).await;
+    mock_current_user().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/issues/123/watchers.json"))
+        .and(wiremock::matchers::body_json(
+            serde_json::json!({"user_id": 1}),
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "watch-project",
+            "--project",
+            "test-project",
+            "--yes",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Watching Project: test-project"))
+        .stdout(predicate::str::contains("| Watched | 1 |"));
+}
+
+#[tokio::test]
+async fn test_issue_get_multiple_skips_missing() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues/999.json"))
+        .respond_with(wiremock::ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    mock_issue_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "issue", "get", "--id", "123,999"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["data"]["issues"][0]["id"], 123);
+    assert_eq!(json["data"]["issues"].as_array().unwrap().len(), 1);
+    assert_eq!(json["data"]["missing"][0], 999);
+}
+
+#[tokio::test]
+async fn test_search_returns_raw_results() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/search.json"))
+        .and(wiremock::matchers::query_param("q", "release"))
+        .and(wiremock::matchers::query_param("wiki_pages", "1"))
+        .and(wiremock::matchers::query_param("news", "1"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "results": [
+                    {
+                        "id": 7,
+                        "title": "Wiki: Release Notes",
+                        "type": "wiki-page",
+                        "url": "https://redmine.example.com/projects/test/wiki/Release_Notes",
+                        "datetime": "2024-01-15T10:30:00Z"
+                    }
+                ],
+                "total_count": 1,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["search", "release", "--types", "wiki,news"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Search Results"))
+        .stdout(predicate::str::contains("Wiki: Release Notes"))
+        .stdout(predicate::str::contains("wiki-page"));
+}
+
+#[test]
+fn test_issue_update_clear_due_date_sends_empty_string() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "123",
+            "--start-date",
+            "2024-03-01",
+            "--clear-due-date",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"start_date\": \"2024-03-01\""))
+        .stdout(predicate::str::contains("\"due_date\": \"\""));
+}
+
+#[test]
+fn test_issue_update_cf_keeps_commas_in_value() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "123",
+        ])
+        .args(["--cf", "3=Berlin, Germany"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let fields = &json["data"]["payload"]["issue"]["custom_fields"];
+    assert_eq!(fields[0]["value"], serde_json::json!("Berlin, Germany"));
+}
+
+#[test]
+fn test_issue_create_repeated_cf_accumulates_multi_value() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--format", "json", "--dry-run", "issue", "create"])
+        .args(["--project", "1", "--subject", "Tagged"])
+        .args(["--cf", "5=backend", "--cf", "7=high", "--cf", "5=api"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let fields = &json["data"]["payload"]["issue"]["custom_fields"];
+    assert_eq!(fields[0]["id"], 5);
+    assert_eq!(fields[0]["value"], serde_json::json!(["backend", "api"]));
+    assert_eq!(fields[1]["value"], serde_json::json!("high"));
+}
+
+#[test]
+fn test_issue_create_with_parent_sends_parent_issue_id() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Write tests",
+            "--parent",
+            "100",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"parent_issue_id\": 100"))
+        .stdout(predicate::str::contains("watcher_user_ids").not());
+}
+
+#[test]
+fn test_issue_create_with_watchers() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "--dry-run",
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Write tests",
+            "--watcher",
+            "5",
+            "--watcher",
+            "7",
+        ]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        json["data"]["payload"]["issue"]["watcher_user_ids"],
+        serde_json::json!([5, 7])
+    );
+}
+
+#[test]
+fn test_issue_watchers_remove() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "watchers",
+            "remove",
+            "--id",
+            "123",
+            "--user",
+            "5",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("DELETE"))
+        .stdout(predicate::str::contains("/issues/123/watchers/5.json"));
+}
+
+#[test]
+fn test_issue_watchers_add_dry_run_rejects_me() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "watchers",
+            "add",
+            "--id",
+            "123",
+            "--user",
+            "me",
+        ]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--user me"));
+}
+
+#[test]
+fn test_issue_update_clear_parent_sends_null() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "123",
+            "--clear-parent",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"parent_issue_id\": null"));
+}
+
+#[test]
+fn test_issue_comment_sends_private_note() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "comment",
+            "--id",
+            "123",
+            "Deployed to staging",
+            "--private",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("/issues/123.json"))
+        .stdout(predicate::str::contains(
+            "\"notes\": \"Deployed to staging\"",
+        ))
+        .stdout(predicate::str::contains("\"private_notes\": true"));
+}
+
+#[test]
+fn test_issue_comment_escaped_mention_is_literal() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--dry-run", "issue", "comment", "--id", "123"])
+        .arg("@@alice can you review?");
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "\"notes\": \"@alice can you review?\"",
+    ));
+}
+
+#[test]
+fn test_issue_update_reads_description_and_notes() {
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("description.md");
+    std::fs::write(&file, "Steps to reproduce").unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--dry-run", "issue", "update", "--id", "123"])
+        .arg(format!("--description=@{}", file.display()))
+        .args(["--notes", "-"])
+        .write_stdin("Rewrote the description");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "\"description\": \"Steps to reproduce\"",
+        ))
+        .stdout(predicate::str::contains(
+            "\"notes\": \"Rewrote the description\"",
+        ));
+}
+
+#[test]
+fn test_issue_update_estimated_hours_and_tracker() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "update",
+            "--id",
+            "123",
+            "--tracker",
+            "2",
+            "--estimated-hours",
+            "1h30m",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"tracker_id\": 2"))
+        .stdout(predicate::str::contains("\"estimated_hours\": 1.5"));
+}
+
+#[tokio::test]
+async fn test_issue_create_shows_full_issue() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 200,
+                    "subject": "New feature",
+                    "description": "Details here",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "tracker": {"id": 2, "name": "Feature"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "Test User"},
+                    "done_ratio": 0
+                }
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "New feature",
+            "--description",
+            "Details here",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issue Created"))
+        .stdout(predicate::str::contains("### Issue #200: New feature"))
+        .stdout(predicate::str::contains("| Tracker | Feature |"))
+        .stdout(predicate::str::contains("#### Description\n\nDetails here"));
+}
+
+#[tokio::test]
+async fn test_issue_create_attaches_uploaded_files() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/uploads.json"))
+        .and(wiremock::matchers::query_param("filename", "trace.log"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201)
+                .set_body_json(serde_json::json!({"upload": {"token": "7.abc"}})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "issue": {"uploads": [{
+                "token": "7.abc",
+                "filename": "trace.log",
+                "content_type": "application/octet-stream"
+            }]}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 200,
+                    "subject": "Crash",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "Test User"}
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("trace.log");
+    std::fs::write(&file, "panic at line 1").unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["issue", "create", "--project", "1", "--subject", "Crash"])
+        .arg("--attach")
+        .arg(&file);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Issue Created"));
+}
+
+#[test]
+fn test_issue_create_missing_attachment_fails_before_requests() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "create", "--project", "1", "--subject", "Crash"])
+        .args(["--attach", "no-such-file.log"]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("File not found: no-such-file.log"));
+}
+
+#[tokio::test]
+async fn test_issue_attachment_list_downloads_all() {
+    let server = start_mock_server().await;
+    let attachment = |id: u32| {
+        serde_json::json!({
+            "id": id,
+            "filename": "notes.txt",
+            "filesize": 5,
+            "content_type": "text/plain",
+            "content_url": format!("{}/attachments/download/{}/notes.txt", server.uri(), id)
+        })
+    };
+    wiremock::Mock::given(wiremock::matchers::path("/issues/123.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 123,
+                    "subject": "Test Issue",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "Test User"},
+                    "attachments": [attachment(7), attachment(8)]
+                }
+            })),
+        )
+        .mount(&server)
+        .await;
+    for id in [7, 8] {
+        wiremock::Mock::given(wiremock::matchers::path(format!(
+            "/attachments/download/{}/notes.txt",
+            id
+        )))
+        .and(wiremock::matchers::header(
+            "X-Redmine-API-Key",
+            "test-api-key",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_string(format!("v{}", id)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    }
+    let temp = tempfile::tempdir().unwrap();
+    let dir = temp.path().join("files");
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "attachment",
+            "list",
+            "--issue-id",
+            "123",
+            "--download",
+        ])
+        .arg(&dir);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "/attachments/download/7/notes.txt |",
+        ))
+        .stdout(predicate::str::contains("### Downloaded (4 B)"));
+
+    // The second file with the same name is prefixed with its ID
+    assert_eq!(
+        std::fs::read_to_string(dir.join("notes.txt")).unwrap(),
+        "v7"
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("8-notes.txt")).unwrap(),
+        "v8"
+    );
+}
+
+#[test]
+fn test_issue_create_done_ratio() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Imported",
+            "--done-ratio",
+            "40",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"done_ratio\": 40"));
+}
+
+#[tokio::test]
+async fn test_issue_create_fixed_version_by_name() {
+    let server = start_mock_server().await;
+    mock_project_versions().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "issue": {"fixed_version_id": 4}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "issue": {
+                    "id": 200,
+                    "subject": "Milestone work",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "Test User"}
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Milestone work",
+            "--fixed-version",
+            "q3 release",
+        ]);
+
+    cmd.assert().success();
+}
+
+#[test]
+fn test_issue_create_dry_run_takes_version_id() {
+    for (version, code) in [("4", 0), ("q3 release", 2)] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+            .args(["--dry-run", "issue", "create", "--project", "1"])
+            .args(["--subject", "Milestone work", "--fixed-version", version]);
+
+        cmd.assert().code(code);
+    }
+}
+
+#[tokio::test]
+async fn test_issue_create_rejects_unavailable_version() {
+    let server = start_mock_server().await;
+    mock_project_versions().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Milestone work",
+            "--version",
+            "99",
+        ]);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("not available in project 1"))
+        .stderr(predicate::str::contains("1.0, Q3 Release"));
+}
+
+#[test]
+fn test_issue_create_rejects_done_ratio_out_of_range() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "issue",
+            "create",
+            "--project",
+            "1",
+            "--subject",
+            "Imported",
+            "--done-ratio",
+            "150",
+        ]);
+
+    cmd.assert().failure().code(2);
+}
+
+#[test]
+fn test_issue_update_rejects_invalid_date() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["issue", "update", "--id", "123", "--due-date", "31/03/2024"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid --due-date value"));
+}
+
+// ============================================================================
+// Time Entry Commands
+// ============================================================================
+
+#[tokio::test]
+async fn test_time_list() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("time")
+        .arg("list");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("456"))
+        .stdout(predicate::str::contains("2.50"))
+        .stdout(predicate::str::contains("Development"));
+}
+
+#[tokio::test]
+async fn test_time_list_resolves_project_identifier_once() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/my-slug.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "project": {"id": 7, "name": "My Project", "identifier": "my-slug"}
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::query_param("project_id", "7"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time_entries": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    // The second run reads the identifier from the cache
+    for _ in 0..2 {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", temp.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["time", "list", "--project", "my-slug"]);
+        cmd.assert().success();
+    }
+}
+
+#[tokio::test]
+async fn test_time_list_project_identifier_cache_is_per_server() {
+    let temp = tempfile::tempdir().unwrap();
+    // Both servers stay up so the second cannot reuse the first one's URL
+    let servers = [start_mock_server().await, start_mock_server().await];
+    for (server, id) in servers.iter().zip([7, 9]) {
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path("/projects/my-slug.json"))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "project": {"id": id, "name": "My Project", "identifier": "my-slug"}
+                })),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path("/time_entries.json"))
+            .and(wiremock::matchers::query_param(
+                "project_id",
+                id.to_string(),
+            ))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "time_entries": [],
+                    "total_count": 0,
+                    "offset": 0,
+                    "limit": 25
+                })),
+            )
+            .expect(1)
+            .mount(server)
+            .await;
+
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", temp.path())
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(["time", "list", "--project", "my-slug"]);
+        cmd.assert().success();
+    }
+}
+
+#[test]
+fn test_time_list_dry_run_skips_project_lookup() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--dry-run", "time", "list", "--project", "my-slug"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_time_list_unknown_project_identifier_is_not_found() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/projects/nope.json"))
+        .respond_with(wiremock::ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "list", "--project", "nope"]);
+
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains("rdm project list"));
+}
+
+#[tokio::test]
+async fn test_time_list_user_me_sends_user_id() {
+    let server = start_mock_server().await;
+    mock_current_user().expect(1).mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::query_param("user_id", "1"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time_entries": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "list", "--user", "me"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_time_import_csv_creates_each_row() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"issue_id": 123, "activity_id": 2, "spent_on": "2024-01-15"}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "time_entry": {
+                    "id": 801,
+                    "hours": 2.0,
+                    "spent_on": "2024-01-15",
+                    "activity": {"id": 2, "name": "Design"},
+                    "user": {"id": 1, "name": "Test User"},
+                    "issue": {"id": 123}
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"project_id": 1, "activity_id": 1, "comments": "Planning, sprint 3"}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "time_entry": {
+                    "id": 802,
+                    "hours": 1.0,
+                    "spent_on": "2024-01-16",
+                    "activity": {"id": 1, "name": "Development"},
+                    "user": {"id": 1, "name": "Test User"},
+                    "project": {"id": 1, "name": "Test Project"}
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("week.csv");
+    std::fs::write(
+        &file,
+        "issue,project,hours,activity,spent_on,comment\n\
+         123,,2,Design,2024-01-15,\n\
+         ,1,1,,2024-01-16,\"Planning, sprint 3\"\n",
+    )
+    .unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "import", "--file"])
+        .arg(&file);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("801"))
+        .stdout(predicate::str::contains("802"))
+        .stdout(predicate::str::contains("2 created, 0 failed"));
+}
+
+#[tokio::test]
+async fn test_time_import_json_reports_failed_rows() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"issue_id": 999}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(422)
+                .set_body_json(serde_json::json!({"errors": ["Issue is invalid"]})),
+        )
+        .mount(&server)
+        .await;
+    mock_time_entry_create().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("week.json");
+    std::fs::write(
+        &file,
+        r#"[
+            {"issue": 123, "hours": 1.5, "spent_on": "2024-01-16", "comment": "New entry"},
+            {"issue": 999, "hours": 1, "spent_on": "2024-01-16"}
+        ]"#,
+    )
+    .unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "time", "import", "--file"])
+        .arg(&file);
+
+    let assert = cmd.assert().failure().code(7);
+    let output = assert.get_output();
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result["data"]["created"], 1);
+    assert_eq!(result["data"]["failed"], 1);
+    let rows = result["data"]["rows"].as_array().unwrap();
+    assert_eq!(rows[0]["status"], "created");
+    assert_eq!(rows[0]["time_entry_id"], 789);
+    assert_eq!(rows[1]["status"], "failed");
+    assert!(rows[1]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Issue is invalid"));
+
+    let error: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(error["error"]["code"], "PARTIAL_FAILURE");
+    let message = error["error"]["message"].as_str().unwrap();
+    assert!(message.contains("1 of 2 time entries failed to import"));
+    assert!(message.contains("Row 2:"));
+}
+
+#[tokio::test]
+async fn test_time_import_dry_run_plans_every_row() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("week.csv");
+    std::fs::write(
+        &file,
+        "issue,project,hours,activity\n123,,1h30m,Design\n,backend,0:45,Development\n",
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(args);
+        cmd
+    };
+    // --dry-run does not fetch activities, so warm the cache first
+    run(&["time", "activities", "list"]).assert().success();
+
+    let output = run(&["--format", "json", "--dry-run", "time", "import", "--file"])
+        .arg(&file)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let rows = &json["data"]["rows"];
+    assert_eq!(rows[0]["status"], "planned");
+    assert_eq!(rows[0]["request"]["payload"]["time_entry"]["hours"], 1.5);
+    assert_eq!(rows[1]["status"], "planned");
+    let entry = &rows[1]["request"]["payload"]["time_entry"];
+    assert_eq!(entry["project_id"], "backend");
+    assert_eq!(entry["hours"], 0.75);
+}
+
+#[test]
+fn test_time_import_rejects_invalid_row_before_sending() {
+    let temp = tempfile::tempdir().unwrap();
+    let file = temp.path().join("week.json");
+    std::fs::write(&file, r#"[{"issue": 123, "hours": 1}, {"issue": 124}]"#).unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["time", "import", "--file"])
+        .arg(&file);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Row 2: missing hours"));
+}
+
+#[tokio::test]
+async fn test_time_list_json() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json"])
+        .arg("time")
+        .arg("list");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"ok\": true"))
+        .stdout(predicate::str::contains("\"hours\": 2.5"));
+}
+
+#[tokio::test]
+async fn test_time_list_csv_enriched_with_issue_subject() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+    mock_issue_get().expect(1).mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "csv", "time", "list", "--enrich"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    let header: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let row: Vec<&str> = lines.next().unwrap().split(',').collect();
+    let column = header.iter().position(|c| *c == "issue_subject").unwrap();
+    assert_eq!(row[column], "Test Issue");
+}
+
+#[tokio::test]
+async fn test_issue_list_csv_quotes_subjects() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [{
+                    "id": 7,
+                    "subject": "Fix \"save\", then reload",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"}
+                }],
+                "total_count": 1, "offset": 0, "limit": 25
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "csv", "issue", "list"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(",subject,"))
+        .stdout(predicate::str::contains(
+            ",\"Fix \"\"save\"\", then reload\",",
+        ))
+        .stdout(predicate::str::contains("\"ok\"").not());
+}
+
+#[tokio::test]
+async fn test_issue_list_csv_empty_prints_header() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/issues.json"))
+        .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({"issues": [], "total_count": 0, "offset": 0, "limit": 25}),
+        ))
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "csv", "issue", "list"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("id,project,tracker,status,priority,subject,"));
+    assert!(stdout.ends_with("closed_on\r\n"));
+    assert_eq!(stdout.lines().count(), 1);
+}
+
+#[tokio::test]
+async fn test_time_list_min_hours_filters_entries() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "list", "--min-hours", "8"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No time entries found"));
+}
+
+#[tokio::test]
+async fn test_time_list_weekly_grid() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "list", "--weekly-grid", "--from", "2024-01-15"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Weekly Timesheet (2024-01-15 to 2024-01-21)",
+        ))
+        .stdout(predicate::str::contains("Mon 01-15"))
+        .stdout(predicate::str::contains("| #123 | 2.50 | - |"))
+        .stdout(predicate::str::contains("**2.50**"));
+}
+
+#[tokio::test]
+async fn test_time_summary_rolls_up_days_and_weeks() {
+    let server = start_mock_server().await;
+    let entry = |id: u32, hours: f64, spent_on: &str| {
+        serde_json::json!({
+            "id": id,
+            "hours": hours,
+            "spent_on": spent_on,
+            "activity": {"id": 1, "name": "Development"},
+            "user": {"id": 1, "name": "Test User"}
+        })
+    };
+    wiremock::Mock::given(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::query_param("from", "2024-01-15"))
+        .and(wiremock::matchers::query_param("to", "2024-01-28"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time_entries": [
+                    entry(1, 2.5, "2024-01-15"),
+                    entry(2, 1.0, "2024-01-15"),
+                    entry(3, 4.0, "2024-01-23")
+                ],
+                "total_count": 3,
+                "offset": 0,
+                "limit": 100
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "time",
+            "summary",
+            "--from",
+            "2024-01-15",
+            "--to",
+            "2024-01-28",
+        ]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Time Summary (2024-01-15 to 2024-01-28)",
+        ))
+        .stdout(predicate::str::contains(
+            "| 2024-W03 (2024-01-15) | 3.50 | - |",
+        ))
+        .stdout(predicate::str::contains(
+            "| 2024-W04 (2024-01-22) | - | 4.00 |",
+        ))
+        .stdout(predicate::str::contains(
+            "Grand Total: 7.50 hours (3 entries on 2 days)",
+        ));
+}
+
+#[test]
+fn test_time_list_weekly_grid_requires_from() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["time", "list", "--weekly-grid"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--weekly-grid requires --from"));
+}
+
+#[tokio::test]
+async fn test_time_list_require_results_fails_when_empty() {
+    let server = start_mock_server().await;
+    mock_time_entries_list().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "list", "--min-hours", "8", "--require-results"]);
+
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains(
+            "time entries matching the filters",
+        ));
+}
+
+#[test]
+fn test_time_create_rejects_invalid_spent_on() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "time",
+            "create",
+            "--issue",
+            "123",
+            "--hours",
+            "1",
+            "--spent-on",
+            "2024-13-01",
+        ]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid --spent-on value"));
+}
+
+#[test]
+fn test_time_list_rejects_inverted_hour_bounds() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["time", "list", "--min-hours", "8", "--max-hours", "2"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("greater than --max-hours"));
+}
+
+#[tokio::test]
+async fn test_time_get() {
+    let server = start_mock_server().await;
+    mock_time_entry_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("time")
+        .arg("get")
+        .args(["--id", "456"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Time Entry #456"))
+        .stdout(predicate::str::contains("2.50"));
+}
+
+#[tokio::test]
+async fn test_time_get_enrich_shows_subject_and_url() {
+    let server = start_mock_server().await;
+    mock_time_entry_get().mount(&server).await;
+    mock_issue_get().expect(1).mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "get", "--id", "456", "--enrich"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("#123 Test Issue"))
+        .stdout(predicate::str::contains(format!(
+            "{}/time_entries/456/edit",
+            server.uri()
+        )));
+}
+
+#[tokio::test]
+async fn test_time_update_clear_comment() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path("/time_entries/456.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"comments": ""}
+        })))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/time_entries/456.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "time_entry": {
+                    "id": 456,
+                    "hours": 2.5,
+                    "comments": "",
+                    "spent_on": "2024-01-15",
+                    "activity": {"id": 1, "name": "Development"},
+                    "user": {"id": 1, "name": "Test User"},
+                    "project": {"id": 1, "name": "Test Project"}
+                }
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "time",
+            "update",
+            "--id",
+            "456",
+            "--clear-comment",
+        ]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["time_entry"]["comments"], "");
+}
+
+#[tokio::test]
+async fn test_time_delete() {
+    let server = start_mock_server().await;
+    mock_time_entry_delete().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("time")
+        .arg("delete")
+        .args(["--id", "456"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Time Entry Deleted"));
+}
+
+#[tokio::test]
+async fn test_time_activities_list() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("time")
+        .arg("activities")
+        .arg("list");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Development"))
+        .stdout(predicate::str::contains("Design"))
+        .stdout(predicate::str::contains("Testing"));
+}
+
+// ============================================================================
+// Wiki Commands
+// ============================================================================
+
+#[tokio::test]
+async fn test_wiki_list_tree() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/wiki/index.json",
+        ))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "wiki_pages": [
+                    {"title": "Setup", "parent": {"title": "Wiki"}, "version": 2,
+                     "updated_on": "2024-01-10T09:00:00Z"},
+                    {"title": "Wiki", "version": 5, "updated_on": "2024-01-12T09:00:00Z"},
+                    {"title": "Releases", "version": 1}
+                ]
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["wiki", "list", "--project", "test-project"]);
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("## Wiki Pages (3)"));
+    let releases = stdout.find("| Releases |").unwrap();
+    let wiki = stdout.find("| Wiki |").unwrap();
+    let setup = stdout.find("| ↳ Setup | Wiki | 2 |").unwrap();
+    assert!(releases < wiki && wiki < setup);
+}
+
+#[test]
+fn test_wiki_list_dry_run_is_empty() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args([
+            "--format",
+            "json",
+            "--dry-run",
+            "wiki",
+            "list",
+            "--project",
+            "1",
+        ]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["wiki_pages"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_wiki_list_without_wiki_module() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/wiki/index.json",
+        ))
+        .respond_with(wiremock::ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+    mock_project_get().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["wiki", "list", "--project", "test-project"]);
+
+    cmd.assert()
+        .code(4)
+        .stderr(predicate::str::contains("enable the Wiki module"));
+}
+
+#[tokio::test]
+async fn test_wiki_update_from_stdin() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("PUT"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/wiki/Release%20Notes.json",
+        ))
+        .and(wiremock::matchers::body_json(serde_json::json!({
+            "wiki_page": {"text": "h1. Release Notes\n", "comments": "Add 1.0"}
+        })))
+        .respond_with(wiremock::ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path(
+            "/projects/test-project/wiki/Release%20Notes.json",
+        ))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "wiki_page": {"title": "Release_Notes", "text": "h1. Release Notes", "version": 4}
+            })),
+        )
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "wiki",
+            "update",
+            "--project",
+            "test-project",
+            "--page",
+            "Release Notes",
+            "--file",
+            "-",
+            "--comment",
+            "Add 1.0",
+        ])
+        .write_stdin("h1. Release Notes\n");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Wiki Page Updated"))
+        .stdout(predicate::str::contains("| Version | 4 |"));
+}
+
+#[test]
+fn test_wiki_update_dry_run_describes_put() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--dry-run", "wiki", "update", "--project", "test-project"])
+        .args(["--page", "Release Notes", "--file", "-"])
+        .write_stdin("h1. Release Notes\n");
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "## Dry Run: PUT /projects/test-project/wiki/Release%20Notes.json",
+    ));
+}
+
+// ============================================================================
+// Me Command
+// ============================================================================
+
+#[tokio::test]
+async fn test_me() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("me");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("testuser"))
+        .stdout(predicate::str::contains("Test User"));
+}
+
+#[tokio::test]
+async fn test_me_probe_reports_capabilities() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+    mock_users_list_forbidden().expect(1).mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "me", "--probe"]);
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json["data"]["capabilities"],
+        serde_json::json!({"is_admin": false, "can_list_users": false})
+    );
+}
+
+#[tokio::test]
+async fn test_me_probe_markdown_shows_inferred_admin() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/users/current.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "user": {"id": 1, "login": "admin", "firstname": "Ada", "lastname": "Admin"}
+            })),
+        )
+        .mount(&server)
+        .await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/users.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"users": [], "total_count": 0})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["me", "--probe"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(r"\| Admin +\| Yes +\|").unwrap())
+        .stdout(predicate::str::is_match(r"\| Can List Users +\| Yes +\|").unwrap());
+}
+
+#[tokio::test]
+async fn test_me_probe_rejects_dry_run() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::any())
+        .respond_with(wiremock::ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--dry-run", "me", "--probe"]);
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("--dry-run"));
+}
+
+#[tokio::test]
+async fn test_lang_sets_accept_language_header() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path_regex(r"/issues\.json.*"))
+        .and(wiremock::matchers::header("Accept-Language", "de"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issues": [],
+                "total_count": 0,
+                "offset": 0,
+                "limit": 25
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--lang", "de", "issue", "list"]);
+
+    cmd.assert().success();
+}
+
+// ============================================================================
+// Ping Command
+// ============================================================================
+
+#[tokio::test]
+async fn test_ping() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("ping");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Connection Status"))
+        .stdout(predicate::str::contains("ok"));
+}
+
+#[tokio::test]
+async fn test_ping_shows_declared_server_version() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--api-version", "5.1.2", "ping"]);
+
+    cmd.assert().success().stdout(predicate::str::contains(
+        "- **Server Version**: Redmine 5.1",
+    ));
+}
+
+#[tokio::test]
+async fn test_ping_json_reports_latency() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "ping"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"latency_ms\":"));
+}
+
+#[tokio::test]
+async fn test_ping_unauthorized_exits_with_auth_code() {
+    let server = start_mock_server().await;
+    mock_current_user_unauthorized().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "wrong-key"])
+        .arg("ping");
+
+    cmd.assert().code(3);
+}
+
+#[test]
+fn test_ping_connection_refused_exits_with_api_code() {
+    // Bind and release a port so nothing is listening on it
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args([
+            "--url",
+            &format!("http://127.0.0.1:{}", port),
+            "--api-key",
+            "test-api-key",
+        ])
+        .args(["--format", "json", "ping"]);
+
+    cmd.assert()
+        .code(5)
+        .stderr(predicate::str::contains("NETWORK_ERROR"));
+}
+
+#[tokio::test]
+async fn test_ping_times_out_after_timeout() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::path("/users/current.json"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(5)),
+        )
+        .mount(&server)
+        .await;
+
+    let started = std::time::Instant::now();
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--timeout", "1", "--format", "json", "ping"]);
+
+    cmd.assert()
+        .code(5)
+        .stderr(predicate::str::contains("NETWORK_ERROR"));
+    assert!(started.elapsed() < std::time::Duration::from_secs(4));
+}
+
+#[test]
+fn test_timeout_must_be_in_range() {
+    for timeout in ["0", "601"] {
+        let mut cmd = get_binary();
+        cmd.env("APPDATA", std::env::temp_dir())
+            .env("LOCALAPPDATA", std::env::temp_dir())
+            .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+            .args(["--timeout", timeout, "ping"]);
+
+        cmd.assert().code(2);
+    }
+}
+
+#[test]
+fn test_profile_timeout_out_of_range_is_a_config_error() {
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"http://localhost:1\"\napi_key = \"secret-key\"\ntimeout_secs = 0\n",
+    )
+    .unwrap();
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .arg("ping")
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("Invalid timeout_secs: 0"));
+}
+
+#[tokio::test]
+async fn test_doctor_reports_checks() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .arg("doctor");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("## Doctor"))
+        .stdout(predicate::str::contains("| PASS | Connection |"))
+        .stdout(predicate::str::contains("| PASS | Cache directory |"))
+        .stdout(predicate::str::contains("| WARN | TLS |"))
+        .stdout(predicate::str::contains("| PASS | Clock |"));
+}
+
+#[tokio::test]
+async fn test_doctor_applies_timeout_and_language() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/users/current.json"))
+        .and(wiremock::matchers::header("Accept-Language", "de"))
+        .respond_with(
+            wiremock::ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"user": {"id": 1, "login": "testuser"}}))
+                .set_delay(std::time::Duration::from_secs(3)),
+        )
+        .mount(&server)
+        .await;
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--lang", "de", "--timeout", "1", "doctor"]);
+
+    cmd.assert()
+        .code(5)
+        .stdout(predicate::str::contains("| FAIL | Connection |"));
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests
+        .iter()
+        .all(|r| r.headers.get("Accept-Language").is_some_and(|v| v == "de")));
+}
+
+#[test]
+fn test_doctor_without_config_skips_server_checks() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .env_remove("REDMINE_URL")
+        .env_remove("REDMINE_API_KEY")
+        .args(["--format", "json", "doctor"]);
+
+    cmd.assert()
+        .code(3)
+        .stdout(predicate::str::contains("\"healthy\": false"))
+        .stdout(predicate::str::contains("\"status\": \"skip\""));
+}
+
+#[tokio::test]
+async fn test_user_list_all_follows_pages() {
+    let server = start_mock_server().await;
+    for (offset, login) in [("0", "alice"), ("1", "bob")] {
+        wiremock::Mock::given(wiremock::matchers::method("GET"))
+            .and(wiremock::matchers::path("/users.json"))
+            .and(wiremock::matchers::query_param("offset", offset))
+            .respond_with(
+                wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "users": [{"id": 1, "login": login, "firstname": "A", "lastname": "B"}],
+                    "total_count": 2, "offset": offset.parse::<u32>().unwrap(), "limit": 1
+                })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["user", "list", "--all", "--page-size", "1"]);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("showing 1-2 of 2"))
+        .stdout(predicate::str::contains("alice"))
+        .stdout(predicate::str::contains("bob"));
+}
+
+#[tokio::test]
+async fn test_me_debug_reports_retry_attempts() {
+    let server = start_mock_server().await;
+    mock_current_user_unavailable_once().mount(&server).await;
+    mock_current_user().mount(&server).await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--debug", "me"]);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("succeeded after 2 attempts"));
+}
+
+#[tokio::test]
+async fn test_max_retries_zero_makes_a_single_attempt() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/users/current.json"))
+        .respond_with(wiremock::ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--max-retries", "0", "--debug", "me"]);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("failed after 1 attempt,"));
+}
+
+#[tokio::test]
+async fn test_max_retries_caps_retries() {
+    let server = start_mock_server().await;
+    wiremock::Mock::given(wiremock::matchers::method("GET"))
+        .and(wiremock::matchers::path("/users/current.json"))
+        .respond_with(wiremock::ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--max-retries", "2", "me"]);
+
+    cmd.assert().failure().code(5);
+}
+
+#[tokio::test]
+async fn test_me_retries_after_rate_limit() {
+    let server = start_mock_server().await;
+    mock_current_user_rate_limited_twice().mount(&server).await;
+    mock_current_user().mount(&server).await;
+
+    let started = std::time::Instant::now();
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--debug", "me"]);
+
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("succeeded after 3 attempts"));
+    // Both retries waited for the Retry-After delay
+    assert!(started.elapsed() >= std::time::Duration::from_secs(2));
+}
+
+// ============================================================================
+// Error Handling
+// ============================================================================
+
+#[test]
+fn test_missing_credentials() {
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env_remove("REDMINE_URL")
+        .env_remove("REDMINE_API_KEY")
+        .arg("ping");
+
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No Redmine credentials"));
+}
+
+#[test]
+fn test_help() {
+    let mut cmd = get_binary();
+    cmd.arg("--help");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Agent-first Redmine CLI"))
+        .stdout(predicate::str::contains("ping"))
+        .stdout(predicate::str::contains("issue"))
+        .stdout(predicate::str::contains("time"));
+}
+
+#[test]
+fn test_version() {
+    let mut cmd = get_binary();
+    cmd.arg("--version");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("rdm"));
+}
+
+// ============================================================================
+// Completions Command
+// ============================================================================
+
+#[test]
+fn test_completions_for_each_shell() {
+    for shell in ["bash", "zsh", "fish", "powershell", "elvish"] {
+        let mut cmd = get_binary();
+        cmd.env_remove("REDMINE_URL")
+            .env_remove("REDMINE_API_KEY")
+            .args(["completions", shell]);
+
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("rdm"))
+            .stdout(predicate::str::contains("issue"));
+    }
+}
+
+#[test]
+fn test_completions_rejects_unknown_shell() {
+    let mut cmd = get_binary();
+    cmd.args(["completions", "tcsh"]);
+
+    cmd.assert().failure();
+}
+
+// ============================================================================
+// Man Command
+// ============================================================================
+
+#[test]
+fn test_man_prints_root_page() {
+    let mut cmd = get_binary();
+    cmd.arg("man");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(".TH rdm"));
+}
+
+#[test]
+fn test_man_output_dir_covers_subcommands() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.arg("man").arg("--output-dir").arg(temp.path());
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("rdm-issue-list.1"));
+
+    for page in [
+        "rdm.1",
+        "rdm-issue.1",
+        "rdm-time-create.1",
+        "rdm-project-get.1",
+    ] {
+        assert!(temp.path().join(page).exists(), "{page} should be written");
+    }
+    assert!(!temp.path().join("rdm-man.1").exists());
+}
+
+// ============================================================================
+// Profile Commands
+// ============================================================================
+
+#[test]
+fn test_profile_list_empty() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", temp.path())
+        .env("LOCALAPPDATA", temp.path())
+        .env_remove("REDMINE_URL")
+        .env_remove("REDMINE_API_KEY")
+        .arg("profile")
+        .arg("list");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No profiles"));
+}
+
+// ============================================================================
+// Config Commands
+// ============================================================================
+
+#[test]
+fn test_config_export_import_round_trip() {
+    let temp = tempfile::tempdir().unwrap();
+    let source = temp.path().join("source.toml");
+    let target = temp.path().join("target.toml");
+    let exported = temp.path().join("exported.toml");
+
+    std::fs::write(
+        &source,
+        "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"https://work.example.com\"\napi_key = \"secret-key\"\n",
+    )
+    .unwrap();
+
+    let output = get_binary()
+        .env("REDMINE_CONFIG", &source)
+        .args(["config", "export", "--no-keys"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let toml = String::from_utf8(output.stdout).unwrap();
+    assert!(toml.contains("https://work.example.com"));
+    assert!(!toml.contains("secret-key"));
+    std::fs::write(&exported, toml).unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &target)
+        .args(["config", "import"])
+        .arg(&exported);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("**Added**: work"));
+    assert!(std::fs::read_to_string(&target)
+        .unwrap()
+        .contains("https://work.example.com"));
+}
+
+#[test]
+fn test_config_set_get_setting() {
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &config)
+        .args(["config", "set", "time_rounding", "15"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("**time_rounding**: 15"));
+    assert!(std::fs::read_to_string(&config)
+        .unwrap()
+        .contains("[settings]"));
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &config)
+        .args(["--format", "json", "config", "get", "time_rounding"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("\"value\": \"15\""));
+}
+
+#[tokio::test]
+async fn test_time_create_uses_project_default_activity() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_issue_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"activity_id": 2}
+        })))
+        .respond_with(
+            wiremock::ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "time_entry": {
+                    "id": 789,
+                    "hours": 1.0,
+                    "spent_on": "2024-01-16",
+                    "activity": {"id": 2, "name": "Design"},
+                    "user": {"id": 1, "name": "Test User"},
+                    "issue": {"id": 123}
+                }
+            })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &config)
+        .args(["config", "set", "default_activity.1", "Design"]);
+    cmd.assert().success();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &config)
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "create", "--issue", "123", "--hours", "1"]);
+
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_time_create_unknown_issue_fails_before_post() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_time_entry_create().expect(0).mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["time", "create", "--issue", "99999", "--hours", "1"]);
+
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains("--no-verify-issue"));
+}
+
+#[tokio::test]
+async fn test_time_create_no_verify_issue_skips_lookup() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_time_entry_create().expect(1).mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "time",
+            "create",
+            "--issue",
+            "123",
+            "--hours",
+            "1",
+            "--no-verify-issue",
+        ]);
+    cmd.assert().success();
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().all(|r| r.url.path() != "/issues/123.json"));
+}
+
+#[tokio::test]
+async fn test_time_create_ignores_unreadable_config_with_flag_credentials() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_time_entry_create().expect(1).mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(&config, "not = [valid toml").unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", &config)
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "time",
+            "create",
+            "--issue",
+            "123",
+            "--hours",
+            "1",
+            "--no-verify-issue",
+        ]);
+    cmd.assert().success();
+}
+
+#[tokio::test]
+async fn test_time_create_dry_run_skips_issue_check() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    // --dry-run does not fetch activities, so warm the cache first
+    let dry_run = [
+        "--dry-run",
+        "time",
+        "create",
+        "--issue",
+        "123",
+        "--hours",
+        "1",
+    ];
+    for args in [&["time", "activities", "list"][..], &dry_run[..]] {
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(args);
+        cmd.assert().success();
+    }
+
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().all(|r| r.url.path() != "/issues/123.json"));
+}
+
+#[tokio::test]
+async fn test_time_create_dry_run_activity_id_without_cache() {
+    let server = start_mock_server().await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args(["--format", "json", "--dry-run"])
+        .args([
+            "time",
+            "create",
+            "--issue",
+            "1",
+            "--hours",
+            "1",
+            "--activity",
+            "9",
+        ]);
+    let output = cmd.assert().success().get_output().stdout.clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["payload"]["time_entry"]["activity_id"], 9);
+
+    // A name cannot be resolved without the activity list
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "--dry-run",
+            "time",
+            "create",
+            "--issue",
+            "1",
+            "--hours",
+            "1",
+        ])
+        .args(["--activity", "Design"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Pass the activity ID"));
+}
+
+#[tokio::test]
+async fn test_time_create_dry_run_payload() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    // --dry-run does not fetch activities, so warm the cache first
+    let dry_run = [
+        "--format",
+        "json",
+        "--dry-run",
+        "time",
+        "create",
+        "--issue",
+        "123",
+        "--hours",
+        "1.5",
+        "--activity",
+        "Design",
+        "--spent-on",
+        "2024-01-15",
+        "--comment",
+        "Review",
+    ];
+    let mut output = Vec::new();
+    for args in [&["time", "activities", "list"][..], &dry_run[..]] {
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(args);
+        output = cmd.assert().success().get_output().stdout.clone();
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["method"], "POST");
+    assert_eq!(json["data"]["path"], "/time_entries.json");
+    assert_eq!(
+        json["data"]["payload"],
+        serde_json::json!({"time_entry": {
+            "issue_id": 123,
+            "hours": 1.5,
+            "activity_id": 2,
+            "spent_on": "2024-01-15",
+            "comments": "Review"
+        }})
+    );
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().all(|r| r.method.as_str() == "GET"));
+}
+
+#[tokio::test]
+async fn test_time_update_dry_run_payload() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let dry_run = [
+        "--format",
+        "json",
+        "--dry-run",
+        "time",
+        "update",
+        "--id",
+        "42",
+        "--hours",
+        "2",
+        "--activity",
+        "Testing",
+        "--clear-comment",
+    ];
+    let mut output = Vec::new();
+    for args in [&["time", "activities", "list"][..], &dry_run[..]] {
+        let mut cmd = get_binary();
+        cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+            .args(args);
+        output = cmd.assert().success().get_output().stdout.clone();
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["data"]["method"], "PUT");
+    assert_eq!(json["data"]["path"], "/time_entries/42.json");
+    assert_eq!(
+        json["data"]["payload"],
+        serde_json::json!({"time_entry": {
+            "hours": 2.0,
+            "activity_id": 3,
+            "comments": ""
+        }})
+    );
+    let requests = server.received_requests().await.unwrap();
+    assert!(requests.iter().all(|r| r.method.as_str() == "GET"));
+}
+
+#[tokio::test]
+async fn test_time_create_for_other_user_forbidden() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_issue_get().mount(&server).await;
+    wiremock::Mock::given(wiremock::matchers::method("POST"))
+        .and(wiremock::matchers::path("/time_entries.json"))
+        .and(wiremock::matchers::body_partial_json(serde_json::json!({
+            "time_entry": {"user_id": 7}
+        })))
+        .respond_with(wiremock::ResponseTemplate::new(403))
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "time", "create", "--issue", "123", "--hours", "1", "--user", "7",
+        ]);
+
+    cmd.assert()
+        .code(3)
+        .stderr(predicate::str::contains("Logging time for other users"));
+}
+
+#[tokio::test]
+async fn test_time_create_user_me_sends_no_user_id() {
+    let server = start_mock_server().await;
+    mock_activities().mount(&server).await;
+    mock_issue_get().mount(&server).await;
+    mock_time_entry_create().expect(1).mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--url", &server.uri(), "--api-key", "test-api-key"])
+        .args([
+            "time", "create", "--issue", "123", "--hours", "1.5", "--user", "me",
+        ]);
+    cmd.assert().success();
+
+    let requests = server.received_requests().await.unwrap();
+    let post = requests
+        .iter()
+        .find(|r| r.method.as_str() == "POST")
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&post.body).unwrap();
+    assert!(body["time_entry"].get("user_id").is_none());
+}
+
+#[test]
+fn test_api_version_gates_project_close() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["--api-version", "4.2", "project", "close", "--project", "1"]);
+    cmd.assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("requires Redmine >= 5.1"));
+
+    // The declared version is remembered for the server
+    let mut cmd = get_binary();
+    cmd.env("APPDATA", std::env::temp_dir())
+        .env("LOCALAPPDATA", std::env::temp_dir())
+        .env("REDMINE_CACHE_DIR", temp.path())
+        .args(["--url", "http://localhost:1", "--api-key", "test-api-key"])
+        .args(["project", "reopen", "--project", "1"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("server is 4.2"));
+}
+
+#[test]
+fn test_config_set_rejects_unknown_key() {
+    let temp = tempfile::tempdir().unwrap();
+
+    let mut cmd = get_binary();
+    cmd.env("REDMINE_CONFIG", temp.path().join("config.toml"))
+        .args(["config", "set", "timezone", "UTC"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Unknown setting"));
+}
+
+#[tokio::test]
+async fn test_profile_set_key_saves_accepted_key() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"{}\"\napi_key = \"old-key\"\n",
+            server.uri()
+        ),
+    )
+    .unwrap();
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .args(["profile", "set-key", "--name", "work", "--from-stdin"])
+        .write_stdin("test-api-key\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("API Key Updated"));
+
+    let saved = std::fs::read_to_string(&config).unwrap();
+    assert!(saved.contains("api_key = \"test-api-key\""));
+    assert!(saved.contains(&server.uri()));
+}
+
+#[tokio::test]
+async fn test_profile_set_key_keeps_old_key_when_rejected() {
+    let server = start_mock_server().await;
+    mock_current_user_unauthorized().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"{}\"\napi_key = \"old-key\"\n",
+            server.uri()
+        ),
+    )
+    .unwrap();
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .args(["profile", "set-key", "--name", "work", "--api-key", "wrong"])
+        .assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains("previous key was kept"));
+
+    let saved = std::fs::read_to_string(&config).unwrap();
+    assert!(saved.contains("api_key = \"old-key\""));
+}
+
+#[test]
+fn test_profile_set_default_project_shown_in_config_show() {
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"https://work.example.com\"\napi_key = \"secret-key\"\n",
+    )
+    .unwrap();
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .args([
+            "profile",
+            "set-default-project",
+            "--name",
+            "work",
+            "--project",
+            "backend",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Default Project Updated"));
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .args(["config", "show"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("**Default Project**: backend"));
+}
+
+#[tokio::test]
+async fn test_issue_list_falls_back_to_profile_default_project() {
+    let server = start_mock_server().await;
+    let issues = wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "issues": [{
+            "id": 123,
+            "subject": "Test Issue",
+            "project": {"id": 1, "name": "Test Project"},
+            "status": {"id": 1, "name": "New"},
+            "priority": {"id": 2, "name": "Normal"},
+            "author": {"id": 1, "name": "Test User"}
+        }],
+        "total_count": 1,
+        "offset": 0,
+        "limit": 25
+    }));
+    for project in ["backend", "frontend"] {
+        wiremock::Mock::given(wiremock::matchers::path("/issues.json"))
+            .and(wiremock::matchers::query_param("project_id", project))
+            .respond_with(issues.clone())
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    wiremock::Mock::given(wiremock::matchers::path("/issues.json"))
+        .and(wiremock::matchers::query_param_is_missing("project_id"))
+        .respond_with(issues)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"{}\"\napi_key = \"test-api-key\"\ndefault_project = \"backend\"\n",
+            server.uri()
+        ),
+    )
+    .unwrap();
+
+    // The default applies when no project is given; --project and --all-projects win
+    for args in [
+        vec![],
+        vec!["--project", "frontend"],
+        vec!["--all-projects"],
+    ] {
+        get_binary()
+            .env("REDMINE_CONFIG", &config)
+            .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+            .args(["issue", "list"])
+            .args(args)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Test Issue"));
+    }
+}
+
+#[test]
+fn test_issue_create_dry_run_sends_default_project_identifier() {
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        "active = \"work\"\n\n[profiles.work]\nname = \"work\"\nurl = \"http://localhost:1\"\napi_key = \"test-api-key\"\ndefault_project = \"backend\"\n",
+    )
+    .unwrap();
+
+    get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .env("REDMINE_CACHE_DIR", temp.path().join("cache"))
+        .args(["--dry-run", "issue", "create", "--subject", "Crash on save"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"project_id\": \"backend\""));
+}
+
+#[tokio::test]
+async fn test_profile_check_reports_each_profile() {
+    let server = start_mock_server().await;
+    mock_current_user().mount(&server).await;
+    mock_current_user_unauthorized().mount(&server).await;
+
+    let temp = tempfile::tempdir().unwrap();
+    let config = temp.path().join("config.toml");
+    std::fs::write(
+        &config,
+        format!(
+            "active = \"good\"\n\n\
+             [profiles.good]\nname = \"good\"\nurl = \"{uri}\"\napi_key = \"test-api-key\"\n\n\
+             [profiles.badkey]\nname = \"badkey\"\nurl = \"{uri}\"\napi_key = \"wrong\"\n\n\
+             [profiles.down]\nname = \"down\"\nurl = \"http://127.0.0.1:1\"\napi_key = \"x\"\n",
+            uri = server.uri()
+        ),
+    )
+    .unwrap();
+
+    let output = get_binary()
+        .env("REDMINE_CONFIG", &config)
+        .args(["--format", "json", "profile", "check"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let profiles = json["data"]["profiles"].as_array().unwrap();
+    let by_name = |name: &str| profiles.iter().find(|p| p["name"] == name).unwrap().clone();
+    assert_eq!(by_name("good")["auth_ok"], true);
+    assert_eq!(by_name("badkey")["reachable"], true);
+    assert_eq!(by_name("badkey")["auth_ok"], false);
+    assert_eq!(by_name("down")["reachable"], false);
 }
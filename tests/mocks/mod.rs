@@ -0,0 +1,355 @@
+//! Mocks used only by the integration tests.
+
+use wiremock::matchers::{header, method, path, path_regex, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+/// Create a mock that answers the current user endpoint with a single 503.
+pub fn mock_current_user_unavailable_once() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/users/current.json"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+}
+
+/// Create a mock that rate-limits the current user endpoint twice with `Retry-After`.
+pub fn mock_current_user_rate_limited_twice() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/users/current.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(2)
+}
+
+/// Create a mock that forbids listing users, as Redmine does for non-admin keys.
+pub fn mock_users_list_forbidden() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/users.json"))
+        .respond_with(ResponseTemplate::new(403))
+}
+
+/// Create a mock for the issue statuses endpoint.
+pub fn mock_issue_statuses() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issue_statuses.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issue_statuses": [
+                {"id": 1, "name": "New", "is_closed": false},
+                {"id": 2, "name": "In Progress", "is_closed": false},
+                {"id": 5, "name": "Closed", "is_closed": true}
+            ]
+        })))
+}
+
+/// Create a mock for the trackers endpoint.
+pub fn mock_trackers() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/trackers.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "trackers": [
+                {"id": 1, "name": "Bug"},
+                {"id": 2, "name": "Feature"}
+            ]
+        })))
+}
+
+/// Create a mock for the issue priorities endpoint.
+pub fn mock_issue_priorities() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/enumerations/issue_priorities.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issue_priorities": [
+                {"id": 1, "name": "Low", "is_default": false},
+                {"id": 2, "name": "Normal", "is_default": true}
+            ]
+        })))
+}
+
+/// Create a mock for a full page of projects with distinct names.
+pub fn mock_projects_list_all() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/projects.json"))
+        .and(query_param("limit", "100"))
+        .and(query_param("offset", "0"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "projects": [
+                {"id": 1, "name": "Backend Services", "identifier": "backend"},
+                {"id": 2, "name": "Website", "identifier": "web"}
+            ],
+            "total_count": 2,
+            "offset": 0,
+            "limit": 100
+        })))
+}
+
+/// Create a mock for the issues list endpoint with a closed, high-priority issue.
+pub fn mock_issues_list_closed_high_priority() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issues": [
+                {
+                    "id": 123,
+                    "subject": "Test Issue",
+                    "project": {"id": 1, "name": "Test Project"},
+                    "status": {"id": 5, "name": "Closed", "is_closed": true},
+                    "priority": {"id": 3, "name": "High"},
+                    "author": {"id": 1, "name": "Test User"}
+                }
+            ],
+            "total_count": 1,
+            "offset": 0,
+            "limit": 25
+        })))
+}
+
+/// Create a mock for listing issues closed on/after 2024-01-01.
+pub fn mock_issues_list_closed_since() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues.json"))
+        .and(query_param("status_id", "closed"))
+        .and(query_param("closed_on", ">=2024-01-01"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issues": [
+                {
+                    "id": 124,
+                    "subject": "Resolved Issue",
+                    "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                    "status": {"id": 5, "name": "Closed"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "author": {"id": 1, "name": "Test User"},
+                    "created_on": "2024-01-01T00:00:00Z",
+                    "updated_on": "2024-01-20T12:00:00Z",
+                    "closed_on": "2024-01-20T12:00:00Z"
+                }
+            ],
+            "total_count": 1,
+            "offset": 0,
+            "limit": 25
+        })))
+}
+
+/// Create a mock for listing open issues sorted by due date.
+pub fn mock_issues_list_due_soon() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues.json"))
+        .and(query_param("status_id", "open"))
+        .and(query_param("sort", "due_date"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issues": [
+                {
+                    "id": 125,
+                    "subject": "Due Soon Issue",
+                    "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "due_date": "2024-01-18",
+                    "updated_on": "2024-01-15T12:00:00Z"
+                }
+            ],
+            "total_count": 1,
+            "offset": 0,
+            "limit": 25
+        })))
+}
+
+/// Create a mock for listing issues that have no assignee.
+pub fn mock_issues_list_unassigned() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues.json"))
+        .and(query_param("assigned_to_id", "!*"))
+        .and(query_param("status_id", "open"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issues": [
+                {
+                    "id": 126,
+                    "subject": "Nobody's Issue",
+                    "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                    "status": {"id": 1, "name": "New"},
+                    "priority": {"id": 2, "name": "Normal"},
+                    "updated_on": "2024-01-15T12:00:00Z"
+                }
+            ],
+            "total_count": 1,
+            "offset": 0,
+            "limit": 25
+        })))
+}
+
+/// Create a mock for getting a single issue.
+pub fn mock_issue_get() -> Mock {
+    Mock::given(method("GET"))
+        .and(path_regex(r"/issues/\d+\.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issue": {
+                "id": 123,
+                "subject": "Test Issue",
+                "description": "This is a test issue",
+                "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                "status": {"id": 1, "name": "New"},
+                "priority": {"id": 2, "name": "Normal"},
+                "tracker": {"id": 1, "name": "Bug"},
+                "author": {"id": 1, "name": "Test User"},
+                "parent": {"id": 100},
+                "created_on": "2024-01-01T00:00:00Z",
+                "updated_on": "2024-01-15T12:00:00Z"
+            }
+        })))
+}
+
+/// Create a mock for getting a single issue that carries an `ETag` validator.
+pub fn mock_issue_get_with_etag() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues/123.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", "\"v1\"")
+                .set_body_json(serde_json::json!({
+                    "issue": {
+                        "id": 123,
+                        "subject": "Cached Issue",
+                        "project": {"id": 1, "name": "Test Project"},
+                        "status": {"id": 1, "name": "New"},
+                        "priority": {"id": 2, "name": "Normal"},
+                        "tracker": {"id": 1, "name": "Bug"},
+                        "author": {"id": 1, "name": "Test User"},
+                        "created_on": "2024-01-01T00:00:00Z",
+                        "updated_on": "2024-01-15T12:00:00Z"
+                    }
+                })),
+        )
+}
+
+/// Create a mock answering 304 when the issue is revalidated with `If-None-Match`.
+pub fn mock_issue_get_not_modified() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/issues/123.json"))
+        .and(header("If-None-Match", "\"v1\""))
+        .respond_with(ResponseTemplate::new(304))
+}
+
+/// Create a mock for getting an issue with a multi-value and a single-value custom field.
+pub fn mock_issue_get_with_custom_fields() -> Mock {
+    Mock::given(method("GET"))
+        .and(path_regex(r"/issues/\d+\.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "issue": {
+                "id": 123,
+                "subject": "Test Issue",
+                "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                "status": {"id": 1, "name": "New"},
+                "priority": {"id": 2, "name": "Normal"},
+                "custom_fields": [
+                    {"id": 5, "name": "Components", "multiple": true, "value": ["backend", "frontend"]},
+                    {"id": 6, "name": "Severity", "value": "high"}
+                ]
+            }
+        })))
+}
+
+/// Create a mock for getting a single time entry.
+pub fn mock_time_entry_get() -> Mock {
+    Mock::given(method("GET"))
+        .and(path_regex(r"/time_entries/\d+\.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "time_entry": {
+                "id": 456,
+                "hours": 2.5,
+                "comments": "Test comment",
+                "spent_on": "2024-01-15",
+                "activity": {"id": 1, "name": "Development"},
+                "user": {"id": 1, "name": "Test User"},
+                "project": {"id": 1, "name": "Test Project", "identifier": "test-project"},
+                "issue": {"id": 123},
+                "created_on": "2024-01-15T12:00:00Z",
+                "updated_on": "2024-01-15T12:00:00Z"
+            }
+        })))
+}
+
+/// Create a mock for creating a time entry.
+pub fn mock_time_entry_create() -> Mock {
+    Mock::given(method("POST"))
+        .and(path("/time_entries.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+            "time_entry": {
+                "id": 789,
+                "hours": 1.5,
+                "comments": "New entry",
+                "spent_on": "2024-01-16",
+                "activity": {"id": 1, "name": "Development"},
+                "user": {"id": 1, "name": "Test User"},
+                "issue": {"id": 123},
+                "created_on": "2024-01-16T12:00:00Z",
+                "updated_on": "2024-01-16T12:00:00Z"
+            }
+        })))
+}
+
+/// Create a mock for deleting a time entry.
+pub fn mock_time_entry_delete() -> Mock {
+    Mock::given(method("DELETE"))
+        .and(path_regex(r"/time_entries/\d+\.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200))
+}
+
+/// Create a mock for an issue's relations: `relations` are (id, from, to)
+/// `blocks` relations.
+pub fn mock_issue_relations(issue_id: u32, relations: &[(u32, u32, u32)]) -> Mock {
+    let relations: Vec<serde_json::Value> = relations
+        .iter()
+        .map(|(id, from, to)| {
+            serde_json::json!({
+                "id": id,
+                "issue_id": from,
+                "issue_to_id": to,
+                "relation_type": "blocks",
+                "delay": null
+            })
+        })
+        .collect();
+    Mock::given(method("GET"))
+        .and(path(format!("/issues/{}/relations.json", issue_id)))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "relations": relations })),
+        )
+}
+
+/// Create a mock listing the versions available to project 1 (one shared from project 2).
+pub fn mock_project_versions() -> Mock {
+    Mock::given(method("GET"))
+        .and(path("/projects/1/versions.json"))
+        .and(header("X-Redmine-API-Key", "test-api-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "versions": [
+                {
+                    "id": 3,
+                    "project": {"id": 1, "name": "Test Project"},
+                    "name": "1.0",
+                    "status": "open",
+                    "sharing": "none"
+                },
+                {
+                    "id": 4,
+                    "project": {"id": 2, "name": "Platform"},
+                    "name": "Q3 Release",
+                    "status": "open",
+                    "sharing": "system"
+                }
+            ],
+            "total_count": 2
+        })))
+}